@@ -0,0 +1,145 @@
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+
+// What has to happen to unlock an achievement
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AchievementGoal {
+    // Defeat enemy #n in any run
+    DefeatEnemy(u32),
+    // Die n times in total
+    Deaths(u32),
+    // Get any single upgradeable stat to level n
+    UpgradeLevel(u32),
+    // Rebirth n times, by death or by hand
+    Rebirths(u32),
+    // Earn this much EXP in total (rewards before multipliers)
+    TotalExp(f64),
+}
+
+// Permanent global bonus from an unlocked achievement
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AchievementReward {
+    // EXP gains * (1 + bonus)
+    Exp(f64),
+    // Player damage * (1 + bonus)
+    Damage(f64),
+}
+
+impl AchievementReward {
+    pub fn describe(&self) -> String {
+        match self {
+            AchievementReward::Exp(bonus) => format!("+{:.0}% EXP", bonus * 100.0),
+            AchievementReward::Damage(bonus) => format!("+{:.0}% damage", bonus * 100.0),
+        }
+    }
+}
+
+pub struct Achievement {
+    // Stable key used in save files
+    pub id: &'static str,
+    pub name: &'static str,
+    pub goal: AchievementGoal,
+    pub reward: AchievementReward,
+}
+
+impl Achievement {
+    pub fn describe(&self) -> String {
+        match self.goal {
+            AchievementGoal::DefeatEnemy(n) => format!("Defeat enemy #{}", n),
+            AchievementGoal::Deaths(n) => format!("Die {} times", n),
+            AchievementGoal::UpgradeLevel(n) => format!("Upgrade a stat to level {}", n),
+            AchievementGoal::Rebirths(n) => format!("Rebirth {} times", n),
+            AchievementGoal::TotalExp(exp) => format!("Earn {} EXP in total", exp),
+        }
+    }
+}
+
+pub const ACHIEVEMENTS: [Achievement; 13] = [
+    Achievement { id: "defeat_1", name: "First Victory", goal: AchievementGoal::DefeatEnemy(1), reward: AchievementReward::Exp(0.02) },
+    Achievement { id: "defeat_10", name: "Boss Slayer", goal: AchievementGoal::DefeatEnemy(10), reward: AchievementReward::Damage(0.05) },
+    Achievement { id: "defeat_25", name: "Delver", goal: AchievementGoal::DefeatEnemy(25), reward: AchievementReward::Exp(0.05) },
+    Achievement { id: "defeat_50", name: "Conqueror", goal: AchievementGoal::DefeatEnemy(50), reward: AchievementReward::Damage(0.1) },
+    Achievement { id: "defeat_100", name: "Legend", goal: AchievementGoal::DefeatEnemy(100), reward: AchievementReward::Exp(0.1) },
+    Achievement { id: "deaths_1", name: "Learning Experience", goal: AchievementGoal::Deaths(1), reward: AchievementReward::Exp(0.02) },
+    Achievement { id: "deaths_10", name: "Stubborn", goal: AchievementGoal::Deaths(10), reward: AchievementReward::Damage(0.05) },
+    Achievement { id: "upgrade_10", name: "Specialist", goal: AchievementGoal::UpgradeLevel(10), reward: AchievementReward::Damage(0.02) },
+    Achievement { id: "upgrade_50", name: "Devoted", goal: AchievementGoal::UpgradeLevel(50), reward: AchievementReward::Exp(0.05) },
+    Achievement { id: "rebirths_1", name: "Born Again", goal: AchievementGoal::Rebirths(1), reward: AchievementReward::Exp(0.05) },
+    Achievement { id: "rebirths_10", name: "Cycle of Life", goal: AchievementGoal::Rebirths(10), reward: AchievementReward::Damage(0.1) },
+    Achievement { id: "exp_1k", name: "Studious", goal: AchievementGoal::TotalExp(1e3), reward: AchievementReward::Exp(0.02) },
+    Achievement { id: "exp_1m", name: "Enlightened", goal: AchievementGoal::TotalExp(1e6), reward: AchievementReward::Damage(0.05) },
+];
+
+// Progress toward every goal plus what is unlocked; kept across rebirths and saved.
+// `unlocked` is indexed like ACHIEVEMENTS.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct Achievements {
+    pub unlocked: [bool; ACHIEVEMENTS.len()],
+    pub highest_enemy_defeated: u32,
+    pub deaths: u32,
+    pub highest_upgrade_level: u32,
+    pub total_exp: BigFloat,
+}
+
+impl Default for Achievements {
+    fn default() -> Self {
+        Self {
+            unlocked: [false; ACHIEVEMENTS.len()],
+            highest_enemy_defeated: 0,
+            deaths: 0,
+            highest_upgrade_level: 0,
+            total_exp: BigFloat::from(0.0),
+        }
+    }
+}
+
+impl Achievements {
+    fn goal_met(&self, goal: AchievementGoal, rebirths: u32) -> bool {
+        match goal {
+            AchievementGoal::DefeatEnemy(n) => self.highest_enemy_defeated >= n,
+            AchievementGoal::Deaths(n) => self.deaths >= n,
+            AchievementGoal::UpgradeLevel(n) => self.highest_upgrade_level >= n,
+            AchievementGoal::Rebirths(n) => rebirths >= n,
+            AchievementGoal::TotalExp(exp) => self.total_exp >= BigFloat::from(exp),
+        }
+    }
+
+    // Unlocks every achievement whose goal is now met and returns their indices
+    pub fn check(&mut self, rebirths: u32) -> Vec<usize> {
+        let newly_unlocked: Vec<usize> = (0..ACHIEVEMENTS.len())
+            .filter(|&index| !self.unlocked[index] && self.goal_met(ACHIEVEMENTS[index].goal, rebirths))
+            .collect();
+        for &index in &newly_unlocked {
+            self.unlocked[index] = true;
+        }
+        newly_unlocked
+    }
+
+    pub fn unlocked_count(&self) -> usize {
+        self.unlocked.iter().filter(|&&unlocked| unlocked).count()
+    }
+
+    fn bonus(&self, pick: impl Fn(AchievementReward) -> Option<f64>) -> f64 {
+        ACHIEVEMENTS.iter()
+            .zip(self.unlocked)
+            .filter(|(_, unlocked)| *unlocked)
+            .filter_map(|(achievement, _)| pick(achievement.reward))
+            .sum()
+    }
+
+    pub fn exp_multiplier(&self) -> BigFloat {
+        let bonus = self.bonus(|reward| match reward {
+            AchievementReward::Exp(bonus) => Some(bonus),
+            _ => None,
+        });
+        BigFloat::from(1.0 + bonus)
+    }
+
+    pub fn damage_multiplier(&self) -> BigFloat {
+        let bonus = self.bonus(|reward| match reward {
+            AchievementReward::Damage(bonus) => Some(bonus),
+            _ => None,
+        });
+        BigFloat::from(1.0 + bonus)
+    }
+}
@@ -0,0 +1,576 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use too_big_float::BigFloat;
+use crate::components::enemy_archetypes::EnemyArchetypes;
+use crate::components::rebirth_shop::RebirthPerk;
+use crate::components::skills::SkillKind;
+use crate::components::status_effects::StatusKind;
+use crate::components::upgradeable_stats::{calculate_exponential_growth, geometric_cost_sum, levels_within, StatKind};
+
+// value = base * growth^n
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct GrowthCurve {
+    pub base: f64,
+    pub growth: f64,
+}
+
+impl GrowthCurve {
+    pub const fn new(base: f64, growth: f64) -> Self {
+        Self { base, growth }
+    }
+
+    pub fn at(&self, n: u32) -> BigFloat {
+        calculate_exponential_growth(BigFloat::from(self.base), self.growth, n)
+    }
+}
+
+// Enemy stats scale with EnemyNumber
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct EnemyBalance {
+    pub hp: GrowthCurve,
+    pub attack: GrowthCurve,
+    pub defense: GrowthCurve,
+    pub speed: GrowthCurve,
+    pub exp_reward: GrowthCurve,
+    // Combat ratings; archetypes and bosses leave these alone
+    pub crit_chance: GrowthCurve,
+    pub crit_multiplier: GrowthCurve,
+    pub evasion: GrowthCurve,
+    pub accuracy: GrowthCurve,
+    pub archetypes: EnemyArchetypes,
+}
+
+impl Default for EnemyBalance {
+    fn default() -> Self {
+        Self {
+            hp: GrowthCurve::new(20.0, 1.5),
+            attack: GrowthCurve::new(3.0, 1.3),
+            defense: GrowthCurve::new(2.0, 1.3),
+            speed: GrowthCurve::new(0.8, 1.1),
+            exp_reward: GrowthCurve::new(5.0, 1.15),
+            crit_chance: GrowthCurve::new(0.05, 1.0),
+            crit_multiplier: GrowthCurve::new(1.5, 1.0),
+            evasion: GrowthCurve::new(0.05, 1.02),
+            accuracy: GrowthCurve::new(1.0, 1.02),
+            archetypes: EnemyArchetypes::default(),
+        }
+    }
+}
+
+// Player starting stats and upgrade scaling
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct PlayerBalance {
+    pub base_hp: f64,
+    pub base_attack: f64,
+    pub base_defense: f64,
+    pub base_speed: f64,
+    pub base_crit_chance: f64,
+    pub base_crit_multiplier: f64,
+    pub base_evasion: f64,
+    pub base_accuracy: f64,
+    pub base_upgrade_cost: f64,
+    pub upgrade_multiplier: f64,
+    pub cost_multiplier: f64,
+}
+
+impl Default for PlayerBalance {
+    fn default() -> Self {
+        Self {
+            base_hp: 100.0,
+            base_attack: 10.0,
+            base_defense: 5.0,
+            base_speed: 1.0,
+            base_crit_chance: 0.05,
+            base_crit_multiplier: 1.5,
+            base_evasion: 0.05,
+            base_accuracy: 1.0,
+            base_upgrade_cost: 10.0,
+            upgrade_multiplier: 1.15,
+            cost_multiplier: 1.3,
+        }
+    }
+}
+
+impl PlayerBalance {
+    // Starting values of the combat ratings in StatKind::RATINGS order
+    pub fn base_ratings(&self) -> [f64; 4] {
+        [self.base_crit_chance, self.base_crit_multiplier, self.base_evasion, self.base_accuracy]
+    }
+}
+
+// Going from level n to n + 1 takes exp.at(n - 1) lifetime EXP.
+// Each level adds the per-level amounts to the base value of the matching stat.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct LevelingBalance {
+    pub exp: GrowthCurve,
+    pub hp_per_level: f64,
+    pub attack_per_level: f64,
+    pub defense_per_level: f64,
+    pub speed_per_level: f64,
+}
+
+impl Default for LevelingBalance {
+    fn default() -> Self {
+        Self {
+            exp: GrowthCurve::new(20.0, 1.2),
+            hp_per_level: 5.0,
+            attack_per_level: 1.0,
+            defense_per_level: 0.5,
+            speed_per_level: 0.0,
+        }
+    }
+}
+
+impl LevelingBalance {
+    // Lifetime EXP needed to reach `level` from level 1
+    pub fn exp_to_reach(&self, level: u32) -> BigFloat {
+        geometric_cost_sum(BigFloat::from(self.exp.base), self.exp.growth, level.saturating_sub(1))
+    }
+
+    pub fn level_for(&self, lifetime_exp: BigFloat) -> u32 {
+        1 + levels_within(lifetime_exp, BigFloat::from(self.exp.base), self.exp.growth, u32::MAX - 1).0
+    }
+
+    // Base value bonus per level in StatKind order; combat ratings do not grow with level
+    pub fn stat_bonuses(&self) -> [f64; StatKind::COUNT] {
+        [self.hp_per_level, self.attack_per_level, self.defense_per_level, self.speed_per_level, 0.0, 0.0, 0.0, 0.0]
+    }
+
+    // Everything levels 2..=level added, for stats respawned at an existing level
+    pub fn bonuses_at(&self, level: u32) -> [f64; StatKind::COUNT] {
+        self.stat_bonuses().map(|bonus| bonus * level.saturating_sub(1) as f64)
+    }
+}
+
+// Stats: * (points * stat_bonus_per_point + 1), cost: / (points * cost_reduction_per_point + 1)
+// where points are the lifetime rebirth points
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct RebirthBalance {
+    pub stat_bonus_per_point: f64,
+    pub cost_reduction_per_point: f64,
+    // Manual rebirth unlocks once this enemy number is reached
+    pub manual_min_enemy: u32,
+    pub shop: RebirthShopBalance,
+}
+
+impl Default for RebirthBalance {
+    fn default() -> Self {
+        Self {
+            stat_bonus_per_point: 0.1,
+            cost_reduction_per_point: 0.05,
+            manual_min_enemy: 5,
+            shop: RebirthShopBalance::default(),
+        }
+    }
+}
+
+// Level n of a perk costs base_cost * cost_growth^n rebirth points; max_level 0 means uncapped
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PerkBalance {
+    pub base_cost: f64,
+    pub cost_growth: f64,
+    pub effect_per_level: f64,
+    pub max_level: u32,
+}
+
+impl PerkBalance {
+    pub fn cost(&self, level: u32) -> BigFloat {
+        calculate_exponential_growth(BigFloat::from(self.base_cost), self.cost_growth, level)
+    }
+
+    pub fn effect(&self, level: u32) -> f64 {
+        self.effect_per_level * level as f64
+    }
+}
+
+// EXP: * (1 + effect), starting levels: effect levels per stat,
+// stat cost: / (1 + effect), attack time: / (1 + effect)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct RebirthShopBalance {
+    pub exp_multiplier: PerkBalance,
+    pub starting_levels: PerkBalance,
+    // Shared by the four per-stat cost perks
+    pub cheaper_stat: PerkBalance,
+    pub faster_cooldown: PerkBalance,
+}
+
+impl Default for RebirthShopBalance {
+    fn default() -> Self {
+        Self {
+            exp_multiplier: PerkBalance { base_cost: 5.0, cost_growth: 1.5, effect_per_level: 0.25, max_level: 0 },
+            starting_levels: PerkBalance { base_cost: 10.0, cost_growth: 2.0, effect_per_level: 1.0, max_level: 0 },
+            cheaper_stat: PerkBalance { base_cost: 3.0, cost_growth: 1.4, effect_per_level: 0.1, max_level: 0 },
+            faster_cooldown: PerkBalance { base_cost: 8.0, cost_growth: 1.6, effect_per_level: 0.05, max_level: 20 },
+        }
+    }
+}
+
+impl RebirthShopBalance {
+    pub fn perk(&self, perk: RebirthPerk) -> &PerkBalance {
+        match perk {
+            RebirthPerk::ExpMultiplier => &self.exp_multiplier,
+            RebirthPerk::StartingLevels => &self.starting_levels,
+            RebirthPerk::CheaperHp
+            | RebirthPerk::CheaperAttack
+            | RebirthPerk::CheaperDefense
+            | RebirthPerk::CheaperSpeed => &self.cheaper_stat,
+            RebirthPerk::FasterCooldown => &self.faster_cooldown,
+        }
+    }
+}
+
+// Every `interval`-th enemy is a boss (0 disables bosses). Multipliers stack on the
+// enemy's archetype; once `enrage_secs` pass the boss's attack is multiplied by `enrage_attack`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct BossBalance {
+    pub interval: u32,
+    pub hp: f64,
+    pub attack: f64,
+    pub defense: f64,
+    pub speed: f64,
+    pub exp_reward: f64,
+    pub enrage_secs: f32,
+    pub enrage_attack: f64,
+}
+
+impl Default for BossBalance {
+    fn default() -> Self {
+        Self {
+            interval: 10,
+            hp: 3.0,
+            attack: 1.2,
+            defense: 1.2,
+            speed: 1.0,
+            exp_reward: 5.0,
+            enrage_secs: 30.0,
+            enrage_attack: 2.0,
+        }
+    }
+}
+
+impl BossBalance {
+    pub fn is_boss(&self, enemy_number: u32) -> bool {
+        self.interval > 0 && enemy_number.is_multiple_of(self.interval)
+    }
+
+    // First enemy after the previous boss milestone - where a failed boss fight retreats to
+    pub fn checkpoint(&self, enemy_number: u32) -> u32 {
+        if self.interval == 0 {
+            return enemy_number;
+        }
+        (enemy_number.saturating_sub(1) / self.interval) * self.interval + 1
+    }
+}
+
+// How the target's defense reduces an attack; every formula deals at least 1 damage
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum DamageFormula {
+    /// attack - defense
+    #[default]
+    Subtractive,
+    /// attack² / (attack + defense)
+    Ratio,
+    /// attack * scale / (scale + defense)
+    Mitigation,
+}
+
+impl DamageFormula {
+    pub const ALL: [DamageFormula; 3] = [DamageFormula::Subtractive, DamageFormula::Ratio, DamageFormula::Mitigation];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DamageFormula::Subtractive => "subtractive",
+            DamageFormula::Ratio => "ratio",
+            DamageFormula::Mitigation => "mitigation",
+        }
+    }
+}
+
+// Damage dealt by both sides. With Mitigation, `mitigation_scale` defense halves the damage.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct DamageBalance {
+    pub formula: DamageFormula,
+    pub mitigation_scale: f64,
+}
+
+impl Default for DamageBalance {
+    fn default() -> Self {
+        Self {
+            formula: DamageFormula::Subtractive,
+            mitigation_scale: 100.0,
+        }
+    }
+}
+
+impl DamageBalance {
+    // Damage of one plain hit, before crits and damage multipliers
+    pub fn damage(&self, attack: BigFloat, defense: BigFloat) -> BigFloat {
+        let one = BigFloat::from(1.0);
+        let damage = match self.formula {
+            DamageFormula::Subtractive => attack - defense,
+            DamageFormula::Ratio => {
+                let total = attack + defense;
+                if total <= BigFloat::from(0.0) {
+                    return one;
+                }
+                attack * attack / total
+            }
+            DamageFormula::Mitigation => {
+                let scale = BigFloat::from(self.mitigation_scale);
+                let total = scale + defense.max(BigFloat::from(0.0));
+                if total <= BigFloat::from(0.0) {
+                    return one;
+                }
+                attack * scale / total
+            }
+        };
+        damage.max(one)
+    }
+}
+
+// Chance for a landed hit to apply a status effect and how long it lasts. `potency` is the
+// damage per second as a share of the hit (Poison, Burn) or the cooldown drain rate (Slow).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct StatusEffectBalance {
+    pub chance: f64,
+    pub duration_secs: f32,
+    pub potency: f64,
+}
+
+impl StatusEffectBalance {
+    pub const NONE: Self = Self::new(0.0, 0.0, 0.0);
+
+    pub const fn new(chance: f64, duration_secs: f32, potency: f64) -> Self {
+        Self { chance, duration_secs, potency }
+    }
+}
+
+// Status effects one side's attacks can apply
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct OnHitEffects {
+    pub poison: StatusEffectBalance,
+    pub burn: StatusEffectBalance,
+    pub stun: StatusEffectBalance,
+    pub slow: StatusEffectBalance,
+}
+
+impl Default for OnHitEffects {
+    fn default() -> Self {
+        Self {
+            poison: StatusEffectBalance::NONE,
+            burn: StatusEffectBalance::NONE,
+            stun: StatusEffectBalance::NONE,
+            slow: StatusEffectBalance::NONE,
+        }
+    }
+}
+
+impl OnHitEffects {
+    pub fn get(&self, kind: StatusKind) -> &StatusEffectBalance {
+        match kind {
+            StatusKind::Poison => &self.poison,
+            StatusKind::Burn => &self.burn,
+            StatusKind::Stun => &self.stun,
+            StatusKind::Slow => &self.slow,
+        }
+    }
+
+    // Average poison and burn damage a landed hit adds, relative to the hit itself. The next
+    // attack may reapply the effect and restart it, so no hit is credited past `interval_secs`
+    pub fn expected_dot_factor(&self, interval_secs: f64) -> f64 {
+        [&self.poison, &self.burn].iter()
+            .map(|effect| effect.chance.clamp(0.0, 1.0) * effect.potency * (effect.duration_secs as f64).min(interval_secs))
+            .sum()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct StatusBalance {
+    pub player: OnHitEffects,
+    pub enemy: OnHitEffects,
+}
+
+impl Default for StatusBalance {
+    fn default() -> Self {
+        Self {
+            player: OnHitEffects {
+                poison: StatusEffectBalance::new(0.1, 5.0, 0.1),
+                burn: StatusEffectBalance::new(0.05, 3.0, 0.3),
+                ..default()
+            },
+            enemy: OnHitEffects {
+                stun: StatusEffectBalance::new(0.03, 1.0, 0.0),
+                slow: StatusEffectBalance::new(0.1, 3.0, 0.5),
+                ..default()
+            },
+        }
+    }
+}
+
+// Mana cost, cooldown and strength of one skill: damage as a multiple of a normal hit
+// (PowerStrike) or a share of max HP healed / absorbed (Heal, Shield)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct SkillStats {
+    pub cost: f64,
+    pub cooldown_secs: f32,
+    pub potency: f64,
+    // How long the effect lasts; 0 for instant skills
+    #[serde(default)]
+    pub duration_secs: f32,
+}
+
+impl SkillStats {
+    pub const fn new(cost: f64, cooldown_secs: f32, potency: f64, duration_secs: f32) -> Self {
+        Self { cost, cooldown_secs, potency, duration_secs }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct SkillBalance {
+    pub max_mana: f64,
+    pub mana_per_sec: f64,
+    pub power_strike: SkillStats,
+    pub heal: SkillStats,
+    pub shield: SkillStats,
+}
+
+impl Default for SkillBalance {
+    fn default() -> Self {
+        Self {
+            max_mana: 100.0,
+            mana_per_sec: 5.0,
+            power_strike: SkillStats::new(30.0, 6.0, 2.5, 0.0),
+            heal: SkillStats::new(40.0, 15.0, 0.3, 0.0),
+            shield: SkillStats::new(50.0, 20.0, 0.25, 8.0),
+        }
+    }
+}
+
+impl SkillBalance {
+    pub fn get(&self, skill: SkillKind) -> &SkillStats {
+        match skill {
+            SkillKind::PowerStrike => &self.power_strike,
+            SkillKind::Heal => &self.heal,
+            SkillKind::Shield => &self.shield,
+        }
+    }
+}
+
+// All balance constants; missing fields in a config file fall back to these defaults
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct BalanceConfig {
+    pub enemy: EnemyBalance,
+    pub player: PlayerBalance,
+    pub leveling: LevelingBalance,
+    pub rebirth: RebirthBalance,
+    pub boss: BossBalance,
+    pub damage: DamageBalance,
+    pub status: StatusBalance,
+    pub skills: SkillBalance,
+}
+
+#[derive(Debug)]
+pub enum BalanceConfigError {
+    Io(std::io::Error),
+    Parse(ron::error::SpannedError),
+    // The named value must be a finite number above zero
+    NotPositive(&'static str),
+    // Upgrade costs would stop growing
+    CostMultiplierTooLow(f64),
+    // The named chance must lie in 0..=1
+    ChanceOutOfRange(&'static str),
+}
+
+impl fmt::Display for BalanceConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BalanceConfigError::Io(err) => write!(f, "I/O error: {}", err),
+            BalanceConfigError::Parse(err) => write!(f, "invalid balance config: {}", err),
+            BalanceConfigError::NotPositive(field) => write!(f, "invalid balance config: {} must be a positive number", field),
+            BalanceConfigError::CostMultiplierTooLow(value) => write!(
+                f, "invalid balance config: player.cost_multiplier must be greater than 1, got {}", value
+            ),
+            BalanceConfigError::ChanceOutOfRange(field) => write!(f, "invalid balance config: {} must be between 0 and 1", field),
+        }
+    }
+}
+
+impl std::error::Error for BalanceConfigError {}
+
+impl BalanceConfig {
+    pub fn from_ron(contents: &str) -> Result<Self, BalanceConfigError> {
+        let config: Self = ron::from_str(contents).map_err(BalanceConfigError::Parse)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    // Values that parse but would break the formulas
+    fn validate(&self) -> Result<(), BalanceConfigError> {
+        let enemy = &self.enemy;
+        let shop = &self.rebirth.shop;
+        let positive = [
+            ("damage.mitigation_scale", self.damage.mitigation_scale),
+            ("leveling.exp.base", self.leveling.exp.base),
+            ("leveling.exp.growth", self.leveling.exp.growth),
+            ("player.upgrade_multiplier", self.player.upgrade_multiplier),
+            ("enemy.hp.growth", enemy.hp.growth),
+            ("enemy.attack.growth", enemy.attack.growth),
+            ("enemy.defense.growth", enemy.defense.growth),
+            ("enemy.speed.growth", enemy.speed.growth),
+            ("enemy.exp_reward.growth", enemy.exp_reward.growth),
+            ("enemy.crit_chance.growth", enemy.crit_chance.growth),
+            ("enemy.crit_multiplier.growth", enemy.crit_multiplier.growth),
+            ("enemy.evasion.growth", enemy.evasion.growth),
+            ("enemy.accuracy.growth", enemy.accuracy.growth),
+            ("rebirth.shop.exp_multiplier.cost_growth", shop.exp_multiplier.cost_growth),
+            ("rebirth.shop.starting_levels.cost_growth", shop.starting_levels.cost_growth),
+            ("rebirth.shop.cheaper_stat.cost_growth", shop.cheaper_stat.cost_growth),
+            ("rebirth.shop.faster_cooldown.cost_growth", shop.faster_cooldown.cost_growth),
+        ];
+        if let Some((field, _)) = positive.iter().find(|(_, value)| !value.is_finite() || *value <= 0.0) {
+            return Err(BalanceConfigError::NotPositive(field));
+        }
+
+        let cost_multiplier = self.player.cost_multiplier;
+        if !cost_multiplier.is_finite() || cost_multiplier <= 1.0 {
+            return Err(BalanceConfigError::CostMultiplierTooLow(cost_multiplier));
+        }
+
+        let (player_effects, enemy_effects) = (&self.status.player, &self.status.enemy);
+        let chances = [
+            ("player.base_crit_chance", self.player.base_crit_chance),
+            ("enemy.crit_chance.base", enemy.crit_chance.base),
+            ("status.player.poison.chance", player_effects.poison.chance),
+            ("status.player.burn.chance", player_effects.burn.chance),
+            ("status.player.stun.chance", player_effects.stun.chance),
+            ("status.player.slow.chance", player_effects.slow.chance),
+            ("status.enemy.poison.chance", enemy_effects.poison.chance),
+            ("status.enemy.burn.chance", enemy_effects.burn.chance),
+            ("status.enemy.stun.chance", enemy_effects.stun.chance),
+            ("status.enemy.slow.chance", enemy_effects.slow.chance),
+        ];
+        if let Some((field, _)) = chances.iter().find(|(_, chance)| !(0.0..=1.0).contains(chance)) {
+            return Err(BalanceConfigError::ChanceOutOfRange(field));
+        }
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, BalanceConfigError> {
+        let contents = fs::read_to_string(path).map_err(BalanceConfigError::Io)?;
+        Self::from_ron(&contents)
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::VecDeque;
+use bevy::prelude::*;
+
+// Entries kept before the oldest ones are dropped
+pub const COMBAT_LOG_CAPACITY: usize = 200;
+
+// Kind of combat log entry; each can be hidden with the filter buttons
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CombatLogKind {
+    Attack,
+    Status,
+    Skill,
+    Kill,
+    Exp,
+    Upgrade,
+    Rebirth,
+}
+
+impl CombatLogKind {
+    pub const ALL: [CombatLogKind; 7] = [
+        CombatLogKind::Attack,
+        CombatLogKind::Status,
+        CombatLogKind::Skill,
+        CombatLogKind::Kill,
+        CombatLogKind::Exp,
+        CombatLogKind::Upgrade,
+        CombatLogKind::Rebirth,
+    ];
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CombatLogKind::Attack => "Attacks",
+            CombatLogKind::Status => "Status",
+            CombatLogKind::Skill => "Skills",
+            CombatLogKind::Kill => "Kills",
+            CombatLogKind::Exp => "EXP",
+            CombatLogKind::Upgrade => "Upgrades",
+            CombatLogKind::Rebirth => "Rebirths",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CombatLogEntry {
+    pub kind: CombatLogKind,
+    pub message: String,
+}
+
+// Bounded ring buffer of recent combat entries shown in the Combat tab.
+// `shown` is indexed like CombatLogKind::ALL.
+#[derive(Resource, Clone, Debug)]
+pub struct CombatLog {
+    entries: VecDeque<CombatLogEntry>,
+    capacity: usize,
+    pub shown: [bool; CombatLogKind::ALL.len()],
+}
+
+impl Default for CombatLog {
+    fn default() -> Self {
+        Self::with_capacity(COMBAT_LOG_CAPACITY)
+    }
+}
+
+impl CombatLog {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            shown: [true; CombatLogKind::ALL.len()],
+        }
+    }
+
+    // Appends an entry, dropping the oldest one when full
+    pub fn push(&mut self, kind: CombatLogKind, message: impl Into<String>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(CombatLogEntry { kind, message: message.into() });
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn is_shown(&self, kind: CombatLogKind) -> bool {
+        self.shown[kind.index()]
+    }
+
+    pub fn toggle(&mut self, kind: CombatLogKind) {
+        self.shown[kind.index()] = !self.shown[kind.index()];
+    }
+
+    // Entries that pass the filter, newest first
+    pub fn visible(&self) -> impl Iterator<Item = &CombatLogEntry> {
+        self.entries.iter().rev().filter(|entry| self.is_shown(entry.kind))
+    }
+}
@@ -17,12 +17,35 @@ pub struct CombatDefense(pub BigFloat);
 #[derive(Component, Clone, Debug, PartialEq)]
 pub struct CombatSpeed(pub BigFloat);
 
+// Chance (0 to 1) that a landed hit is critical
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct CombatCritChance(pub BigFloat);
+
+// Damage multiplier of critical hits
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct CombatCritMultiplier(pub BigFloat);
+
+// Evasion and accuracy are ratings: a hit lands with accuracy / (accuracy + evasion)
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct CombatEvasion(pub BigFloat);
+
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct CombatAccuracy(pub BigFloat);
+
 // Combat state tracking
 #[derive(Component)]
 pub struct CombatTimer {
     pub timer: Timer,
 }
 
+// Boss enrage countdown; attack is multiplied once when it runs out
+#[derive(Component)]
+pub struct EnrageTimer {
+    pub timer: Timer,
+    pub attack_multiplier: f64,
+    pub enraged: bool,
+}
+
 // Attack cooldown in milliseconds
 #[derive(Component, Clone, Debug, PartialEq)]
 pub struct AttackCooldown(pub f32);
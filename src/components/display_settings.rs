@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+use crate::number_format::NumberNotation;
+
+// Player-facing display options from the Settings tab; saved with the game.
+// Balance checks take the notation from --number-notation instead.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct DisplaySettings {
+    pub notation: NumberNotation,
+}
@@ -0,0 +1,153 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// Enemy type, decides which stat profile an enemy is spawned with
+#[derive(Component, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EnemyArchetype {
+    #[default]
+    Standard,
+    Tank,
+    GlassCannon,
+    Speedster,
+}
+
+impl EnemyArchetype {
+    pub const ALL: [EnemyArchetype; 4] = [
+        EnemyArchetype::Standard,
+        EnemyArchetype::Tank,
+        EnemyArchetype::GlassCannon,
+        EnemyArchetype::Speedster,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EnemyArchetype::Standard => "Slime",
+            EnemyArchetype::Tank => "Golem",
+            EnemyArchetype::GlassCannon => "Imp",
+            EnemyArchetype::Speedster => "Wolf",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EnemyArchetype::Standard => "Standard",
+            EnemyArchetype::Tank => "Tank",
+            EnemyArchetype::GlassCannon => "Glass Cannon",
+            EnemyArchetype::Speedster => "Speedster",
+        }
+    }
+}
+
+// Multipliers applied on top of the enemy growth curves
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct ArchetypeBalance {
+    pub hp: f64,
+    pub attack: f64,
+    pub defense: f64,
+    pub speed: f64,
+    pub exp_reward: f64,
+    // Relative chance in the weighted table; 0 never picks this type
+    pub weight: u32,
+}
+
+impl ArchetypeBalance {
+    pub const fn new(hp: f64, attack: f64, defense: f64, speed: f64, exp_reward: f64, weight: u32) -> Self {
+        Self { hp, attack, defense, speed, exp_reward, weight }
+    }
+}
+
+impl Default for ArchetypeBalance {
+    fn default() -> Self {
+        Self::new(1.0, 1.0, 1.0, 1.0, 1.0, 1)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ArchetypeSelection {
+    // Pick from the weighted table, keyed by enemy number and seed
+    #[default]
+    Weighted,
+    // Tank, glass cannon, speedster, standard in turn
+    Cycle,
+}
+
+// How enemy types are chosen per enemy number. Selection never uses runtime randomness,
+// so the same enemy number always has the same type (offline progress relies on this).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct EnemyArchetypes {
+    pub selection: ArchetypeSelection,
+    // Enemies up to this number are always Standard
+    pub standard_until: u32,
+    pub seed: u64,
+    pub standard: ArchetypeBalance,
+    pub tank: ArchetypeBalance,
+    pub glass_cannon: ArchetypeBalance,
+    pub speedster: ArchetypeBalance,
+}
+
+impl Default for EnemyArchetypes {
+    fn default() -> Self {
+        Self {
+            selection: ArchetypeSelection::Weighted,
+            standard_until: 3,
+            seed: 0,
+            standard: ArchetypeBalance::new(1.0, 1.0, 1.0, 1.0, 1.0, 4),
+            tank: ArchetypeBalance::new(2.0, 0.8, 1.5, 0.8, 1.5, 2),
+            glass_cannon: ArchetypeBalance::new(0.6, 1.6, 0.5, 1.0, 1.3, 2),
+            speedster: ArchetypeBalance::new(0.8, 0.9, 0.8, 1.3, 1.3, 2),
+        }
+    }
+}
+
+// splitmix64 finalizer - spreads consecutive enemy numbers over the weight table
+fn mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+impl EnemyArchetypes {
+    pub fn balance(&self, archetype: EnemyArchetype) -> &ArchetypeBalance {
+        match archetype {
+            EnemyArchetype::Standard => &self.standard,
+            EnemyArchetype::Tank => &self.tank,
+            EnemyArchetype::GlassCannon => &self.glass_cannon,
+            EnemyArchetype::Speedster => &self.speedster,
+        }
+    }
+
+    pub fn select(&self, enemy_number: u32) -> EnemyArchetype {
+        if enemy_number <= self.standard_until {
+            return EnemyArchetype::Standard;
+        }
+        match self.selection {
+            ArchetypeSelection::Cycle => {
+                let index = (enemy_number - self.standard_until - 1) as usize;
+                [
+                    EnemyArchetype::Tank,
+                    EnemyArchetype::GlassCannon,
+                    EnemyArchetype::Speedster,
+                    EnemyArchetype::Standard,
+                ][index % 4]
+            }
+            ArchetypeSelection::Weighted => {
+                let total: u64 = EnemyArchetype::ALL.iter().map(|a| self.balance(*a).weight as u64).sum();
+                if total == 0 {
+                    return EnemyArchetype::Standard;
+                }
+                let mut roll = mix(self.seed ^ enemy_number as u64) % total;
+                for archetype in EnemyArchetype::ALL {
+                    let weight = self.balance(archetype).weight as u64;
+                    if roll < weight {
+                        return archetype;
+                    }
+                    roll -= weight;
+                }
+                EnemyArchetype::Standard
+            }
+        }
+    }
+}
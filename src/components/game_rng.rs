@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// Seed used when --seed is not given
+pub const DEFAULT_SEED: u64 = 0x5EED;
+
+// Source of every random roll in the simulation. Seeded from --seed and saved with the game,
+// so the same seed and StartupConfig always play out the same way.
+#[derive(Resource, Clone, Debug)]
+pub struct GameRng {
+    seed: u64,
+    rng: ChaCha8Rng,
+}
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    // Continue a saved stream `word_pos` 32-bit words into `seed`
+    pub fn resume(seed: u64, word_pos: u64) -> Self {
+        let mut rng = Self::from_seed(seed);
+        rng.rng.set_word_pos(word_pos as u128);
+        rng
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn word_pos(&self) -> u64 {
+        self.rng.get_word_pos() as u64
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::from_seed(DEFAULT_SEED)
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
@@ -20,5 +20,9 @@ pub struct Experience(pub BigFloat);
 #[derive(Component, Clone, Debug, PartialEq)]
 pub struct Level(pub u32);
 
+// EXP earned over every run; never spent and kept through rebirths, drives Level
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct LifetimeExperience(pub BigFloat);
+
 #[derive(Component, Clone, Debug, PartialEq)]
 pub struct RebirthPoints(pub BigFloat);
\ No newline at end of file
@@ -1,4 +1,8 @@
 use bevy::prelude::*;
+use crate::components::rebirth_shop::RebirthPerk;
+use crate::components::upgradeable_stats::{BuyAmount, StatKind};
+use crate::components::combat_log::CombatLogKind;
+use crate::components::skills::SkillKind;
 
 // Marker components for identification
 #[derive(Component)]
@@ -7,6 +11,9 @@ pub struct Player;
 #[derive(Component)]
 pub struct Enemy;
 
+#[derive(Component)]
+pub struct Boss;
+
 // UI marker components (kept from original)
 #[derive(Component)]
 pub struct StatsText;
@@ -26,6 +33,102 @@ pub struct AutoRetryButton;
 #[derive(Component)]
 pub struct AutoRetryButtonText;
 
+#[derive(Component)]
+pub struct RebirthPreviewText;
+
+#[derive(Component)]
+pub struct AchievementsText;
+
+#[derive(Component)]
+pub struct StatisticsText;
+
+#[derive(Component)]
+pub struct NumberNotationButton;
+
+#[derive(Component)]
+pub struct NumberNotationButtonText;
+
+#[derive(Component)]
+pub struct NumberPreviewText;
+
+#[derive(Component)]
+pub struct CombatLogPanel;
+
+#[derive(Component)]
+pub struct CombatLogText;
+
+#[derive(Component)]
+pub struct CombatLogFilterButton {
+    pub kind: CombatLogKind,
+}
+
+#[derive(Component)]
+pub struct RebirthButton;
+
+#[derive(Component)]
+pub struct RebirthButtonText;
+
+#[derive(Component)]
+pub struct UpgradeRowText {
+    pub stat: StatKind,
+}
+
+#[derive(Component)]
+pub struct ManualUpgradeButton {
+    pub stat: StatKind,
+    pub amount: BuyAmount,
+}
+
+#[derive(Component)]
+pub struct ManualUpgradeButtonText {
+    pub stat: StatKind,
+    pub amount: BuyAmount,
+}
+
+#[derive(Component)]
+pub struct AutoUpgradeButton;
+
+#[derive(Component)]
+pub struct AutoUpgradeButtonText;
+
+#[derive(Component)]
+pub struct SkillButton {
+    pub skill: SkillKind,
+}
+
+#[derive(Component)]
+pub struct SkillButtonText {
+    pub skill: SkillKind,
+}
+
+#[derive(Component)]
+pub struct AutoCastButton;
+
+#[derive(Component)]
+pub struct AutoCastButtonText;
+
+#[derive(Component)]
+pub struct UpgradeStrategyButton;
+
+#[derive(Component)]
+pub struct UpgradeStrategyButtonText;
+
+#[derive(Component)]
+pub struct RebirthShopButton {
+    pub perk: RebirthPerk,
+}
+
+#[derive(Component)]
+pub struct RebirthShopButtonText {
+    pub perk: RebirthPerk,
+}
+
+#[derive(Component)]
+pub struct OfflineSummaryPanel;
+
+#[derive(Component)]
+pub struct OfflineSummaryCloseButton;
+
 #[derive(Component)]
 pub struct TabButton {
     pub tab: GameTab,
@@ -59,6 +162,7 @@ pub struct UIState {
 pub struct AutomationConfig {
     pub auto_retry_unlocked: bool,
     pub auto_retry_enabled: bool,
+    pub auto_cast_enabled: bool,
 }
 
 
@@ -67,4 +171,7 @@ pub enum GameTab {
     Combat,
     Rebirth,
     Automation,
+    Achievements,
+    Statistics,
+    Settings,
 }
\ No newline at end of file
@@ -2,8 +2,32 @@ pub mod management_stats;
 pub mod combat_stats;
 pub mod markers;
 pub mod upgradeable_stats;
+pub mod balance_config;
+pub mod enemy_archetypes;
+pub mod rebirth_currency;
+pub mod rebirth_shop;
+pub mod upgrade_strategy;
+pub mod achievements;
+pub mod statistics;
+pub mod combat_log;
+pub mod display_settings;
+pub mod game_rng;
+pub mod status_effects;
+pub mod skills;
 
 pub use management_stats::*;
 pub use combat_stats::*;
 pub use markers::*;
-pub use upgradeable_stats::*;
\ No newline at end of file
+pub use upgradeable_stats::*;
+pub use balance_config::*;
+pub use enemy_archetypes::*;
+pub use rebirth_currency::*;
+pub use rebirth_shop::*;
+pub use upgrade_strategy::*;
+pub use achievements::*;
+pub use statistics::*;
+pub use combat_log::*;
+pub use display_settings::*;
+pub use game_rng::*;
+pub use status_effects::*;
+pub use skills::*;
\ No newline at end of file
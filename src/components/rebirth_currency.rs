@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use too_big_float::BigFloat;
+
+// Only the most recent rebirths are kept in the history
+pub const MAX_REBIRTH_HISTORY: usize = 100;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RebirthCause {
+    Death,
+    Manual,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RebirthRecord {
+    pub points_gained: BigFloat,
+    pub enemy_reached: u32,
+    pub cause: RebirthCause,
+}
+
+// Rebirth points live here rather than on the player entity, which is replaced on every rebirth.
+// `points` is the spendable balance; the rebirth stat bonus follows `lifetime_points`.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct RebirthCurrency {
+    pub points: BigFloat,
+    pub lifetime_points: BigFloat,
+    pub rebirth_count: u32,
+    pub history: VecDeque<RebirthRecord>,
+}
+
+impl Default for RebirthCurrency {
+    fn default() -> Self {
+        Self {
+            points: BigFloat::from(0.0),
+            lifetime_points: BigFloat::from(0.0),
+            rebirth_count: 0,
+            history: VecDeque::new(),
+        }
+    }
+}
+
+impl RebirthCurrency {
+    pub fn record(&mut self, points_gained: BigFloat, enemy_reached: u32, cause: RebirthCause) {
+        self.points += points_gained;
+        self.lifetime_points += points_gained;
+        self.rebirth_count += 1;
+        self.history.push_back(RebirthRecord { points_gained, enemy_reached, cause });
+        while self.history.len() > MAX_REBIRTH_HISTORY {
+            self.history.pop_front();
+        }
+    }
+}
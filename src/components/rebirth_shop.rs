@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+use crate::components::{RebirthCurrency, RebirthShopBalance};
+
+// Permanent perks bought with rebirth points
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RebirthPerk {
+    ExpMultiplier,
+    StartingLevels,
+    CheaperHp,
+    CheaperAttack,
+    CheaperDefense,
+    CheaperSpeed,
+    FasterCooldown,
+}
+
+impl RebirthPerk {
+    pub const ALL: [RebirthPerk; 7] = [
+        RebirthPerk::ExpMultiplier,
+        RebirthPerk::StartingLevels,
+        RebirthPerk::CheaperHp,
+        RebirthPerk::CheaperAttack,
+        RebirthPerk::CheaperDefense,
+        RebirthPerk::CheaperSpeed,
+        RebirthPerk::FasterCooldown,
+    ];
+
+    // Per-stat cost perks in the HP/Attack/Defense/Speed order of the upgrade entities
+    pub const CHEAPER_STATS: [RebirthPerk; 4] = [
+        RebirthPerk::CheaperHp,
+        RebirthPerk::CheaperAttack,
+        RebirthPerk::CheaperDefense,
+        RebirthPerk::CheaperSpeed,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RebirthPerk::ExpMultiplier => "EXP Boost",
+            RebirthPerk::StartingLevels => "Head Start",
+            RebirthPerk::CheaperHp => "Cheaper HP",
+            RebirthPerk::CheaperAttack => "Cheaper Attack",
+            RebirthPerk::CheaperDefense => "Cheaper Defense",
+            RebirthPerk::CheaperSpeed => "Cheaper Speed",
+            RebirthPerk::FasterCooldown => "Quick Strikes",
+        }
+    }
+}
+
+// Perk levels bought so far. EXP and cooldown perks apply immediately;
+// starting levels and cost reductions are applied by rebirth_player_system.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct RebirthShop {
+    pub exp_multiplier: u32,
+    pub starting_levels: u32,
+    pub cheaper_hp: u32,
+    pub cheaper_attack: u32,
+    pub cheaper_defense: u32,
+    pub cheaper_speed: u32,
+    pub faster_cooldown: u32,
+}
+
+impl RebirthShop {
+    pub fn level(&self, perk: RebirthPerk) -> u32 {
+        match perk {
+            RebirthPerk::ExpMultiplier => self.exp_multiplier,
+            RebirthPerk::StartingLevels => self.starting_levels,
+            RebirthPerk::CheaperHp => self.cheaper_hp,
+            RebirthPerk::CheaperAttack => self.cheaper_attack,
+            RebirthPerk::CheaperDefense => self.cheaper_defense,
+            RebirthPerk::CheaperSpeed => self.cheaper_speed,
+            RebirthPerk::FasterCooldown => self.faster_cooldown,
+        }
+    }
+
+    fn level_mut(&mut self, perk: RebirthPerk) -> &mut u32 {
+        match perk {
+            RebirthPerk::ExpMultiplier => &mut self.exp_multiplier,
+            RebirthPerk::StartingLevels => &mut self.starting_levels,
+            RebirthPerk::CheaperHp => &mut self.cheaper_hp,
+            RebirthPerk::CheaperAttack => &mut self.cheaper_attack,
+            RebirthPerk::CheaperDefense => &mut self.cheaper_defense,
+            RebirthPerk::CheaperSpeed => &mut self.cheaper_speed,
+            RebirthPerk::FasterCooldown => &mut self.faster_cooldown,
+        }
+    }
+
+    // Cost of the next level, or None once the perk is maxed
+    pub fn next_cost(&self, perk: RebirthPerk, balance: &RebirthShopBalance) -> Option<BigFloat> {
+        let perk_balance = balance.perk(perk);
+        let level = self.level(perk);
+        if perk_balance.max_level > 0 && level >= perk_balance.max_level {
+            return None;
+        }
+        Some(perk_balance.cost(level))
+    }
+
+    // Spends rebirth points on one level of `perk` if affordable
+    pub fn purchase(&mut self, perk: RebirthPerk, balance: &RebirthShopBalance, currency: &mut RebirthCurrency) -> bool {
+        let Some(cost) = self.next_cost(perk, balance) else { return false };
+        if currency.points < cost {
+            return false;
+        }
+        currency.points = currency.points - cost;
+        *self.level_mut(perk) += 1;
+        true
+    }
+
+    // Multiplier on every EXP gain
+    pub fn exp_multiplier(&self, balance: &RebirthShopBalance) -> BigFloat {
+        BigFloat::from(balance.exp_multiplier.effect(self.exp_multiplier) + 1.0)
+    }
+
+    // Upgrade level every stat starts at after a rebirth
+    pub fn starting_level(&self, balance: &RebirthShopBalance) -> u32 {
+        balance.starting_levels.effect(self.starting_levels).floor() as u32
+    }
+
+    // Divisor on the starting upgrade cost of one stat (`perk` is one of CHEAPER_STATS)
+    pub fn cost_divisor(&self, perk: RebirthPerk, balance: &RebirthShopBalance) -> BigFloat {
+        BigFloat::from(balance.cheaper_stat.effect(self.level(perk)) + 1.0)
+    }
+
+    // Multiplier on the player's base attack time
+    pub fn attack_time_multiplier(&self, balance: &RebirthShopBalance) -> f64 {
+        1.0 / (balance.faster_cooldown.effect(self.faster_cooldown) + 1.0)
+    }
+}
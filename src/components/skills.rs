@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+
+// Active skills the player can cast with mana
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SkillKind {
+    PowerStrike,
+    Heal,
+    Shield,
+}
+
+impl SkillKind {
+    pub const COUNT: usize = 3;
+    pub const ALL: [SkillKind; Self::COUNT] = [SkillKind::PowerStrike, SkillKind::Heal, SkillKind::Shield];
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SkillKind::PowerStrike => "Power Strike",
+            SkillKind::Heal => "Heal",
+            SkillKind::Shield => "Shield",
+        }
+    }
+}
+
+// Spent by skills, refills over time while in the dungeon
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Mana {
+    pub current: f64,
+    pub max: f64,
+}
+
+impl Mana {
+    pub fn full(max: f64) -> Self {
+        Self { current: max, max }
+    }
+}
+
+// Seconds until each skill can be cast again, indexed like SkillKind::ALL
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct SkillCooldowns(pub [f32; SkillKind::COUNT]);
+
+impl SkillCooldowns {
+    pub fn remaining(&self, skill: SkillKind) -> f32 {
+        self.0[skill.index()]
+    }
+
+    pub fn is_ready(&self, skill: SkillKind) -> bool {
+        self.remaining(skill) <= 0.0
+    }
+}
+
+// Takes attack damage before HP until `absorb` is used up or the time runs out
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Shielded {
+    pub absorb: BigFloat,
+    pub remaining_secs: f32,
+}
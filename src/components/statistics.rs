@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+
+// Counters kept both for the current run and for the whole save
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatTotals {
+    pub damage_dealt: BigFloat,
+    pub damage_taken: BigFloat,
+    pub kills: u64,
+    pub highest_enemy: u32,
+    pub exp_earned: BigFloat,
+    pub time_played_secs: f64,
+}
+
+impl Default for StatTotals {
+    fn default() -> Self {
+        Self {
+            damage_dealt: BigFloat::from(0.0),
+            damage_taken: BigFloat::from(0.0),
+            kills: 0,
+            highest_enemy: 0,
+            exp_earned: BigFloat::from(0.0),
+            time_played_secs: 0.0,
+        }
+    }
+}
+
+// Session statistics. Deaths and rebirths only make sense lifetime-wide;
+// a rebirth (see statistics_system) starts a fresh `run`.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct Statistics {
+    pub run: StatTotals,
+    pub lifetime: StatTotals,
+    pub deaths: u32,
+    pub rebirths: u32,
+}
+
+impl Statistics {
+    fn both(&mut self, update: impl Fn(&mut StatTotals)) {
+        update(&mut self.run);
+        update(&mut self.lifetime);
+    }
+
+    pub fn record_damage_dealt(&mut self, damage: BigFloat) {
+        self.both(|totals| totals.damage_dealt += damage);
+    }
+
+    pub fn record_damage_taken(&mut self, damage: BigFloat) {
+        self.both(|totals| totals.damage_taken += damage);
+    }
+
+    pub fn record_kill(&mut self, enemy_number: u32) {
+        self.both(|totals| {
+            totals.kills += 1;
+            totals.highest_enemy = totals.highest_enemy.max(enemy_number);
+        });
+    }
+
+    pub fn record_exp(&mut self, exp: BigFloat) {
+        self.both(|totals| totals.exp_earned += exp);
+    }
+
+    pub fn record_time(&mut self, secs: f64) {
+        self.both(|totals| totals.time_played_secs += secs);
+    }
+
+    // Catch up with RebirthCurrency's count; each new rebirth starts a fresh run
+    pub fn sync_rebirths(&mut self, rebirth_count: u32) {
+        if rebirth_count > self.rebirths {
+            self.rebirths = rebirth_count;
+            self.run = StatTotals::default();
+        }
+    }
+}
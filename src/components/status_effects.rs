@@ -0,0 +1,120 @@
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+
+// Seconds between two damage-over-time ticks
+pub const DOT_TICK_SECS: f32 = 1.0;
+
+// Timed effects a landed attack can leave on its target
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatusKind {
+    Poison,
+    Burn,
+    Stun,
+    Slow,
+}
+
+impl StatusKind {
+    pub const ALL: [StatusKind; 4] = [StatusKind::Poison, StatusKind::Burn, StatusKind::Stun, StatusKind::Slow];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StatusKind::Poison => "Poison",
+            StatusKind::Burn => "Burn",
+            StatusKind::Stun => "Stun",
+            StatusKind::Slow => "Slow",
+        }
+    }
+
+    // "Enemy #3 is poisoned"
+    pub fn applied(&self) -> &'static str {
+        match self {
+            StatusKind::Poison => "poisoned",
+            StatusKind::Burn => "burning",
+            StatusKind::Stun => "stunned",
+            StatusKind::Slow => "slowed",
+        }
+    }
+}
+
+// Deals `damage_per_tick` every DOT_TICK_SECS, `ticks_left` more times
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DamageOverTime {
+    pub damage_per_tick: BigFloat,
+    pub ticks_left: u32,
+    pub next_tick_secs: f32,
+}
+
+impl DamageOverTime {
+    pub fn new(damage_per_tick: BigFloat, duration_secs: f32) -> Self {
+        Self {
+            damage_per_tick,
+            ticks_left: (duration_secs / DOT_TICK_SECS).round() as u32,
+            next_tick_secs: DOT_TICK_SECS,
+        }
+    }
+
+    // Advance by `delta_secs`; returns how many ticks fell due
+    pub fn advance(&mut self, delta_secs: f32) -> u32 {
+        self.next_tick_secs -= delta_secs;
+        let mut ticks = 0;
+        while self.ticks_left > 0 && self.next_tick_secs <= 0.0 {
+            self.ticks_left -= 1;
+            self.next_tick_secs += DOT_TICK_SECS;
+            ticks += 1;
+        }
+        ticks
+    }
+
+    pub fn expired(&self) -> bool {
+        self.ticks_left == 0
+    }
+
+    pub fn remaining_secs(&self) -> f32 {
+        match self.ticks_left {
+            0 => 0.0,
+            ticks => self.next_tick_secs + (ticks - 1) as f32 * DOT_TICK_SECS,
+        }
+    }
+}
+
+// Reapplying an effect replaces the old one
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Poisoned(pub DamageOverTime);
+
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Burning(pub DamageOverTime);
+
+// AttackCooldown does not drain and no attacks are made
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Stunned {
+    pub remaining_secs: f32,
+}
+
+// AttackCooldown drains at `factor` times its normal rate
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Slowed {
+    pub factor: f32,
+    pub remaining_secs: f32,
+}
+
+// Every status effect a combatant may carry, for display
+#[derive(QueryData)]
+pub struct StatusEffects {
+    poisoned: Option<&'static Poisoned>,
+    burning: Option<&'static Burning>,
+    stunned: Option<&'static Stunned>,
+    slowed: Option<&'static Slowed>,
+}
+
+impl StatusEffectsItem<'_> {
+    // Active effects with their remaining seconds, in StatusKind::ALL order
+    pub fn active(&self) -> Vec<(StatusKind, f32)> {
+        [
+            self.poisoned.map(|effect| (StatusKind::Poison, effect.0.remaining_secs())),
+            self.burning.map(|effect| (StatusKind::Burn, effect.0.remaining_secs())),
+            self.stunned.map(|effect| (StatusKind::Stun, effect.remaining_secs)),
+            self.slowed.map(|effect| (StatusKind::Slow, effect.remaining_secs)),
+        ].into_iter().flatten().collect()
+    }
+}
@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::upgradeable_stats::StatKind;
+
+// How the auto-upgrader spends EXP
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+pub enum UpgradeStrategyKind {
+    /// Always buy the cheapest affordable upgrade
+    #[default]
+    CheapestFirst,
+    /// Buy every stat in turn, waiting for the next one
+    RoundRobin,
+    /// Keep upgrade levels in proportion to --upgrade-weights
+    Weighted,
+    /// Buy the upgrade that improves the current fight the most per EXP
+    MaxEfficiency,
+}
+
+impl UpgradeStrategyKind {
+    pub const ALL: [UpgradeStrategyKind; 4] = [
+        UpgradeStrategyKind::CheapestFirst,
+        UpgradeStrategyKind::RoundRobin,
+        UpgradeStrategyKind::Weighted,
+        UpgradeStrategyKind::MaxEfficiency,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            UpgradeStrategyKind::CheapestFirst => "Cheapest First",
+            UpgradeStrategyKind::RoundRobin => "Round Robin",
+            UpgradeStrategyKind::Weighted => "Weighted",
+            UpgradeStrategyKind::MaxEfficiency => "Max DPS/EHP",
+        }
+    }
+
+    // Next strategy when cycling through them in the Automation tab
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|kind| kind == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+// Active auto-upgrade strategy. Stats are indexed in StatKind order.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct UpgradeStrategy {
+    // Off leaves all spending to the manual upgrade buttons
+    pub enabled: bool,
+    pub kind: UpgradeStrategyKind,
+    // Target level ratios for Weighted; a weight of 0 never buys that stat
+    pub weights: [f64; StatKind::COUNT],
+    // Stat RoundRobin buys next
+    pub round_robin_next: usize,
+}
+
+impl Default for UpgradeStrategy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            kind: UpgradeStrategyKind::default(),
+            weights: [1.0; StatKind::COUNT],
+            round_robin_next: 0,
+        }
+    }
+}
+
+impl UpgradeStrategy {
+    pub fn new(kind: UpgradeStrategyKind) -> Self {
+        Self { kind, ..default() }
+    }
+
+    // Weights from --upgrade-weights: the four base stats, optionally followed by the four
+    // ratings. At least one must be positive or Weighted would never buy anything.
+    pub fn set_weights(&mut self, weights: &[f64]) -> Result<(), String> {
+        if ![4, StatKind::COUNT].contains(&weights.len()) || weights.iter().any(|weight| !weight.is_finite() || *weight < 0.0) {
+            return Err("needs four or eight non-negative numbers \
+                (HP,Attack,Defense,Speed[,CritChance,CritMultiplier,Evasion,Accuracy])".to_string());
+        }
+        if weights.iter().all(|weight| *weight == 0.0) {
+            return Err("needs at least one positive weight".to_string());
+        }
+        self.weights[..weights.len()].copy_from_slice(weights);
+        Ok(())
+    }
+
+    // Called after each purchase so RoundRobin moves on to the following stat
+    pub fn bought(&mut self, index: usize) {
+        self.round_robin_next = (index + 1) % self.weights.len();
+    }
+}
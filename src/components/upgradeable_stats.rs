@@ -1,3 +1,4 @@
+use bevy::ecs::query::QueryData;
 use bevy::prelude::*;
 use too_big_float::BigFloat;
 
@@ -48,6 +49,155 @@ pub struct UpgradeableDefense;
 #[derive(Component, Clone, Debug)]
 pub struct UpgradeableSpeed;
 
+#[derive(Component, Clone, Debug)]
+pub struct UpgradeableCritChance;
+
+#[derive(Component, Clone, Debug)]
+pub struct UpgradeableCritMultiplier;
+
+#[derive(Component, Clone, Debug)]
+pub struct UpgradeableEvasion;
+
+#[derive(Component, Clone, Debug)]
+pub struct UpgradeableAccuracy;
+
+// Which upgradeable stat a UI row or request refers to, in spawn order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StatKind {
+    Hp,
+    Attack,
+    Defense,
+    Speed,
+    CritChance,
+    CritMultiplier,
+    Evasion,
+    Accuracy,
+}
+
+impl StatKind {
+    pub const COUNT: usize = 8;
+
+    pub const ALL: [StatKind; StatKind::COUNT] = [
+        StatKind::Hp,
+        StatKind::Attack,
+        StatKind::Defense,
+        StatKind::Speed,
+        StatKind::CritChance,
+        StatKind::CritMultiplier,
+        StatKind::Evasion,
+        StatKind::Accuracy,
+    ];
+
+    // Combat ratings: no level or rebirth bonuses and no cost perk
+    pub const RATINGS: [StatKind; 4] = [StatKind::CritChance, StatKind::CritMultiplier, StatKind::Evasion, StatKind::Accuracy];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StatKind::Hp => "HP",
+            StatKind::Attack => "Attack",
+            StatKind::Defense => "Defense",
+            StatKind::Speed => "Speed",
+            StatKind::CritChance => "Crit Chance",
+            StatKind::CritMultiplier => "Crit Multiplier",
+            StatKind::Evasion => "Evasion",
+            StatKind::Accuracy => "Accuracy",
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    // Value past which further levels do nothing; crit chance is a probability
+    pub fn cap(&self) -> Option<f64> {
+        match self {
+            StatKind::CritChance => Some(1.0),
+            _ => None,
+        }
+    }
+
+    // Levels that still raise `value` when each one multiplies it by `multiplier`;
+    // the level that crosses the cap counts, u32::MAX for uncapped stats
+    pub fn levels_to_cap(&self, value: BigFloat, multiplier: f64) -> u32 {
+        let Some(cap) = self.cap() else { return u32::MAX };
+        let value = value.to_f64().unwrap_or(f64::INFINITY);
+        if value >= cap {
+            0
+        } else if value <= 0.0 || multiplier <= 1.0 {
+            u32::MAX
+        } else {
+            ((cap / value).ln() / multiplier.ln()).ceil().max(1.0) as u32
+        }
+    }
+}
+
+// Marker components of an upgrade entity, to tell which StatKind it is
+#[derive(QueryData)]
+pub struct StatMarkers {
+    hp: Has<UpgradeableHp>,
+    attack: Has<UpgradeableAttack>,
+    defense: Has<UpgradeableDefense>,
+    speed: Has<UpgradeableSpeed>,
+    crit_chance: Has<UpgradeableCritChance>,
+    crit_multiplier: Has<UpgradeableCritMultiplier>,
+    evasion: Has<UpgradeableEvasion>,
+    accuracy: Has<UpgradeableAccuracy>,
+}
+
+impl StatMarkersItem<'_> {
+    pub fn kind(&self) -> StatKind {
+        if self.hp {
+            StatKind::Hp
+        } else if self.attack {
+            StatKind::Attack
+        } else if self.defense {
+            StatKind::Defense
+        } else if self.speed {
+            StatKind::Speed
+        } else if self.crit_chance {
+            StatKind::CritChance
+        } else if self.crit_multiplier {
+            StatKind::CritMultiplier
+        } else if self.evasion {
+            StatKind::Evasion
+        } else {
+            debug_assert!(self.accuracy, "upgrade entity without a stat marker");
+            StatKind::Accuracy
+        }
+    }
+}
+
+// How many levels a manual purchase buys at most
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BuyAmount {
+    One,
+    Ten,
+    Hundred,
+    Max,
+}
+
+impl BuyAmount {
+    pub const ALL: [BuyAmount; 4] = [BuyAmount::One, BuyAmount::Ten, BuyAmount::Hundred, BuyAmount::Max];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            BuyAmount::One => "x1",
+            BuyAmount::Ten => "x10",
+            BuyAmount::Hundred => "x100",
+            BuyAmount::Max => "Max",
+        }
+    }
+
+    pub fn limit(&self) -> u32 {
+        match self {
+            BuyAmount::One => 1,
+            BuyAmount::Ten => 10,
+            BuyAmount::Hundred => 100,
+            BuyAmount::Max => u32::MAX,
+        }
+    }
+}
+
 // Entity creation helper bundles
 #[derive(Bundle)]
 pub struct UpgradeableStatBundle {
@@ -215,23 +365,73 @@ pub fn recalculate_current_value(
     level: &UpgradeLevel,
     multiplier: &UpgradeMultiplier,
 ) -> BigFloat {
-    let mut result = base_value.0;
-    for _ in 0..level.0 {
-        result = result * BigFloat::from(multiplier.0);
-    }
-    result
+    calculate_exponential_growth(base_value.0, multiplier.0, level.0)
 }
 
-// Backward compatibility function
+// base * multiplier^level
 pub fn calculate_exponential_growth(
     base: BigFloat,
     multiplier: f64,
     level: u32,
 ) -> BigFloat {
-    let multiplier_bf = BigFloat::from(multiplier);
-    let mut result = base;
-    for _ in 0..level {
-        result = result * multiplier_bf;
+    base * big_pow(BigFloat::from(multiplier), level)
+}
+
+// base^exponent by repeated squaring, O(log exponent) multiplications
+pub fn big_pow(base: BigFloat, exponent: u32) -> BigFloat {
+    let mut result = BigFloat::from(1.0);
+    let mut square = base;
+    let mut remaining = exponent;
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result = result * square;
+        }
+        remaining >>= 1;
+        if remaining > 0 {
+            square = square * square;
+        }
     }
     result
-}
\ No newline at end of file
+}
+
+// Total price of `levels` consecutive purchases starting at `cost`, each `multiplier` times the last:
+// cost * (multiplier^levels - 1) / (multiplier - 1)
+pub fn geometric_cost_sum(cost: BigFloat, multiplier: f64, levels: u32) -> BigFloat {
+    let one = BigFloat::from(1.0);
+    let growth = big_pow(BigFloat::from(multiplier), levels);
+    if multiplier > 1.0 {
+        cost * (growth - one) / BigFloat::from(multiplier - 1.0)
+    } else if multiplier < 1.0 {
+        cost * (one - growth) / BigFloat::from(1.0 - multiplier)
+    } else {
+        cost * BigFloat::from(levels as f64)
+    }
+}
+
+// Largest n <= `limit` whose geometric_cost_sum fits in `budget`, and that sum.
+// Doubles an upper bound and then bisects, so huge counts take O(log^2 n) multiplications.
+pub fn levels_within(budget: BigFloat, cost: BigFloat, multiplier: f64, limit: u32) -> (u32, BigFloat) {
+    let total_for = |levels: u64| geometric_cost_sum(cost, multiplier, levels as u32);
+    let limit = limit as u64;
+    if limit == 0 || total_for(1) > budget {
+        return (0, BigFloat::from(0.0));
+    }
+
+    // `low` always fits, `high` never does (or is past the limit)
+    let mut low = 1;
+    let mut high = 2;
+    while high <= limit && total_for(high) <= budget {
+        low = high;
+        high *= 2;
+    }
+    let mut high = high.min(limit + 1);
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if total_for(mid) <= budget {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    (low as u32, total_for(low))
+}
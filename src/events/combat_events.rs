@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use too_big_float::BigFloat;
+use crate::components::{BuyAmount, RebirthPerk, SkillKind, StatKind, StatusKind};
 
 // Combat-related events for loose coupling between systems
 #[derive(Event)]
@@ -17,6 +18,44 @@ pub struct PlayerDeathEvent {
     pub player_entity: Entity,
 }
 
+// Player fell to a boss - handled as a retreat instead of a death
+#[derive(Event)]
+pub struct BossFailedEvent {
+    pub player_entity: Entity,
+    pub enemy_number: u32,
+}
+
+// Player asked to rebirth from the Rebirth tab
+#[derive(Event)]
+pub struct RebirthRequestEvent;
+
+// Player bought upgrades by hand from the Combat tab
+#[derive(Event)]
+pub struct ManualUpgradeEvent {
+    pub stat: StatKind,
+    pub amount: BuyAmount,
+}
+
+// Player (or auto-cast) asked to cast a skill; ignored unless it is ready and affordable
+#[derive(Event)]
+pub struct SkillCastEvent {
+    pub skill: SkillKind,
+}
+
+// A skill was cast; `amount` is the HP healed or damage absorbed (zero for Power Strike,
+// whose damage arrives as an AttackEvent)
+#[derive(Event)]
+pub struct SkillUsedEvent {
+    pub skill: SkillKind,
+    pub amount: BigFloat,
+}
+
+// Player asked to buy one level of a rebirth shop perk
+#[derive(Event)]
+pub struct RebirthShopPurchaseEvent {
+    pub perk: RebirthPerk,
+}
+
 #[derive(Event)]
 pub struct EnemyDeathEvent {
     pub enemy_entity: Entity,
@@ -44,7 +83,18 @@ pub struct TurnStartEvent {
 pub struct AttackEvent {
     pub attacker: Entity,
     pub target: Entity,
+    // Zero when the attack missed
     pub damage: BigFloat,
+    pub critical: bool,
+    pub missed: bool,
+}
+
+// A status effect was applied to `target` (damage None) or dealt damage over time to it
+#[derive(Event)]
+pub struct StatusEffectEvent {
+    pub target: Entity,
+    pub kind: StatusKind,
+    pub damage: Option<BigFloat>,
 }
 
 #[derive(Event)]
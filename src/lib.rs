@@ -5,16 +5,40 @@ pub mod systems;
 pub mod events;
 pub mod ui;
 pub mod plugins;
+pub mod number_format;
 
 #[cfg(test)]
 pub mod tests {
+    pub mod fixtures;
     pub mod components_tests;
     pub mod systems_tests;
     pub mod integration_tests;
     pub mod real_time_combat_tests;
+    pub mod save_tests;
+    pub mod offline_progress_tests;
+    pub mod balance_config_tests;
+    pub mod balance_report_tests;
+    pub mod accelerated_time_tests;
+    pub mod sweep_tests;
+    pub mod enemy_archetype_tests;
+    pub mod boss_tests;
+    pub mod rebirth_tests;
+    pub mod rebirth_shop_tests;
+    pub mod upgrade_strategy_tests;
+    pub mod manual_upgrade_tests;
+    pub mod growth_math_tests;
+    pub mod leveling_tests;
+    pub mod achievement_tests;
+    pub mod statistics_tests;
+    pub mod combat_log_tests;
+    pub mod number_format_tests;
+    pub mod combat_roll_tests;
+    pub mod damage_formula_tests;
+    pub mod status_effect_tests;
+    pub mod skill_tests;
 }
 
-#[derive(Resource)]
+#[derive(Resource, Clone, Debug)]
 pub struct StartupConfig {
     pub level: u32,
     pub experience: u64,
@@ -29,4 +53,5 @@ pub use components::*;
 pub use systems::*;
 pub use events::*;
 pub use ui::*;
-pub use plugins::*;
\ No newline at end of file
+pub use plugins::*;
+pub use number_format::*;
\ No newline at end of file
@@ -1,10 +1,17 @@
+use bevy::app::ScheduleRunnerPlugin;
+use bevy::log::{Level, LogPlugin};
 use bevy::prelude::*;
-use clap::Parser;
+use bevy::time::TimeUpdateStrategy;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 use simple_idle_rpg::*;
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Enable balance check mode (no UI, auto-report game state)
     #[arg(short, long)]
     balance_check: bool,
@@ -36,13 +43,273 @@ struct Args {
     /// Duration for balance check mode in seconds
     #[arg(long, default_value = "60")]
     duration: u64,
+
+    /// Simulate this many seconds with fixed time steps as fast as possible (replaces --duration)
+    #[arg(long)]
+    simulated_duration: Option<u64>,
+
+    /// Run fixed time steps at this multiple of real-time speed (e.g. 100 = 100x, at least 0.01)
+    #[arg(long, value_parser = parse_time_scale)]
+    time_scale: Option<f64>,
+
+    /// Balance check report format
+    #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+    report_format: ReportFormat,
+
+    /// Write balance check reports to this file instead of stdout
+    #[arg(long)]
+    report_file: Option<PathBuf>,
+
+    /// Balance constants file (RON); missing fields use the built-in defaults
+    #[arg(long, global = true)]
+    balance_config: Option<PathBuf>,
+
+    /// Damage formula overriding the balance config; sweeps accept a list and run every
+    /// combination under each formula, e.g. subtractive,ratio,mitigation
+    #[arg(long, value_enum, value_delimiter = ',', global = true)]
+    damage_formula: Option<Vec<DamageFormula>>,
+
+    /// Auto-upgrade strategy (a loaded save keeps its own in normal mode)
+    #[arg(long, value_enum, global = true)]
+    upgrade_strategy: Option<UpgradeStrategyKind>,
+
+    /// HP,Attack,Defense,Speed[,CritChance,CritMultiplier,Evasion,Accuracy] level ratios
+    /// for the weighted strategy, e.g. 2,1,1,0.5 (left-out combat ratings keep weight 1)
+    #[arg(long, value_delimiter = ',', global = true)]
+    upgrade_weights: Option<Vec<f64>>,
+
+    /// Seed for every random roll; the same seed and starting state replay the same run
+    /// (a loaded save keeps its own in normal mode)
+    #[arg(long, global = true, default_value_t = DEFAULT_SEED)]
+    seed: u64,
+
+    /// Do not cast skills automatically in balance checks and sweeps
+    #[arg(long, global = true)]
+    no_auto_cast: bool,
+
+    /// Save file used in normal mode (loaded on startup, autosaved while playing)
+    #[arg(long, default_value = "savegame.ron")]
+    save_file: PathBuf,
+
+    /// How large numbers are written in the UI and text reports (a loaded save keeps its own in normal mode)
+    #[arg(long, value_enum)]
+    number_notation: Option<NumberNotation>,
+
+    /// Log verbosity; attacks and spawns are logged at debug, HP changes at trace
+    #[arg(long, value_enum, global = true, default_value_t = LogLevel::Info)]
+    log_level: LogLevel,
+
+    /// Per-target log levels on top of --log-level, e.g. combat=warn,upgrades=debug
+    /// (targets: combat, upgrades, rebirth, progress, offline, save, balance)
+    #[arg(long, global = true)]
+    log_filter: Option<String>,
+}
+
+// Slowest --time-scale; one 60 Hz step then takes under two seconds of wall time
+const MIN_TIME_SCALE: f64 = 0.01;
+
+fn parse_time_scale(value: &str) -> Result<f64, String> {
+    let scale: f64 = value.parse().map_err(|err| format!("{}", err))?;
+    if !scale.is_finite() || scale < MIN_TIME_SCALE {
+        return Err(format!("must be a finite number of at least {}", MIN_TIME_SCALE));
+    }
+    Ok(scale)
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevel> for Level {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => Level::ERROR,
+            LogLevel::Warn => Level::WARN,
+            LogLevel::Info => Level::INFO,
+            LogLevel::Debug => Level::DEBUG,
+            LogLevel::Trace => Level::TRACE,
+        }
+    }
+}
+
+// ログ設定：--log-filter はBevy既定のフィルタ（wgpu等）の後ろに追加
+fn log_plugin(level: LogLevel, filter: Option<&str>) -> LogPlugin {
+    let defaults = LogPlugin::default();
+    let filter = match filter {
+        Some(filter) => format!("{},{}", defaults.filter, filter),
+        None => defaults.filter.clone(),
+    };
+    LogPlugin {
+        level: level.into(),
+        filter,
+        ..defaults
+    }
+}
+
+// LogPlugin installs the global logger as soon as it is built, so building it on its own
+// up front lets the startup lines go through it too; the mode apps then leave it out
+fn init_logging(level: LogLevel, filter: Option<&str>) {
+    App::new().add_plugins(log_plugin(level, filter));
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run every combination of starting parameters headlessly and print a summary table
+    Sweep(SweepArgs),
+}
+
+/// Ranges accept N, A..B (inclusive) or A..B:STEP
+#[derive(clap::Args)]
+struct SweepArgs {
+    /// Initial player level range
+    #[arg(long, default_value = "1")]
+    level: SweepRange,
+
+    /// Initial experience range
+    #[arg(long, default_value = "0")]
+    experience: SweepRange,
+
+    /// HP upgrade level range
+    #[arg(long, default_value = "0")]
+    hp_level: SweepRange,
+
+    /// Attack upgrade level range
+    #[arg(long, default_value = "0")]
+    attack_level: SweepRange,
+
+    /// Defense upgrade level range
+    #[arg(long, default_value = "0")]
+    defense_level: SweepRange,
+
+    /// Speed upgrade level range
+    #[arg(long, default_value = "0")]
+    speed_level: SweepRange,
+
+    /// Simulated seconds per combination
+    #[arg(long, default_value = "600")]
+    duration: u64,
+
+    /// Output format for the result table
+    #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+    format: ReportFormat,
+
+    /// Write the result table to this file instead of stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+// パラメータスイープ：全組み合わせを固定ステップで実行して結果表を出力
+fn run_sweep(
+    balance: &BalanceConfig,
+    formulas: &[DamageFormula],
+    strategy: &UpgradeStrategy,
+    seed: u64,
+    auto_cast: bool,
+    notation: NumberNotation,
+    sweep: SweepArgs,
+) {
+    let ranges = SweepRanges {
+        level: sweep.level,
+        experience: sweep.experience,
+        hp_level: sweep.hp_level,
+        attack_level: sweep.attack_level,
+        defense_level: sweep.defense_level,
+        speed_level: sweep.speed_level,
+    };
+    let configs = ranges.configs(sweep.duration);
+    let total = configs.len() * formulas.len();
+    info!(target: "balance", "Seed: {}", seed);
+
+    // 各ダメージ式ごとに全組み合わせを実行（比較用）
+    let mut results = Vec::with_capacity(total);
+    for &formula in formulas {
+        let mut balance = balance.clone();
+        balance.damage.formula = formula;
+        for config in configs.iter().cloned() {
+            info!(
+                target: "balance",
+                "Sweep {}/{}: Level={}, Exp={}, HP_LV={}, ATK_LV={}, DEF_LV={}, SPD_LV={}, Damage={}",
+                results.len() + 1, total, config.level, config.experience,
+                config.hp_level, config.attack_level, config.defense_level, config.speed_level, formula.label()
+            );
+            results.push(run_sweep_case(&balance, strategy, seed, auto_cast, config));
+        }
+    }
+
+    let written = match &sweep.output {
+        Some(path) => std::fs::File::create(path)
+            .and_then(|mut file| write_sweep_results(&mut file, sweep.format, &results, notation)),
+        None => {
+            info!(target: "balance", "=== Sweep Results ({}s simulated per combination) ===", sweep.duration);
+            write_sweep_results(&mut std::io::stdout(), sweep.format, &results, notation)
+        }
+    };
+    if let Err(err) = written {
+        eprintln!("Failed to write sweep results: {}", err);
+        std::process::exit(1);
+    }
 }
 
 fn main() {
     let args = Args::parse();
-    
+    init_logging(args.log_level, args.log_filter.as_deref());
+
+    // バランス設定の読み込み（指定がなければデフォルト値）
+    let mut balance = match &args.balance_config {
+        Some(path) => match BalanceConfig::load(path) {
+            Ok(balance) => {
+                info!(target: "balance", "Loaded balance config from {}", path.display());
+                balance
+            }
+            Err(err) => {
+                eprintln!("Failed to load balance config {}: {}", path.display(), err);
+                std::process::exit(1);
+            }
+        },
+        None => BalanceConfig::default(),
+    };
+
+    // 自動アップグレード戦略（--upgrade-weights は weighted 用）
+    let mut strategy = UpgradeStrategy::new(args.upgrade_strategy.unwrap_or_default());
+    if let Err(err) = args.upgrade_weights.as_deref().map_or(Ok(()), |weights| strategy.set_weights(weights)) {
+        eprintln!("--upgrade-weights {}", err);
+        std::process::exit(1);
+    }
+    if args.upgrade_strategy.is_some() || args.upgrade_weights.is_some() {
+        info!(target: "balance", "Upgrade strategy: {} (weights {:?})", strategy.kind.label(), strategy.weights);
+    }
+
+    // ダメージ式（スイープ以外は1つだけ指定可能）
+    let formulas = args.damage_formula.clone().unwrap_or_else(|| vec![balance.damage.formula]);
+    if let Some(Command::Sweep(sweep)) = args.command {
+        run_sweep(&balance, &formulas, &strategy, args.seed, !args.no_auto_cast, args.number_notation.unwrap_or_default(), sweep);
+        return;
+    }
+    let [formula] = formulas[..] else {
+        eprintln!("--damage-formula takes a single formula outside the sweep subcommand");
+        std::process::exit(1);
+    };
+    balance.damage.formula = formula;
+
     let mut app = App::new();
+    app.insert_resource(balance);
+    if args.balance_check || args.upgrade_strategy.is_some() || args.upgrade_weights.is_some() {
+        app.insert_resource(strategy);
+    }
+    if let Some(notation) = args.number_notation {
+        app.insert_resource(DisplaySettings { notation });
+    }
+    // 乱数シード（通常モードではセーブデータの乱数状態で上書き）
+    app.insert_resource(GameRng::from_seed(args.seed));
     
+    let duration = args.simulated_duration.unwrap_or(args.duration);
+    let accelerated = args.simulated_duration.is_some() || args.time_scale.is_some();
+
     // 起動設定をリソースとして追加
     app.insert_resource(StartupConfig {
         level: args.level,
@@ -51,36 +318,60 @@ fn main() {
         attack_level: args.attack_level,
         defense_level: args.defense_level,
         speed_level: args.speed_level,
-        duration: args.duration,
+        duration,
     });
     
     if args.balance_check {
-        println!("Starting in Balance Check Mode...");
+        info!(target: "balance", "Starting in Balance Check Mode...");
         if args.level > 1 || args.experience > 0 || 
            args.hp_level > 0 || args.attack_level > 0 || 
            args.defense_level > 0 || args.speed_level > 0 {
-            println!("Initial State: Level={}, Exp={}, HP_LV={}, ATK_LV={}, DEF_LV={}, SPD_LV={}", 
+            info!(target: "balance", "Initial State: Level={}, Exp={}, HP_LV={}, ATK_LV={}, DEF_LV={}, SPD_LV={}", 
                      args.level, args.experience, args.hp_level, 
                      args.attack_level, args.defense_level, args.speed_level);
         }
-        println!("Duration: {} seconds", args.duration);
+        info!(target: "balance", "Duration: {} seconds", duration);
+        info!(target: "balance", "Seed: {}", args.seed);
+        info!(target: "balance", "Damage formula: {}", formula.label());
         
+        app.insert_resource(BalanceReportConfig {
+            format: args.report_format,
+            file: args.report_file,
+        });
+
         // ヘッドレスモード：UIなし、最小限のプラグイン
-        app.add_plugins(MinimalPlugins)
-           .add_plugins((
+        if accelerated {
+            // 固定ステップで時間を進める（スリープなし、time_scale 指定時のみ待機）
+            let runner = match args.time_scale {
+                Some(scale) => ScheduleRunnerPlugin::run_loop(SIMULATION_STEP.div_f64(scale)),
+                None => ScheduleRunnerPlugin::default(),
+            };
+            info!(target: "balance", "Accelerated time: fixed {:?} steps{}", SIMULATION_STEP,
+                  args.time_scale.map(|scale| format!(" at {}x speed", scale)).unwrap_or_default());
+            app.add_plugins(MinimalPlugins.set(runner))
+               .insert_resource(TimeUpdateStrategy::ManualDuration(SIMULATION_STEP));
+        } else {
+            app.add_plugins(MinimalPlugins);
+        }
+        app.add_plugins((
                PlayerPlugin,
                CombatPlugin,
                StatsPlugin,
-               BalanceCheckPlugin,
+               BalanceCheckPlugin { auto_cast: !args.no_auto_cast },
            ));
     } else {
-        // 通常モード：UI付き
-        app.add_plugins(DefaultPlugins)
+        // 通常モード：UI付き、セーブ/ロード有効
+        app.insert_resource(SaveSettings {
+               path: args.save_file,
+               ..default()
+           })
+           .add_plugins(DefaultPlugins.build().disable::<LogPlugin>())
            .add_plugins((
                PlayerPlugin,
                CombatPlugin,
                StatsPlugin,
                UIPlugin,
+               SavePlugin,
            ));
     }
     
@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use too_big_float::BigFloat;
+use crate::components::big_pow;
+use crate::systems::persistence::SavedBigFloat;
+
+// Short-scale suffixes for every power of 1000; larger numbers fall back to scientific
+const SUFFIXES: [&str; 12] = ["", "K", "M", "B", "T", "Qa", "Qi", "Sx", "Sp", "Oc", "No", "Dc"];
+
+// How numbers of 1000 and above are written; smaller ones are always plain decimals
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+pub enum NumberNotation {
+    /// 1.23K, 4.56M, 7.89B, ...
+    #[default]
+    Suffix,
+    /// 1.23e45
+    Scientific,
+    /// Exponents in steps of three: 123.45e42
+    Engineering,
+    /// a = 1e3, b = 1e6, ..., z, aa, ab, ...
+    Letter,
+}
+
+impl NumberNotation {
+    pub const ALL: [NumberNotation; 4] = [
+        NumberNotation::Suffix,
+        NumberNotation::Scientific,
+        NumberNotation::Engineering,
+        NumberNotation::Letter,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            NumberNotation::Suffix => "Suffix (1.23M)",
+            NumberNotation::Scientific => "Scientific (1.23e6)",
+            NumberNotation::Engineering => "Engineering (123.45e3)",
+            NumberNotation::Letter => "Letter (1.23b)",
+        }
+    }
+
+    // Next notation when cycling through them in the Settings tab
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|notation| notation == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    // Two decimals throughout, e.g. 12.50 or 1.23M
+    pub fn format(&self, value: BigFloat) -> String {
+        self.format_with(value, 2)
+    }
+
+    // For counts and points: no decimals below 1000, e.g. 12 or 1.23M
+    pub fn format_whole(&self, value: BigFloat) -> String {
+        self.format_with(value, 0)
+    }
+
+    fn format_with(&self, value: BigFloat, small_decimals: usize) -> String {
+        let Some((negative, mantissa, exponent)) = decimal_parts(value) else {
+            return "NaN".to_string();
+        };
+        if exponent < 3 {
+            return format!("{:.*}", small_decimals, value.to_f64().unwrap_or(0.0));
+        }
+        let sign = if negative { "-" } else { "" };
+
+        let body = match self {
+            NumberNotation::Scientific => {
+                let (mantissa, exponent) = round_mantissa(mantissa, exponent, 0);
+                format!("{:.2}e{}", mantissa, exponent)
+            }
+            NumberNotation::Engineering => {
+                let (mantissa, exponent) = round_mantissa(mantissa, exponent, 3);
+                format!("{:.2}e{}", mantissa, exponent)
+            }
+            NumberNotation::Suffix => {
+                let (mantissa, exponent) = round_mantissa(mantissa, exponent, 3);
+                match SUFFIXES.get((exponent / 3) as usize) {
+                    Some(suffix) => format!("{:.2}{}", mantissa, suffix),
+                    None => return NumberNotation::Scientific.format_with(value, small_decimals),
+                }
+            }
+            NumberNotation::Letter => {
+                let (mantissa, exponent) = round_mantissa(mantissa, exponent, 3);
+                format!("{:.2}{}", mantissa, letters((exponent / 3) as u64))
+            }
+        };
+        format!("{}{}", sign, body)
+    }
+}
+
+// Sign, mantissa in [1, 10) and base-10 exponent, read off the same
+// mantissa * 2^exponent split used for saves so values beyond f64 work too
+fn decimal_parts(value: BigFloat) -> Option<(bool, f64, i64)> {
+    let saved = SavedBigFloat::from(value);
+    if saved.mantissa == 0.0 {
+        return Some((false, 0.0, 0));
+    }
+    let log10 = saved.mantissa.abs().log10() + saved.exponent as f64 * std::f64::consts::LOG10_2;
+    if !log10.is_finite() {
+        return None;
+    }
+    let exponent = log10.floor();
+    Some((saved.mantissa < 0.0, 10f64.powf(log10 - exponent), exponent as i64))
+}
+
+// Scientific text with every digit an f64 mantissa holds, e.g. "1.23456789012345e400", for
+// report files. The power of ten is divided out as a BigFloat, which keeps the mantissa far
+// more precise than decimal_parts' logarithm.
+pub fn scientific_digits(value: BigFloat) -> Option<String> {
+    let (negative, _, mut exponent) = decimal_parts(value)?;
+    let magnitude = if negative { BigFloat::from(0.0) - value } else { value };
+    let power = big_pow(BigFloat::from(10.0), exponent.unsigned_abs() as u32);
+    let mut mantissa = (if exponent >= 0 { magnitude / power } else { magnitude * power }).to_f64()?;
+    if mantissa == 0.0 {
+        return Some("0".to_string());
+    }
+    // The logarithm can round the exponent one step off
+    if mantissa >= 10.0 {
+        mantissa /= 10.0;
+        exponent += 1;
+    } else if mantissa < 1.0 {
+        mantissa *= 10.0;
+        exponent -= 1;
+    }
+    let digits = format!("{:.14}", mantissa);
+    let digits = digits.trim_end_matches('0').trim_end_matches('.');
+    Some(format!("{}{}e{}", if negative { "-" } else { "" }, digits, exponent))
+}
+
+// Shifts the mantissa so the exponent is a multiple of `step` (0 = leave it), then
+// rounds to two decimals, carrying into the next step when that reaches 10^step
+fn round_mantissa(mantissa: f64, exponent: i64, step: i64) -> (f64, i64) {
+    let (mut mantissa, mut exponent) = if step > 0 {
+        let shift = exponent.rem_euclid(step);
+        (mantissa * 10f64.powi(shift as i32), exponent - shift)
+    } else {
+        (mantissa, exponent)
+    };
+    mantissa = (mantissa * 100.0).round() / 100.0;
+    let limit = 10f64.powi(step.max(1) as i32);
+    if mantissa >= limit {
+        mantissa /= limit;
+        exponent += step.max(1);
+    }
+    (mantissa, exponent)
+}
+
+// 1 -> a, 26 -> z, 27 -> aa, ...
+fn letters(mut group: u64) -> String {
+    let mut letters = Vec::new();
+    while group > 0 {
+        group -= 1;
+        letters.push(b'a' + (group % 26) as u8);
+        group /= 26;
+    }
+    letters.iter().rev().map(|&letter| letter as char).collect()
+}
@@ -1,14 +1,29 @@
 use bevy::prelude::*;
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
 use crate::{
-    Player, Enemy, 
-    Level, Experience,
+    CombatStartEvent,
+    Player, Enemy,
+    Level, LifetimeExperience, Experience, RebirthPoints,
     CurrentHp, MaxHp, CombatAttack, CombatDefense, CombatSpeed,
-    UpgradeLevel, UpgradeableHp, UpgradeableAttack, UpgradeableDefense, UpgradeableSpeed,
-    EnemyNumber, StartupConfig, AutomationConfig,
+    CombatCritChance, CombatCritMultiplier, CombatEvasion, CombatAccuracy,
+    UpgradeLevel, UpgradeableStat, StatKind, StatMarkers,
+    EnemyNumber, EnemyArchetype, Boss, StartupConfig, AutomationConfig, DisplaySettings, NumberNotation, UpgradeStrategy,
+    BalanceConfig, scientific_digits,
 };
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use too_big_float::BigFloat;
 
-pub struct BalanceCheckPlugin;
+// Fixed step used when time is advanced manually: one 60 Hz frame
+pub const SIMULATION_STEP: Duration = Duration::from_nanos(16_666_667);
+
+pub struct BalanceCheckPlugin {
+    // Cast skills automatically; turned off with --no-auto-cast
+    pub auto_cast: bool,
+}
 
 impl Plugin for BalanceCheckPlugin {
     fn build(&self, app: &mut App) {
@@ -16,8 +31,10 @@ impl Plugin for BalanceCheckPlugin {
             .insert_resource(AutomationConfig {
                 auto_retry_unlocked: true,  // Always unlocked in balance check mode
                 auto_retry_enabled: true,   // Always enabled in balance check mode
+                auto_cast_enabled: self.auto_cast,
             })
-            .add_systems(Startup, setup_balance_check_timer)
+            .init_resource::<BalanceReportConfig>()
+            .add_systems(Startup, (setup_balance_check_timer, setup_report_output, start_balance_combat))
             .add_systems(Update, (
                 balance_check_output_system,
                 auto_shutdown_system,
@@ -25,11 +42,177 @@ impl Plugin for BalanceCheckPlugin {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ReportFormat {
+    /// Human-readable report block
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+// Where and how balance reports are written (stdout when no file is given)
+#[derive(Resource, Clone, Debug, Default)]
+pub struct BalanceReportConfig {
+    pub format: ReportFormat,
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Resource)]
+pub struct BalanceReportOutput {
+    writer: Box<dyn Write + Send + Sync>,
+}
+
 #[derive(Resource)]
 pub struct BalanceCheckTimer {
     timer: Timer,
     output_count: u32,
     max_outputs: u32,
+    started_at: Instant,
+}
+
+// One snapshot per BalanceCheckTimer tick. Stats stay BigFloat so every report can show
+// any magnitude; see ReportNumber for how JSON and CSV write them.
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+pub struct BalanceRecord {
+    pub report: u32,
+    pub elapsed_secs: f64,
+    pub enemy_number: Option<u32>,
+    pub level: Option<u32>,
+    #[serde(serialize_with = "serialize_number")]
+    pub lifetime_experience: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub experience: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub rebirth_points: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub hp: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub max_hp: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub attack: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub defense: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub speed: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub crit_chance: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub crit_multiplier: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub evasion: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub accuracy: Option<BigFloat>,
+    pub hp_level: Option<u32>,
+    pub attack_level: Option<u32>,
+    pub defense_level: Option<u32>,
+    pub speed_level: Option<u32>,
+    pub crit_chance_level: Option<u32>,
+    pub crit_multiplier_level: Option<u32>,
+    pub evasion_level: Option<u32>,
+    pub accuracy_level: Option<u32>,
+    pub enemy_count: usize,
+    pub enemy_type: Option<&'static str>,
+    pub enemy_is_boss: bool,
+    #[serde(serialize_with = "serialize_number")]
+    pub enemy_hp: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub enemy_max_hp: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub enemy_attack: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub enemy_defense: Option<BigFloat>,
+    #[serde(serialize_with = "serialize_number")]
+    pub enemy_speed: Option<BigFloat>,
+    pub upgrade_strategy: &'static str,
+    pub damage_formula: &'static str,
+    pub auto_cast: bool,
+}
+
+// A BigFloat in JSON and CSV output: a plain number while it fits in f64, outside that range a
+// scientific string such as "1.5e400" so late-game values are not lost
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum ReportNumber {
+    Float(f64),
+    Scientific(String),
+}
+
+impl fmt::Display for ReportNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportNumber::Float(value) => write!(f, "{}", value),
+            ReportNumber::Scientific(text) => f.write_str(text),
+        }
+    }
+}
+
+pub(crate) fn number(value: Option<BigFloat>) -> Option<ReportNumber> {
+    let value = value?;
+    // Neither overflowed nor flushed to zero
+    let in_range = |float: &f64| float.is_finite() && (*float != 0.0 || value == BigFloat::from(0.0));
+    match value.to_f64().filter(in_range) {
+        Some(float) => Some(ReportNumber::Float(float)),
+        None => scientific_digits(value).map(ReportNumber::Scientific),
+    }
+}
+
+pub(crate) fn serialize_number<S: Serializer>(value: &Option<BigFloat>, serializer: S) -> Result<S::Ok, S::Error> {
+    number(*value).serialize(serializer)
+}
+
+fn csv_field<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+impl BalanceRecord {
+    pub const CSV_HEADER: &'static str = "report,elapsed_secs,enemy_number,level,lifetime_experience,experience,rebirth_points,hp,max_hp,attack,defense,speed,crit_chance,crit_multiplier,evasion,accuracy,hp_level,attack_level,defense_level,speed_level,crit_chance_level,crit_multiplier_level,evasion_level,accuracy_level,enemy_count,enemy_type,enemy_is_boss,enemy_hp,enemy_max_hp,enemy_attack,enemy_defense,enemy_speed,upgrade_strategy,damage_formula,auto_cast";
+
+    pub fn to_csv_row(&self) -> String {
+        [
+            self.report.to_string(),
+            self.elapsed_secs.to_string(),
+            csv_field(self.enemy_number),
+            csv_field(self.level),
+            csv_field(number(self.lifetime_experience)),
+            csv_field(number(self.experience)),
+            csv_field(number(self.rebirth_points)),
+            csv_field(number(self.hp)),
+            csv_field(number(self.max_hp)),
+            csv_field(number(self.attack)),
+            csv_field(number(self.defense)),
+            csv_field(number(self.speed)),
+            csv_field(number(self.crit_chance)),
+            csv_field(number(self.crit_multiplier)),
+            csv_field(number(self.evasion)),
+            csv_field(number(self.accuracy)),
+            csv_field(self.hp_level),
+            csv_field(self.attack_level),
+            csv_field(self.defense_level),
+            csv_field(self.speed_level),
+            csv_field(self.crit_chance_level),
+            csv_field(self.crit_multiplier_level),
+            csv_field(self.evasion_level),
+            csv_field(self.accuracy_level),
+            self.enemy_count.to_string(),
+            csv_field(self.enemy_type),
+            self.enemy_is_boss.to_string(),
+            csv_field(number(self.enemy_hp)),
+            csv_field(number(self.enemy_max_hp)),
+            csv_field(number(self.enemy_attack)),
+            csv_field(number(self.enemy_defense)),
+            csv_field(number(self.enemy_speed)),
+            self.upgrade_strategy.to_string(),
+            self.damage_formula.to_string(),
+            self.auto_cast.to_string(),
+        ].join(",")
+    }
+
+    pub fn to_json_line(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
 }
 
 fn setup_balance_check_timer(
@@ -40,21 +223,171 @@ fn setup_balance_check_timer(
         timer: Timer::new(Duration::from_secs(1), TimerMode::Repeating),
         output_count: 0,
         max_outputs: config.duration as u32,
+        started_at: Instant::now(),
     });
 }
 
+// Enter the dungeon right away - there is no UI to press the button
+pub fn start_balance_combat(mut combat_start_events: EventWriter<CombatStartEvent>) {
+    combat_start_events.write(CombatStartEvent { is_retry: false });
+}
+
+fn setup_report_output(
+    mut commands: Commands,
+    report_config: Res<BalanceReportConfig>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let mut writer: Box<dyn Write + Send + Sync> = match &report_config.file {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(BufWriter::new(file)),
+            Err(err) => {
+                error!(target: "balance", "Could not create report file {}: {}", path.display(), err);
+                exit.write(AppExit::error());
+                return;
+            }
+        },
+        None => Box::new(io::stdout()),
+    };
+
+    if report_config.format == ReportFormat::Csv {
+        let _ = writeln!(writer, "{}", BalanceRecord::CSV_HEADER);
+    }
+    commands.insert_resource(BalanceReportOutput { writer });
+}
+
+// Human-readable report; numbers follow the chosen notation
+pub fn write_text_report(out: &mut dyn Write, record: &BalanceRecord, notation: NumberNotation) -> io::Result<()> {
+    let number = |value: BigFloat| notation.format(value);
+    writeln!(out, "\n=== Balance Check Report #{} ===", record.report)?;
+    if !record.upgrade_strategy.is_empty() {
+        writeln!(out, "Upgrade Strategy: {}", record.upgrade_strategy)?;
+    }
+    if !record.damage_formula.is_empty() {
+        writeln!(out, "Damage Formula: {}", record.damage_formula)?;
+    }
+    writeln!(out, "Auto Cast: {}", if record.auto_cast { "on" } else { "off" })?;
+
+    // プレイヤー情報出力
+    if record.level.is_some() || record.experience.is_some() {
+        writeln!(out, "Player Status:")?;
+        if let Some(level) = record.level {
+            writeln!(out, "  Level: {}", level)?;
+        }
+        if let Some(exp) = record.lifetime_experience {
+            writeln!(out, "  Lifetime EXP: {}", number(exp))?;
+        }
+        if let Some(exp) = record.experience {
+            writeln!(out, "  Experience: {}", number(exp))?;
+        }
+        if let Some(points) = record.rebirth_points {
+            writeln!(out, "  Rebirth Points: {}", number(points))?;
+        }
+        if let (Some(current), Some(max)) = (record.hp, record.max_hp) {
+            writeln!(out, "  HP: {}/{}", number(current), number(max))?;
+        }
+        if let Some(att) = record.attack {
+            writeln!(out, "  Attack: {}", number(att))?;
+        }
+        if let Some(def) = record.defense {
+            writeln!(out, "  Defense: {}", number(def))?;
+        }
+        if let Some(spd) = record.speed {
+            writeln!(out, "  Speed: {}", number(spd))?;
+        }
+        if let Some(chance) = record.crit_chance {
+            writeln!(out, "  Crit Chance: {}", number(chance))?;
+        }
+        if let Some(multiplier) = record.crit_multiplier {
+            writeln!(out, "  Crit Multiplier: {}", number(multiplier))?;
+        }
+        if let Some(evasion) = record.evasion {
+            writeln!(out, "  Evasion: {}", number(evasion))?;
+        }
+        if let Some(accuracy) = record.accuracy {
+            writeln!(out, "  Accuracy: {}", number(accuracy))?;
+        }
+
+        writeln!(out, "  Upgradeable Stats:")?;
+        if let Some(level) = record.hp_level {
+            writeln!(out, "    HP Level: {}", level)?;
+        }
+        if let Some(level) = record.attack_level {
+            writeln!(out, "    Attack Level: {}", level)?;
+        }
+        if let Some(level) = record.defense_level {
+            writeln!(out, "    Defense Level: {}", level)?;
+        }
+        if let Some(level) = record.speed_level {
+            writeln!(out, "    Speed Level: {}", level)?;
+        }
+        if let Some(level) = record.crit_chance_level {
+            writeln!(out, "    Crit Chance Level: {}", level)?;
+        }
+        if let Some(level) = record.crit_multiplier_level {
+            writeln!(out, "    Crit Multiplier Level: {}", level)?;
+        }
+        if let Some(level) = record.evasion_level {
+            writeln!(out, "    Evasion Level: {}", level)?;
+        }
+        if let Some(level) = record.accuracy_level {
+            writeln!(out, "    Accuracy Level: {}", level)?;
+        }
+    }
+
+    // 敵情報出力
+    writeln!(out, "Enemy Count: {}", record.enemy_count)?;
+    if let Some(enemy_id) = record.enemy_number {
+        writeln!(out, "Enemy Status:")?;
+        write!(out, "  Enemy {}: ", enemy_id)?;
+        if let Some(enemy_type) = record.enemy_type {
+            write!(out, "{}, ", enemy_type)?;
+        }
+        if record.enemy_is_boss {
+            write!(out, "BOSS, ")?;
+        }
+        if let (Some(current), Some(max)) = (record.enemy_hp, record.enemy_max_hp) {
+            write!(out, "HP {}/{}, ", number(current), number(max))?;
+        }
+        if let Some(att) = record.enemy_attack {
+            write!(out, "Attack {}, ", number(att))?;
+        }
+        if let Some(def) = record.enemy_defense {
+            write!(out, "Defense {}, ", number(def))?;
+        }
+        if let Some(spd) = record.enemy_speed {
+            write!(out, "Speed {}", number(spd))?;
+        }
+        writeln!(out)?;
+    }
+
+    writeln!(out, "=== End Report ===\n")
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn balance_check_output_system(
     mut timer: ResMut<BalanceCheckTimer>,
     time: Res<Time>,
+    report_config: Res<BalanceReportConfig>,
+    settings: Res<DisplaySettings>,
+    strategy: Res<UpgradeStrategy>,
+    balance: Res<BalanceConfig>,
+    automation: Res<AutomationConfig>,
+    output: Option<ResMut<BalanceReportOutput>>,
     player_query: Query<(
         Option<&Level>,
+        Option<&LifetimeExperience>,
         Option<&Experience>,
+        Option<&RebirthPoints>,
         Option<&CurrentHp>,
         Option<&MaxHp>,
         Option<&CombatAttack>,
         Option<&CombatDefense>,
         Option<&CombatSpeed>,
-    ), (With<Player>, Without<Enemy>)>,
+        Option<&CombatCritChance>,
+        Option<&CombatCritMultiplier>,
+        Option<&CombatEvasion>,
+        Option<&CombatAccuracy>,
+    ), (With<Player>, Without<Enemy>, With<Level>)>,
     enemy_query: Query<(
         Option<&CurrentHp>,
         Option<&MaxHp>,
@@ -62,83 +395,81 @@ fn balance_check_output_system(
         Option<&CombatDefense>,
         Option<&CombatSpeed>,
         Option<&EnemyNumber>,
+        Option<&EnemyArchetype>,
+        Has<Boss>,
     ), (With<Enemy>, Without<Player>)>,
-    upgradeable_hp_query: Query<&UpgradeLevel, (With<UpgradeableHp>, With<Player>)>,
-    upgradeable_attack_query: Query<&UpgradeLevel, (With<UpgradeableAttack>, With<Player>)>,
-    upgradeable_defense_query: Query<&UpgradeLevel, (With<UpgradeableDefense>, With<Player>)>,
-    upgradeable_speed_query: Query<&UpgradeLevel, (With<UpgradeableSpeed>, With<Player>)>,
+    upgrade_level_query: Query<(&UpgradeLevel, StatMarkers), (With<UpgradeableStat>, With<Player>)>,
 ) {
     timer.timer.tick(time.delta());
-    
-    if timer.timer.just_finished() {
-        timer.output_count += 1;
-        
-        println!("\n=== Balance Check Report #{} ===", timer.output_count);
-        
-        // プレイヤー情報出力
-        if let Ok((level, experience, current_hp, max_hp, attack, defense, speed)) = player_query.single() {
-            println!("Player Status:");
-            if let Some(level) = level {
-                println!("  Level: {}", level.0);
-            }
-            if let Some(exp) = experience {
-                println!("  Experience: {}", exp.0);
-            }
-            if let (Some(current), Some(max)) = (current_hp, max_hp) {
-                println!("  HP: {}/{}", current.0, max.0);
-            }
-            if let Some(att) = attack {
-                println!("  Attack: {}", att.0);
-            }
-            if let Some(def) = defense {
-                println!("  Defense: {}", def.0);
-            }
-            if let Some(spd) = speed {
-                println!("  Speed: {}", spd.0);
-            }
-            
-            println!("  Upgradeable Stats:");
-            if let Ok(hp_level) = upgradeable_hp_query.single() {
-                println!("    HP Level: {}", hp_level.0);
-            }
-            if let Ok(att_level) = upgradeable_attack_query.single() {
-                println!("    Attack Level: {}", att_level.0);
-            }
-            if let Ok(def_level) = upgradeable_defense_query.single() {
-                println!("    Defense Level: {}", def_level.0);
-            }
-            if let Ok(spd_level) = upgradeable_speed_query.single() {
-                println!("    Speed Level: {}", spd_level.0);
-            }
-        }
-        
-        // 敵情報出力
-        let enemy_count = enemy_query.iter().count();
-        println!("Enemy Count: {}", enemy_count);
-        
-        if enemy_count > 0 {
-            println!("Enemy Status:");
-            for (i, (current_hp, max_hp, attack, defense, speed, enemy_num)) in enemy_query.iter().enumerate() {
-                let enemy_id = if let Some(num) = enemy_num { num.0 } else { i as u32 + 1 };
-                print!("  Enemy {}: ", enemy_id);
-                
-                if let (Some(current), Some(max)) = (current_hp, max_hp) {
-                    print!("HP {}/{}, ", current.0, max.0);
-                }
-                if let Some(att) = attack {
-                    print!("Attack {}, ", att.0);
-                }
-                if let Some(def) = defense {
-                    print!("Defense {}, ", def.0);
-                }
-                if let Some(spd) = speed {
-                    print!("Speed {}", spd.0);
-                }
-                println!();
-            }
-        }
-        
-        println!("=== End Report ===\n");
+
+    if !timer.timer.just_finished() {
+        return;
+    }
+    timer.output_count += 1;
+    let Some(mut output) = output else { return };
+
+    let mut levels = [None; StatKind::COUNT];
+    for (level, markers) in &upgrade_level_query {
+        levels[markers.kind().index()] = Some(level.0);
+    }
+    let [hp_level, attack_level, defense_level, speed_level, crit_chance_level, crit_multiplier_level, evasion_level, accuracy_level] = levels;
+
+    let mut record = BalanceRecord {
+        report: timer.output_count,
+        elapsed_secs: time.elapsed_secs_f64(),
+        hp_level,
+        attack_level,
+        defense_level,
+        speed_level,
+        crit_chance_level,
+        crit_multiplier_level,
+        evasion_level,
+        accuracy_level,
+        enemy_count: enemy_query.iter().count(),
+        upgrade_strategy: strategy.kind.label(),
+        damage_formula: balance.damage.formula.label(),
+        auto_cast: automation.auto_cast_enabled,
+        ..default()
+    };
+
+    if let Ok((
+        level, lifetime_experience, experience, rebirth_points, current_hp, max_hp, attack, defense, speed,
+        crit_chance, crit_multiplier, evasion, accuracy,
+    )) = player_query.single() {
+        record.level = level.map(|level| level.0);
+        record.lifetime_experience = lifetime_experience.map(|exp| exp.0);
+        record.experience = experience.map(|exp| exp.0);
+        record.rebirth_points = rebirth_points.map(|points| points.0);
+        record.hp = current_hp.map(|hp| hp.0);
+        record.max_hp = max_hp.map(|hp| hp.0);
+        record.attack = attack.map(|att| att.0);
+        record.defense = defense.map(|def| def.0);
+        record.speed = speed.map(|spd| spd.0);
+        record.crit_chance = crit_chance.map(|chance| chance.0);
+        record.crit_multiplier = crit_multiplier.map(|multiplier| multiplier.0);
+        record.evasion = evasion.map(|evasion| evasion.0);
+        record.accuracy = accuracy.map(|accuracy| accuracy.0);
+    }
+
+    if let Some((current_hp, max_hp, attack, defense, speed, enemy_number, archetype, is_boss)) = enemy_query.iter().next() {
+        record.enemy_number = Some(enemy_number.map_or(1, |num| num.0));
+        record.enemy_type = archetype.map(|archetype| archetype.label());
+        record.enemy_is_boss = is_boss;
+        record.enemy_hp = current_hp.map(|hp| hp.0);
+        record.enemy_max_hp = max_hp.map(|hp| hp.0);
+        record.enemy_attack = attack.map(|att| att.0);
+        record.enemy_defense = defense.map(|def| def.0);
+        record.enemy_speed = speed.map(|spd| spd.0);
+    }
+
+    let out = &mut output.writer;
+    let result = match report_config.format {
+        ReportFormat::Text => write_text_report(out.as_mut(), &record, settings.notation),
+        ReportFormat::Json => writeln!(out, "{}", record.to_json_line()),
+        ReportFormat::Csv => writeln!(out, "{}", record.to_csv_row()),
+    };
+    if let Err(err) = result.and_then(|_| out.flush()) {
+        error!(target: "balance", "Failed to write balance report: {}", err);
     }
 }
 
@@ -147,7 +478,12 @@ fn auto_shutdown_system(
     mut exit: EventWriter<AppExit>,
 ) {
     if timer.output_count >= timer.max_outputs {
-        println!("Balance check completed. Shutting down...");
+        info!(
+            target: "balance",
+            "Balance check completed ({}s simulated in {:.2}s wall time). Shutting down...",
+            timer.max_outputs,
+            timer.started_at.elapsed().as_secs_f64()
+        );
         exit.write(AppExit::Success);
     }
-}
\ No newline at end of file
+}
@@ -1,14 +1,22 @@
 use bevy::prelude::*;
 use crate::{
-    AttackEvent, DeathEvent, PlayerDeathEvent, EnemyDeathEvent, 
+    AttackEvent, DeathEvent, PlayerDeathEvent, BossFailedEvent, RebirthRequestEvent, RebirthShopPurchaseEvent, EnemyDeathEvent,
     ExpGainEvent, NextEnemySpawnEvent, CombatEndEvent, CombatStartEvent, CombatState,
-    combat_init_system, combat_start_system, attack_cooldown_system, player_attack_system, 
-    enemy_attack_system, damage_application_system, death_detection_system,
-    enemy_death_system, player_death_system, exp_gain_system, next_enemy_spawn_system
+    combat_init_system, combat_start_system, attack_cooldown_system, boss_enrage_system,
+    player_attack_system, enemy_attack_system, damage_application_system, death_detection_system,
+    enemy_death_system, player_death_system, boss_failure_system, manual_rebirth_system,
+    rebirth_shop_purchase_system, achievement_system, statistics_system, combat_log_system, CombatLog, GameRng,
+    status_effect_application_system, status_effect_tick_system, StatusEffectEvent,
+    skill_cooldown_system, skill_auto_cast_system, skill_cast_system, SkillCastEvent, SkillUsedEvent,
+    exp_gain_system, next_enemy_spawn_system
 };
 
 pub struct CombatPlugin;
 
+// The chained combat systems; anything else touching combat stats or EXP runs after them
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CombatSystems;
+
 impl Plugin for CombatPlugin {
     fn build(&self, app: &mut App) {
         app
@@ -16,16 +24,28 @@ impl Plugin for CombatPlugin {
                 is_game_over: false,
                 in_dungeon: false,
             })
+            .init_resource::<CombatLog>()
+            .init_resource::<GameRng>()
             // Add combat events
             .add_event::<CombatStartEvent>()
             .add_event::<AttackEvent>()
+            .add_event::<StatusEffectEvent>()
+            .add_event::<SkillCastEvent>()
+            .add_event::<SkillUsedEvent>()
             .add_event::<DeathEvent>()
             .add_event::<PlayerDeathEvent>()
+            .add_event::<BossFailedEvent>()
+            .add_event::<RebirthRequestEvent>()
+            .add_event::<RebirthShopPurchaseEvent>()
             .add_event::<EnemyDeathEvent>()
             .add_event::<ExpGainEvent>()
             .add_event::<NextEnemySpawnEvent>()
             .add_event::<CombatEndEvent>()
-            // Add combat systems
+            // Add combat systems. They are chained because the attack, skill and status effect
+            // systems all draw from ResMut<GameRng>, and Bevy runs unordered systems in whatever
+            // order the executor picks. Any change in that order shifts the rolls, so a --seed run
+            // would no longer repeat. The chain also lets events sent early in the list be
+            // handled in the same frame.
             .add_systems(Update, (
                 // Combat control systems
                 combat_start_system,
@@ -34,15 +54,29 @@ impl Plugin for CombatPlugin {
                 combat_init_system,
                 
                 // Real-time combat systems
+                status_effect_tick_system,
                 attack_cooldown_system,
-                (player_attack_system, enemy_attack_system),
+                boss_enrage_system,
+                // Player actions, skills before the regular attack
+                // (nested: a chain takes at most 20 systems)
+                (skill_cooldown_system, skill_auto_cast_system, skill_cast_system, player_attack_system).chain(),
+                enemy_attack_system,
                 damage_application_system,
+                status_effect_application_system,
                 
                 // Combat end systems
                 death_detection_system,
-                (enemy_death_system, player_death_system),
+                enemy_death_system,
+                player_death_system,
+                boss_failure_system,
+                // After player_death_system so a manual rebirth never overlaps a death rebirth
+                manual_rebirth_system,
+                rebirth_shop_purchase_system,
                 exp_gain_system,
                 next_enemy_spawn_system,
-            ));
+                achievement_system,
+                statistics_system,
+                combat_log_system,
+            ).chain().in_set(CombatSystems));
     }
 }
\ No newline at end of file
@@ -3,9 +3,13 @@ pub mod stats;
 pub mod ui;
 pub mod player;
 pub mod balance_check;
+pub mod save;
+pub mod sweep;
 
-pub use combat::CombatPlugin;
+pub use combat::{CombatPlugin, CombatSystems};
 pub use stats::StatsPlugin;
 pub use ui::UIPlugin;
 pub use player::PlayerPlugin;
-pub use balance_check::BalanceCheckPlugin;
\ No newline at end of file
+pub use balance_check::{BalanceCheckPlugin, BalanceReportConfig, BalanceRecord, ReportFormat, SIMULATION_STEP};
+pub use save::SavePlugin;
+pub use sweep::{SweepPlugin, SweepRange, SweepRanges, SweepResult, SweepTracker, run_sweep_case, write_sweep_results};
\ No newline at end of file
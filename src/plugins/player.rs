@@ -1,15 +1,24 @@
 use bevy::prelude::*;
-use crate::{player_init_system, GameProgress};
+use crate::{player_init_system, GameProgress, LoadedSave, BalanceConfig, RebirthCurrency, RebirthShop, Achievements, Statistics, DisplaySettings};
 
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app
+            // Defaults unless main inserted one loaded from --balance-config
+            .init_resource::<BalanceConfig>()
             .insert_resource(GameProgress {
                 current_enemy_number: 1,
                 has_died_once: false,
             })
-            .add_systems(Startup, player_init_system);
+            .init_resource::<RebirthCurrency>()
+            .init_resource::<RebirthShop>()
+            .init_resource::<Achievements>()
+            .init_resource::<Statistics>()
+            // Defaults unless main inserted one from --number-notation
+            .init_resource::<DisplaySettings>()
+            // A loaded save spawns the player instead (see SavePlugin)
+            .add_systems(Startup, player_init_system.run_if(not(resource_exists::<LoadedSave>)));
     }
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+use crate::{
+    SaveSettings, LoadedSave, player_init_system,
+    load_save_system, offline_progress_system, restore_game_system, autosave_system, save_on_exit_system,
+};
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<SaveSettings>()
+            .add_systems(Startup, (
+                load_save_system.before(player_init_system),
+                offline_progress_system
+                    .after(load_save_system)
+                    .run_if(resource_exists::<LoadedSave>),
+                restore_game_system
+                    .after(offline_progress_system)
+                    .run_if(resource_exists::<LoadedSave>),
+            ))
+            .add_systems(Update, autosave_system)
+            .add_systems(Last, save_on_exit_system);
+    }
+}
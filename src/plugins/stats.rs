@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 use crate::{
-    upgradeable_stat_upgrade_system, update_current_value_on_change,
-    hp_sync_system, attack_sync_system, defense_sync_system, speed_sync_system
+    upgradeable_stat_upgrade_system, manual_upgrade_system, update_current_value_on_change,
+    hp_sync_system, attack_sync_system, defense_sync_system, speed_sync_system, UpgradeStrategy,
+    crit_chance_sync_system, crit_multiplier_sync_system, evasion_sync_system, accuracy_sync_system,
+    ManualUpgradeEvent, player_level_system, CombatSystems,
 };
 
 pub struct StatsPlugin;
@@ -9,16 +11,27 @@ pub struct StatsPlugin;
 impl Plugin for StatsPlugin {
     fn build(&self, app: &mut App) {
         app
+            // Defaults unless main inserted one from --upgrade-strategy
+            .init_resource::<UpgradeStrategy>()
+            .add_event::<ManualUpgradeEvent>()
+            // Chained after combat for the same reason combat is chained: purchases and stat
+            // syncs change what the next attack rolls against, so their order must not vary
             .add_systems(Update, (
                 // Upgrade and sync systems
+                manual_upgrade_system,
                 upgradeable_stat_upgrade_system,
+                player_level_system,
                 update_current_value_on_change,
                 (
                     hp_sync_system,
                     attack_sync_system,
                     defense_sync_system,
                     speed_sync_system,
-                ).after(upgradeable_stat_upgrade_system),
-            ));
+                    crit_chance_sync_system,
+                    crit_multiplier_sync_system,
+                    evasion_sync_system,
+                    accuracy_sync_system,
+                ),
+            ).chain().after(CombatSystems));
     }
 }
\ No newline at end of file
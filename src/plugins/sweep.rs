@@ -0,0 +1,319 @@
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use serde::Serialize;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use too_big_float::BigFloat;
+use crate::plugins::balance_check::{number, serialize_number, start_balance_combat};
+use crate::{
+    AutomationConfig, BalanceConfig, BossFailedEvent, CombatPlugin, DamageFormula, GameProgress, GameRng, NumberNotation,
+    PlayerDeathEvent, PlayerPlugin, RebirthCurrency, ReportFormat, StartupConfig, StatsPlugin, UpgradeStrategy, UpgradeStrategyKind, SIMULATION_STEP,
+};
+
+// Tracks the sweep metrics for a single headless run
+pub struct SweepPlugin {
+    // Cast skills automatically; turned off with --no-auto-cast
+    pub auto_cast: bool,
+}
+
+impl Plugin for SweepPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .insert_resource(AutomationConfig {
+                auto_retry_unlocked: true,
+                auto_retry_enabled: true,
+                auto_cast_enabled: self.auto_cast,
+            })
+            .init_resource::<SweepTracker>()
+            .add_systems(Startup, start_balance_combat)
+            .add_systems(Update, sweep_tracking_system);
+    }
+}
+
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct SweepTracker {
+    pub highest_enemy: u32,
+    // First death or failed boss fight, whichever comes first
+    pub first_wall_secs: Option<f64>,
+    pub deaths: u32,
+    pub boss_failures: u32,
+    pub rebirth_points_gained: BigFloat,
+}
+
+impl Default for SweepTracker {
+    fn default() -> Self {
+        Self {
+            highest_enemy: 1,
+            first_wall_secs: None,
+            deaths: 0,
+            boss_failures: 0,
+            rebirth_points_gained: BigFloat::from(0.0),
+        }
+    }
+}
+
+fn sweep_tracking_system(
+    mut tracker: ResMut<SweepTracker>,
+    mut player_death_events: EventReader<PlayerDeathEvent>,
+    mut boss_failed_events: EventReader<BossFailedEvent>,
+    game_progress: Res<GameProgress>,
+    time: Res<Time>,
+    currency: Res<RebirthCurrency>,
+) {
+    tracker.highest_enemy = tracker.highest_enemy.max(game_progress.current_enemy_number);
+
+    // player_death_system and boss_failure_system handle one event per frame
+    let died = player_death_events.read().next().is_some();
+    let failed_boss = boss_failed_events.read().next().is_some();
+    tracker.deaths += died as u32;
+    tracker.boss_failures += failed_boss as u32;
+    if (died || failed_boss) && tracker.first_wall_secs.is_none() {
+        tracker.first_wall_secs = Some(time.elapsed_secs_f64());
+    }
+
+    // Each run starts from an empty currency, so its lifetime total is what this run earned
+    tracker.rebirth_points_gained = currency.lifetime_points;
+}
+
+// Inclusive range of values: "N", "A..B" or "A..B:STEP"
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SweepRange {
+    pub start: u64,
+    pub end: u64,
+    pub step: u64,
+}
+
+impl SweepRange {
+    pub fn single(value: u64) -> Self {
+        Self { start: value, end: value, step: 1 }
+    }
+
+    pub fn values(&self) -> Vec<u64> {
+        (self.start..=self.end).step_by(self.step as usize).collect()
+    }
+}
+
+impl FromStr for SweepRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |value: &str| {
+            value.trim().parse::<u64>().map_err(|_| format!("invalid number '{}' in range '{}'", value, s))
+        };
+        let (range, step) = match s.split_once(':') {
+            Some((range, step)) => (range, parse(step)?),
+            None => (s, 1),
+        };
+        let (start, end) = match range.split_once("..") {
+            Some((start, end)) => (parse(start)?, parse(end)?),
+            None => {
+                let value = parse(range)?;
+                (value, value)
+            }
+        };
+        if step == 0 {
+            return Err(format!("step must be positive in range '{}'", s));
+        }
+        if start > end {
+            return Err(format!("range '{}' ends before it starts", s));
+        }
+        Ok(Self { start, end, step })
+    }
+}
+
+impl fmt::Display for SweepRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else if self.step == 1 {
+            write!(f, "{}..{}", self.start, self.end)
+        } else {
+            write!(f, "{}..{}:{}", self.start, self.end, self.step)
+        }
+    }
+}
+
+// Ranges for every StartupConfig field that can be swept
+#[derive(Clone, Debug, PartialEq)]
+pub struct SweepRanges {
+    pub level: SweepRange,
+    pub experience: SweepRange,
+    pub hp_level: SweepRange,
+    pub attack_level: SweepRange,
+    pub defense_level: SweepRange,
+    pub speed_level: SweepRange,
+}
+
+fn to_u32(value: u64) -> u32 {
+    u32::try_from(value).unwrap_or(u32::MAX)
+}
+
+impl SweepRanges {
+    // Cartesian product, varying the last field (speed level) fastest
+    pub fn configs(&self, duration: u64) -> Vec<StartupConfig> {
+        let mut configs = Vec::new();
+        for level in self.level.values() {
+            for experience in self.experience.values() {
+                for hp_level in self.hp_level.values() {
+                    for attack_level in self.attack_level.values() {
+                        for defense_level in self.defense_level.values() {
+                            for speed_level in self.speed_level.values() {
+                                configs.push(StartupConfig {
+                                    level: to_u32(level),
+                                    experience,
+                                    hp_level: to_u32(hp_level),
+                                    attack_level: to_u32(attack_level),
+                                    defense_level: to_u32(defense_level),
+                                    speed_level: to_u32(speed_level),
+                                    duration,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        configs
+    }
+}
+
+// One row of the sweep table
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct SweepResult {
+    pub level: u32,
+    pub experience: u64,
+    pub hp_level: u32,
+    pub attack_level: u32,
+    pub defense_level: u32,
+    pub speed_level: u32,
+    pub damage_formula: &'static str,
+    pub upgrade_strategy: &'static str,
+    pub auto_cast: bool,
+    pub highest_enemy: u32,
+    // Simulated seconds until the first death or boss failure; None if the run never hit a wall
+    pub time_to_wall_secs: Option<f64>,
+    pub deaths: u32,
+    pub boss_failures: u32,
+    #[serde(serialize_with = "serialize_number")]
+    pub rebirth_points_gained: Option<BigFloat>,
+}
+
+impl SweepResult {
+    pub const CSV_HEADER: &'static str = "level,experience,hp_level,attack_level,defense_level,speed_level,damage_formula,upgrade_strategy,auto_cast,highest_enemy,time_to_wall_secs,deaths,boss_failures,rebirth_points_gained";
+
+    fn new(
+        config: &StartupConfig,
+        damage_formula: DamageFormula,
+        strategy: UpgradeStrategyKind,
+        auto_cast: bool,
+        tracker: &SweepTracker,
+    ) -> Self {
+        Self {
+            level: config.level,
+            experience: config.experience,
+            hp_level: config.hp_level,
+            attack_level: config.attack_level,
+            defense_level: config.defense_level,
+            speed_level: config.speed_level,
+            damage_formula: damage_formula.label(),
+            upgrade_strategy: strategy.label(),
+            auto_cast,
+            highest_enemy: tracker.highest_enemy,
+            time_to_wall_secs: tracker.first_wall_secs,
+            deaths: tracker.deaths,
+            boss_failures: tracker.boss_failures,
+            rebirth_points_gained: Some(tracker.rebirth_points_gained),
+        }
+    }
+
+    pub fn to_csv_row(&self) -> String {
+        [
+            self.level.to_string(),
+            self.experience.to_string(),
+            self.hp_level.to_string(),
+            self.attack_level.to_string(),
+            self.defense_level.to_string(),
+            self.speed_level.to_string(),
+            self.damage_formula.to_string(),
+            self.upgrade_strategy.to_string(),
+            self.auto_cast.to_string(),
+            self.highest_enemy.to_string(),
+            self.time_to_wall_secs.map(|v| format!("{:.2}", v)).unwrap_or_default(),
+            self.deaths.to_string(),
+            self.boss_failures.to_string(),
+            number(self.rebirth_points_gained).map(|v| v.to_string()).unwrap_or_default(),
+        ].join(",")
+    }
+
+    pub fn to_json_line(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+// Run one configuration for `config.duration` simulated seconds with fixed time steps
+// Every case starts from the same seed so combinations differ only in their parameters
+pub fn run_sweep_case(
+    balance: &BalanceConfig,
+    strategy: &UpgradeStrategy,
+    seed: u64,
+    auto_cast: bool,
+    config: StartupConfig,
+) -> SweepResult {
+    let frames = (config.duration as f64 / SIMULATION_STEP.as_secs_f64()).round() as u64;
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .insert_resource(TimeUpdateStrategy::ManualDuration(SIMULATION_STEP))
+        .insert_resource(balance.clone())
+        .insert_resource(strategy.clone())
+        .insert_resource(config.clone())
+        .insert_resource(GameRng::from_seed(seed))
+        .add_plugins((PlayerPlugin, CombatPlugin, StatsPlugin, SweepPlugin { auto_cast }));
+
+    // The first update only starts the clock
+    for _ in 0..=frames {
+        app.update();
+    }
+    SweepResult::new(&config, balance.damage.formula, strategy.kind, auto_cast, app.world().resource::<SweepTracker>())
+}
+
+pub fn write_sweep_results(
+    out: &mut dyn Write,
+    format: ReportFormat,
+    results: &[SweepResult],
+    notation: NumberNotation,
+) -> io::Result<()> {
+    match format {
+        ReportFormat::Text => {
+            writeln!(
+                out,
+                "{:>5} {:>10} {:>5} {:>5} {:>5} {:>5} {:>11} {:>14} {:>9} | {:>13} {:>12} {:>6} {:>10} {:>14}",
+                "LV", "EXP", "HP", "ATK", "DEF", "SPD", "Damage", "Strategy", "Auto Cast", "Highest Enemy", "Time to Wall", "Deaths", "Boss Fails", "Rebirth Points"
+            )?;
+            for result in results {
+                let wall = result.time_to_wall_secs.map(|v| format!("{:.1}s", v)).unwrap_or_else(|| "-".to_string());
+                let points = result.rebirth_points_gained.map(|v| notation.format_whole(v)).unwrap_or_else(|| "-".to_string());
+                let auto_cast = if result.auto_cast { "on" } else { "off" };
+                writeln!(
+                    out,
+                    "{:>5} {:>10} {:>5} {:>5} {:>5} {:>5} {:>11} {:>14} {:>9} | {:>13} {:>12} {:>6} {:>10} {:>14}",
+                    result.level, result.experience, result.hp_level, result.attack_level,
+                    result.defense_level, result.speed_level, result.damage_formula, result.upgrade_strategy,
+                    auto_cast, result.highest_enemy, wall, result.deaths, result.boss_failures, points
+                )?;
+            }
+        }
+        ReportFormat::Json => {
+            for result in results {
+                writeln!(out, "{}", result.to_json_line())?;
+            }
+        }
+        ReportFormat::Csv => {
+            writeln!(out, "{}", SweepResult::CSV_HEADER)?;
+            for result in results {
+                writeln!(out, "{}", result.to_csv_row())?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,5 +1,12 @@
 use bevy::prelude::*;
-use crate::{setup_ui, update_ui_system, tab_button_system, dungeon_button_system, auto_retry_button_system, UIState, AutomationConfig, GameTab};
+use crate::{
+    setup_ui, update_ui_system, tab_button_system, dungeon_button_system, auto_retry_button_system,
+    upgrade_strategy_button_system, upgrade_rows_system, auto_upgrade_button_system,
+    offline_summary_popup_system, offline_summary_close_system,
+    rebirth_preview_system, rebirth_button_system, rebirth_shop_button_system, achievements_ui_system,
+    statistics_ui_system, combat_log_ui_system, combat_log_filter_button_system, combat_log_scroll_system,
+    number_notation_button_system, skill_buttons_system, auto_cast_button_system, UIState, AutomationConfig, GameTab, OfflineProgressReport,
+};
 
 pub struct UIPlugin;
 
@@ -12,6 +19,7 @@ impl Plugin for UIPlugin {
             .insert_resource(AutomationConfig {
                 auto_retry_unlocked: false,
                 auto_retry_enabled: false,
+                auto_cast_enabled: false,
             })
             .add_systems(Startup, setup_ui)
             .add_systems(Update, (
@@ -19,6 +27,22 @@ impl Plugin for UIPlugin {
                 tab_button_system,
                 dungeon_button_system,
                 auto_retry_button_system,
+                upgrade_strategy_button_system,
+                upgrade_rows_system,
+                auto_upgrade_button_system,
+                skill_buttons_system,
+                auto_cast_button_system,
+                rebirth_preview_system,
+                rebirth_button_system,
+                rebirth_shop_button_system,
+                achievements_ui_system,
+                statistics_ui_system,
+                combat_log_ui_system,
+                combat_log_filter_button_system,
+                combat_log_scroll_system,
+                number_notation_button_system,
+                offline_summary_popup_system.run_if(resource_added::<OfflineProgressReport>),
+                offline_summary_close_system,
             ));
     }
 }
\ No newline at end of file
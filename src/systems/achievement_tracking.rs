@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+use crate::components::*;
+use crate::events::*;
+
+// Track achievement progress from combat events and unlock achievements whose goals are met
+pub fn achievement_system(
+    mut enemy_death_events: EventReader<EnemyDeathEvent>,
+    mut player_death_events: EventReader<PlayerDeathEvent>,
+    mut exp_events: EventReader<ExpGainEvent>,
+    upgrade_query: Query<&UpgradeLevel, (With<UpgradeableStat>, Changed<UpgradeLevel>)>,
+    currency: Res<RebirthCurrency>,
+    mut achievements: ResMut<Achievements>,
+) {
+    // Work on a copy so the resource only reads as changed when something moved
+    let mut updated = achievements.clone();
+    for death in enemy_death_events.read() {
+        updated.highest_enemy_defeated = updated.highest_enemy_defeated.max(death.enemy_number);
+    }
+    updated.deaths += player_death_events.read().count() as u32;
+    for exp in exp_events.read() {
+        updated.total_exp += exp.amount;
+    }
+    for level in upgrade_query.iter() {
+        updated.highest_upgrade_level = updated.highest_upgrade_level.max(level.0);
+    }
+
+    for index in updated.check(currency.rebirth_count) {
+        let achievement = &ACHIEVEMENTS[index];
+        info!(target: "progress", "Achievement unlocked: {} ({})", achievement.name, achievement.reward.describe());
+    }
+    achievements.set_if_neq(updated);
+}
+
+// Same bookkeeping for a fight resolved by the offline simulation
+pub fn record_offline_kill(achievements: &mut Achievements, enemy_number: u32, exp_reward: BigFloat) {
+    achievements.highest_enemy_defeated = achievements.highest_enemy_defeated.max(enemy_number);
+    achievements.total_exp += exp_reward;
+}
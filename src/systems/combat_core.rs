@@ -1,33 +1,104 @@
 use bevy::prelude::*;
+use rand::Rng;
 use too_big_float::BigFloat;
 use crate::components::*;
 use crate::events::*;
-use crate::CombatState;
+use crate::{BalanceConfig, CombatState};
+
+// Time between attacks at speed 1, before cooldown perks
+const BASE_ATTACK_TIME_MS: f32 = 1000.0;
 
 // Real-time cooldown system - reduces cooldowns based on speed and time
 pub fn attack_cooldown_system(
     time: Res<Time>,
-    mut cooldown_query: Query<(&mut AttackCooldown, &CombatSpeed)>,
+    mut cooldown_query: Query<(&mut AttackCooldown, &CombatSpeed, Option<&Slowed>), Without<Stunned>>,
     combat_state: Res<CombatState>,
 ) {
     if combat_state.is_game_over || !combat_state.in_dungeon {
         return;
     }
 
-    let delta_ms = time.delta().as_millis() as f32;
+    // Keep sub-millisecond precision so fixed 60 Hz steps don't lose time every frame
+    let delta_ms = time.delta_secs() * 1000.0;
     
-    for (mut cooldown, speed) in cooldown_query.iter_mut() {
+    // Stunned combatants are skipped entirely
+    for (mut cooldown, speed, slowed) in cooldown_query.iter_mut() {
         if cooldown.0 > 0.0 {
             // Cooldown reduction is proportional to speed
             // Higher speed = faster cooldown reduction
             let speed_multiplier = speed.0.to_f64().unwrap_or(1.0) as f32;
-            let reduction = delta_ms * speed_multiplier;
+            let slow_factor = slowed.map_or(1.0, |slowed| slowed.factor);
+            let reduction = delta_ms * speed_multiplier * slow_factor;
             cooldown.0 = (cooldown.0 - reduction).max(0.0);
         }
     }
 }
 
+// Bosses hit harder once their enrage timer runs out
+pub fn boss_enrage_system(
+    time: Res<Time>,
+    mut boss_query: Query<(&mut EnrageTimer, &mut CombatAttack), With<Boss>>,
+    combat_state: Res<CombatState>,
+) {
+    if combat_state.is_game_over || !combat_state.in_dungeon {
+        return;
+    }
+
+    for (mut enrage, mut attack) in boss_query.iter_mut() {
+        if enrage.enraged {
+            continue;
+        }
+        enrage.timer.tick(time.delta());
+        if enrage.timer.finished() {
+            enrage.enraged = true;
+            attack.0 = attack.0 * BigFloat::from(enrage.attack_multiplier);
+            info!(target: "combat", "The boss is enraged! Attack: {}", attack.0);
+        }
+    }
+}
+
+// Chance that an attack lands: accuracy / (accuracy + evasion)
+pub fn hit_chance(accuracy: BigFloat, evasion: BigFloat) -> f64 {
+    let total = accuracy + evasion;
+    if total <= BigFloat::from(0.0) {
+        return 1.0;
+    }
+    (accuracy / total).to_f64().unwrap_or(1.0).clamp(0.0, 1.0)
+}
+
+// Odds of one attack from the attacker's crit stats and accuracy against the target's evasion
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AttackRoll {
+    pub hit_chance: f64,
+    pub crit_chance: f64,
+    pub crit_multiplier: f64,
+}
+
+impl AttackRoll {
+    pub fn new(crit_chance: BigFloat, crit_multiplier: BigFloat, accuracy: BigFloat, evasion: BigFloat) -> Self {
+        Self {
+            hit_chance: hit_chance(accuracy, evasion),
+            crit_chance: crit_chance.to_f64().unwrap_or(0.0).clamp(0.0, 1.0),
+            crit_multiplier: crit_multiplier.to_f64().unwrap_or(1.0),
+        }
+    }
+
+    // Average damage per attack relative to a plain hit, for closed-form simulations
+    pub fn expected_factor(&self) -> f64 {
+        self.hit_chance * (1.0 + self.crit_chance * (self.crit_multiplier - 1.0))
+    }
+
+    // (critical, missed); crits are only rolled for attacks that land
+    pub fn roll(&self, rng: &mut GameRng) -> (bool, bool) {
+        if !rng.gen_bool(self.hit_chance) {
+            return (false, true);
+        }
+        (rng.gen_bool(self.crit_chance), false)
+    }
+}
+
 // Shared helper function for attack execution logic
+#[allow(clippy::too_many_arguments)]
 fn execute_attack_if_ready(
     attacker_entity: Entity,
     attack: &CombatAttack,
@@ -35,43 +106,72 @@ fn execute_attack_if_ready(
     cooldown: &mut AttackCooldown,
     target_entity: Entity,
     target_defense: &CombatDefense,
+    damage_balance: &DamageBalance,
+    roll: AttackRoll,
+    rng: &mut GameRng,
     attack_events: &mut EventWriter<AttackEvent>,
     attacker_name: &str,
+    base_attack_time: f32,
+    damage_multiplier: BigFloat,
 ) -> bool {
     if cooldown.0 <= 0.0 {
-        let damage = (attack.0 - target_defense.0).max(BigFloat::from(1.0));
+        let (critical, missed) = roll.roll(rng);
+        let damage = if missed {
+            BigFloat::from(0.0)
+        } else {
+            let damage = damage_balance.damage(attack.0, target_defense.0) * damage_multiplier;
+            if critical { damage * BigFloat::from(roll.crit_multiplier) } else { damage }
+        };
         
         attack_events.write(AttackEvent {
             attacker: attacker_entity,
             target: target_entity,
             damage,
+            critical,
+            missed,
         });
         
-        // Calculate base attack time (1000ms) adjusted by speed
+        // Base attack time adjusted by speed
         let speed_value = speed.0.to_f64().unwrap_or(1.0) as f32;
-        let base_attack_time = 1000.0; // 1 second base
         cooldown.0 = base_attack_time / speed_value;
         
-        println!("{} attacks for {} damage (cooldown: {}ms)", attacker_name, damage, cooldown.0);
+        if missed {
+            debug!(target: "combat", "{} misses (cooldown: {}ms)", attacker_name, cooldown.0);
+        } else {
+            debug!(target: "combat", "{} attacks for {} damage{} (cooldown: {}ms)",
+                attacker_name, damage, if critical { " (crit)" } else { "" }, cooldown.0);
+        }
         true
     } else {
         false
     }
 }
 
+// Crit chance, crit multiplier and accuracy of an attacker
+type AttackerRatings<'a> = (&'a CombatCritChance, &'a CombatCritMultiplier, &'a CombatAccuracy);
+
+fn attack_roll((crit_chance, crit_multiplier, accuracy): AttackerRatings, evasion: &CombatEvasion) -> AttackRoll {
+    AttackRoll::new(crit_chance.0, crit_multiplier.0, accuracy.0, evasion.0)
+}
+
 // Player attack system - handles only player attacks
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn player_attack_system(
     mut attack_events: EventWriter<AttackEvent>,
-    mut player_query: Query<(Entity, &CombatAttack, &CombatSpeed, &mut AttackCooldown), (With<Player>, Without<Enemy>)>,
-    target_query: Query<(Entity, &CombatDefense), (With<Enemy>, Without<Player>)>,
+    mut player_query: Query<(Entity, &CombatAttack, &CombatSpeed, &mut AttackCooldown, AttackerRatings), (With<Player>, Without<Enemy>, Without<Stunned>)>,
+    target_query: Query<(Entity, &CombatDefense, &CombatEvasion), (With<Enemy>, Without<Player>)>,
     combat_state: Res<CombatState>,
+    shop: Res<RebirthShop>,
+    achievements: Res<Achievements>,
+    balance: Res<BalanceConfig>,
+    mut rng: ResMut<GameRng>,
 ) {
     if combat_state.is_game_over || !combat_state.in_dungeon {
         return;
     }
 
-    if let Ok((player_entity, player_attack, player_speed, mut player_cooldown)) = player_query.single_mut() {
-        if let Ok((enemy_entity, enemy_defense)) = target_query.single() {
+    if let Ok((player_entity, player_attack, player_speed, mut player_cooldown, ratings)) = player_query.single_mut() {
+        if let Ok((enemy_entity, enemy_defense, enemy_evasion)) = target_query.single() {
             execute_attack_if_ready(
                 player_entity,
                 player_attack,
@@ -79,26 +179,34 @@ pub fn player_attack_system(
                 &mut player_cooldown,
                 enemy_entity,
                 enemy_defense,
+                &balance.damage,
+                attack_roll(ratings, enemy_evasion),
+                &mut rng,
                 &mut attack_events,
                 "Player",
+                BASE_ATTACK_TIME_MS * shop.attack_time_multiplier(&balance.rebirth.shop) as f32,
+                achievements.damage_multiplier(),
             );
         }
     }
 }
 
 // Enemy attack system - handles only enemy attacks
+#[allow(clippy::type_complexity)]
 pub fn enemy_attack_system(
     mut attack_events: EventWriter<AttackEvent>,
-    mut enemy_query: Query<(Entity, &CombatAttack, &CombatSpeed, &mut AttackCooldown), (With<Enemy>, Without<Player>)>,
-    target_query: Query<(Entity, &CombatDefense), (With<Player>, Without<Enemy>)>,
+    mut enemy_query: Query<(Entity, &CombatAttack, &CombatSpeed, &mut AttackCooldown, AttackerRatings), (With<Enemy>, Without<Player>, Without<Stunned>)>,
+    target_query: Query<(Entity, &CombatDefense, &CombatEvasion), (With<Player>, Without<Enemy>)>,
     combat_state: Res<CombatState>,
+    balance: Res<BalanceConfig>,
+    mut rng: ResMut<GameRng>,
 ) {
     if combat_state.is_game_over || !combat_state.in_dungeon {
         return;
     }
 
-    if let Ok((enemy_entity, enemy_attack, enemy_speed, mut enemy_cooldown)) = enemy_query.single_mut() {
-        if let Ok((player_entity, player_defense)) = target_query.single() {
+    if let Ok((enemy_entity, enemy_attack, enemy_speed, mut enemy_cooldown, ratings)) = enemy_query.single_mut() {
+        if let Ok((player_entity, player_defense, player_evasion)) = target_query.single() {
             execute_attack_if_ready(
                 enemy_entity,
                 enemy_attack,
@@ -106,8 +214,13 @@ pub fn enemy_attack_system(
                 &mut enemy_cooldown,
                 player_entity,
                 player_defense,
+                &balance.damage,
+                attack_roll(ratings, player_evasion),
+                &mut rng,
                 &mut attack_events,
                 "Enemy",
+                BASE_ATTACK_TIME_MS,
+                BigFloat::from(1.0),
             );
         }
     }
@@ -116,20 +229,31 @@ pub fn enemy_attack_system(
 // Apply damage to targets
 pub fn damage_application_system(
     mut attack_events: EventReader<AttackEvent>,
-    mut hp_query: Query<&mut CurrentHp>,
+    mut commands: Commands,
+    mut hp_query: Query<(&mut CurrentHp, Option<&mut Shielded>)>,
     mut death_events: EventWriter<DeathEvent>,
     player_query: Query<Entity, With<Player>>,
     enemy_query: Query<Entity, With<Enemy>>,
 ) {
     for attack in attack_events.read() {
-        if let Ok(mut current_hp) = hp_query.get_mut(attack.target) {
+        if let Ok((mut current_hp, shielded)) = hp_query.get_mut(attack.target) {
             let old_hp = current_hp.0;
-            current_hp.0 = (current_hp.0 - attack.damage).max(BigFloat::from(0.0));
+            let mut damage = attack.damage;
+            // A shield soaks up damage first and breaks once it is used up
+            if let Some(mut shielded) = shielded {
+                let absorbed = if damage < shielded.absorb { damage } else { shielded.absorb };
+                shielded.absorb = shielded.absorb - absorbed;
+                damage = damage - absorbed;
+                if shielded.absorb <= BigFloat::from(0.0) {
+                    commands.entity(attack.target).try_remove::<Shielded>();
+                }
+            }
+            current_hp.0 = (current_hp.0 - damage).max(BigFloat::from(0.0));
             
-            println!("Target HP: {} -> {}", old_hp, current_hp.0);
+            trace!(target: "combat", "Target HP: {} -> {}", old_hp, current_hp.0);
             
-            // Check for death
-            if current_hp.0 <= BigFloat::from(0.0) {
+            // Check for death; a target already at zero HP (e.g. from poison) has been reported
+            if old_hp > BigFloat::from(0.0) && current_hp.0 <= BigFloat::from(0.0) {
                 if player_query.get(attack.target).is_ok() {
                     death_events.write(DeathEvent {
                         entity: attack.target,
@@ -2,22 +2,40 @@ use bevy::prelude::*;
 use too_big_float::BigFloat;
 use crate::components::*;
 use crate::events::*;
-use crate::systems::initialization::{rebirth_player_system, spawn_enemy};
-use crate::{CombatState, GameProgress, AutomationConfig};
+use crate::systems::initialization::spawn_enemy;
+use crate::systems::rebirth::{kept_level, reset_for_rebirth};
+use crate::{CombatState, GameProgress, AutomationConfig, BalanceConfig, RebirthCurrency, RebirthShop};
 
 // Detect deaths and handle the aftermath
 pub fn death_detection_system(
     mut death_events: EventReader<DeathEvent>,
     mut player_death_events: EventWriter<PlayerDeathEvent>,
+    mut boss_failed_events: EventWriter<BossFailedEvent>,
     mut enemy_death_events: EventWriter<EnemyDeathEvent>,
     enemy_query: Query<(&EnemyNumber, &ExpReward), With<Enemy>>,
+    boss_query: Query<&EnemyNumber, (With<Enemy>, With<Boss>)>,
 ) {
-    for death in death_events.read() {
+    let deaths: Vec<&DeathEvent> = death_events.read().collect();
+    // When both sides fall on the same tick the player's death wins: the enemy goes with the
+    // run (or the boss retreat), so it gives no EXP and spawns no successor next to the
+    // enemy the death handling already spawns
+    let player_died = deaths.iter().any(|death| death.entity_type == DeathEntityType::Player);
+    for death in deaths {
         match death.entity_type {
             DeathEntityType::Player => {
-                player_death_events.write(PlayerDeathEvent {
-                    player_entity: death.entity,
-                });
+                if let Ok(boss_number) = boss_query.single() {
+                    boss_failed_events.write(BossFailedEvent {
+                        player_entity: death.entity,
+                        enemy_number: boss_number.0,
+                    });
+                } else {
+                    player_death_events.write(PlayerDeathEvent {
+                        player_entity: death.entity,
+                    });
+                }
+            }
+            DeathEntityType::Enemy if player_died => {
+                debug!(target: "combat", "Enemy fell together with the player; no EXP awarded");
             }
             DeathEntityType::Enemy => {
                 if let Ok((enemy_number, exp_reward)) = enemy_query.get(death.entity) {
@@ -53,11 +71,12 @@ pub fn enemy_death_system(
             enemy_number: death.enemy_number + 1,
         });
         
-        println!("Enemy {} defeated! Gained {} EXP", death.enemy_number, death.exp_reward);
+        info!(target: "combat", "Enemy {} defeated! Gained {} EXP", death.enemy_number, death.exp_reward);
     }
 }
 
 // Handle player deaths - trigger rebirth
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn player_death_system(
     mut commands: Commands,
     mut player_death_events: EventReader<PlayerDeathEvent>,
@@ -65,23 +84,43 @@ pub fn player_death_system(
     mut game_progress: ResMut<GameProgress>,
     mut automation_config: ResMut<AutomationConfig>,
     mut combat_start_events: EventWriter<CombatStartEvent>,
+    mut currency: ResMut<RebirthCurrency>,
+    shop: Res<RebirthShop>,
+    balance: Res<BalanceConfig>,
     enemy_query: Query<Entity, With<Enemy>>,
+    level_query: Query<(&Level, &LifetimeExperience)>,
+    upgradeable_query: Query<Entity, Or<(
+        With<UpgradeableHp>,
+        With<UpgradeableAttack>,
+        With<UpgradeableDefense>,
+        With<UpgradeableSpeed>,
+        With<UpgradeableCritChance>,
+        With<UpgradeableCritMultiplier>,
+        With<UpgradeableEvasion>,
+        With<UpgradeableAccuracy>,
+    )>>,
 ) {
     // Process only the first death event to avoid moving commands multiple times
     if let Some(death) = player_death_events.read().next() {
-        println!("Game Over! Starting rebirth...");
+        info!(target: "rebirth", "Game Over! Starting rebirth...");
         
-        let rebirth_gain = BigFloat::from(game_progress.current_enemy_number as f64);
-        println!("Gained {} rebirth points", rebirth_gain);
-        
-        // Remove player and enemies
-        commands.entity(death.player_entity).despawn();
-        for enemy_entity in enemy_query.iter() {
-            commands.entity(enemy_entity).despawn();
-        }
+        // Replace player, its upgradeable stats and enemies with a reborn player at enemy #1
+        let despawned = std::iter::once(death.player_entity)
+            .chain(upgradeable_query.iter())
+            .chain(enemy_query.iter());
+        let rebirth_gain = reset_for_rebirth(
+            &mut commands,
+            &balance,
+            &mut game_progress,
+            &mut currency,
+            &shop,
+            RebirthCause::Death,
+            kept_level(level_query.get(death.player_entity).ok()),
+            despawned,
+        );
+        info!(target: "rebirth", "Gained {} rebirth points (lifetime: {})", rebirth_gain, currency.lifetime_points);
         
         // Reset game progress
-        game_progress.current_enemy_number = 1;
         game_progress.has_died_once = true;
         
         // Reset combat state
@@ -97,24 +136,57 @@ pub fn player_death_system(
         if automation_config.auto_retry_enabled {
             combat_start_events.write(CombatStartEvent { is_retry: true });
         }
-        
-        // Rebirth player with enhanced stats
-        rebirth_player_system(&mut commands, rebirth_gain);
-        
-        // Spawn first enemy
-        spawn_enemy(&mut commands, 1);
     }
 }
 
+// Handle boss failures - no rebirth, heal up and retreat to the checkpoint
+#[allow(clippy::type_complexity)]
+pub fn boss_failure_system(
+    mut commands: Commands,
+    mut boss_failed_events: EventReader<BossFailedEvent>,
+    mut game_progress: ResMut<GameProgress>,
+    balance: Res<BalanceConfig>,
+    mut player_query: Query<(&mut CurrentHp, &MaxHp, &mut AttackCooldown), (With<Player>, Without<Enemy>)>,
+    enemy_query: Query<Entity, With<Enemy>>,
+) {
+    if let Some(failure) = boss_failed_events.read().next() {
+        let checkpoint = balance.boss.checkpoint(failure.enemy_number);
+        info!(target: "combat", "Defeated by boss #{}! Retreating to enemy #{}", failure.enemy_number, checkpoint);
+
+        if let Ok((mut current_hp, max_hp, mut cooldown)) = player_query.get_mut(failure.player_entity) {
+            current_hp.0 = max_hp.0;
+            cooldown.0 = 0.0;
+            commands.entity(failure.player_entity).remove::<(Poisoned, Burning, Stunned, Slowed, Shielded)>();
+        }
+        for enemy_entity in enemy_query.iter() {
+            commands.entity(enemy_entity).despawn();
+        }
+
+        game_progress.current_enemy_number = checkpoint;
+        spawn_enemy(&mut commands, &balance, checkpoint);
+    }
+}
+
+// Multiplier exp_gain_system applies to every ExpGainEvent
+pub fn exp_gain_multiplier(balance: &BalanceConfig, shop: &RebirthShop, achievements: &Achievements) -> BigFloat {
+    shop.exp_multiplier(&balance.rebirth.shop) * achievements.exp_multiplier()
+}
+
 // Handle experience gain
 pub fn exp_gain_system(
     mut exp_events: EventReader<ExpGainEvent>,
-    mut player_query: Query<&mut Experience, With<Player>>,
+    mut player_query: Query<(&mut Experience, &mut LifetimeExperience), With<Player>>,
+    shop: Res<RebirthShop>,
+    achievements: Res<Achievements>,
+    balance: Res<BalanceConfig>,
 ) {
+    let multiplier = exp_gain_multiplier(&balance, &shop, &achievements);
     for exp in exp_events.read() {
-        if let Ok(mut player_exp) = player_query.single_mut() {
-            player_exp.0 += exp.amount;
-            println!("Gained {} EXP! Total: {}", exp.amount, player_exp.0);
+        if let Ok((mut player_exp, mut lifetime_exp)) = player_query.single_mut() {
+            let amount = exp.amount * multiplier;
+            player_exp.0 += amount;
+            lifetime_exp.0 += amount;
+            debug!(target: "progress", "Gained {} EXP! Total: {}", amount, player_exp.0);
         }
     }
 }
@@ -124,11 +196,12 @@ pub fn next_enemy_spawn_system(
     mut commands: Commands,
     mut next_enemy_events: EventReader<NextEnemySpawnEvent>,
     mut game_progress: ResMut<GameProgress>,
+    balance: Res<BalanceConfig>,
 ) {
     for spawn in next_enemy_events.read() {
         game_progress.current_enemy_number = spawn.enemy_number;
-        spawn_enemy(&mut commands, spawn.enemy_number);
-        println!("Spawning enemy #{}", spawn.enemy_number);
+        spawn_enemy(&mut commands, &balance, spawn.enemy_number);
+        debug!(target: "combat", "Spawning enemy #{}", spawn.enemy_number);
     }
 }
 
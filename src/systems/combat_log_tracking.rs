@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::events::*;
+use crate::systems::combat_end::exp_gain_multiplier;
+
+// Feed CombatLog from combat events, upgrade level changes and new rebirths
+#[allow(clippy::too_many_arguments)]
+pub fn combat_log_system(
+    mut attack_events: EventReader<AttackEvent>,
+    mut status_events: EventReader<StatusEffectEvent>,
+    mut skill_events: EventReader<SkillUsedEvent>,
+    mut enemy_death_events: EventReader<EnemyDeathEvent>,
+    mut player_death_events: EventReader<PlayerDeathEvent>,
+    mut exp_events: EventReader<ExpGainEvent>,
+    player_query: Query<(), With<Player>>,
+    enemy_query: Query<&EnemyNumber, With<Enemy>>,
+    upgrade_query: Query<(&UpgradeableStat, Ref<UpgradeLevel>), Changed<UpgradeLevel>>,
+    currency: Res<RebirthCurrency>,
+    shop: Res<RebirthShop>,
+    achievements: Res<Achievements>,
+    balance: Res<BalanceConfig>,
+    settings: Res<DisplaySettings>,
+    mut last_rebirth_count: Local<Option<u32>>,
+    mut log: ResMut<CombatLog>,
+) {
+    let name = |entity: Entity| match enemy_query.get(entity) {
+        Ok(number) => format!("Enemy #{}", number.0),
+        Err(_) if player_query.contains(entity) => "Player".to_string(),
+        Err(_) => "Enemy".to_string(),
+    };
+    for attack in attack_events.read() {
+        let message = if attack.missed {
+            format!("{} attacks {} - MISS", name(attack.attacker), name(attack.target))
+        } else {
+            format!(
+                "{} hits {} for {}{}",
+                name(attack.attacker),
+                name(attack.target),
+                settings.notation.format(attack.damage),
+                if attack.critical { " CRIT!" } else { "" },
+            )
+        };
+        log.push(CombatLogKind::Attack, message);
+    }
+    for status in status_events.read() {
+        let message = match status.damage {
+            Some(damage) => format!("{} deals {} to {}", status.kind.label(), settings.notation.format(damage), name(status.target)),
+            None => format!("{} is {}", name(status.target), status.kind.applied()),
+        };
+        log.push(CombatLogKind::Status, message);
+    }
+    for skill in skill_events.read() {
+        let message = match skill.skill {
+            SkillKind::PowerStrike => "Player uses Power Strike".to_string(),
+            SkillKind::Heal => format!("Player uses Heal (+{} HP)", settings.notation.format(skill.amount)),
+            SkillKind::Shield => format!("Player uses Shield (absorbs {})", settings.notation.format(skill.amount)),
+        };
+        log.push(CombatLogKind::Skill, message);
+    }
+    for death in enemy_death_events.read() {
+        log.push(CombatLogKind::Kill, format!("Enemy #{} defeated", death.enemy_number));
+    }
+    for _ in player_death_events.read() {
+        log.push(CombatLogKind::Kill, "Player defeated");
+    }
+    let multiplier = exp_gain_multiplier(&balance, &shop, &achievements);
+    for exp in exp_events.read() {
+        log.push(CombatLogKind::Exp, format!("+{} EXP", settings.notation.format(exp.amount * multiplier)));
+    }
+    for (stat, level) in upgrade_query.iter() {
+        // Freshly spawned stats (startup, rebirth) are not purchases
+        if !level.is_added() {
+            log.push(CombatLogKind::Upgrade, format!("{} upgraded to level {}", stat.name, level.0));
+        }
+    }
+    // The first frame only records the count a loaded save started with
+    if last_rebirth_count.is_some_and(|last| currency.rebirth_count > last) {
+        log.push(CombatLogKind::Rebirth, format!("Rebirth #{}", currency.rebirth_count));
+    }
+    *last_rebirth_count = Some(currency.rebirth_count);
+}
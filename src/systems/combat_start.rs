@@ -8,7 +8,8 @@ pub fn combat_start_system(
     mut combat_state: ResMut<CombatState>,
 ) {
     for event in combat_start_events.read() {
-        println!(
+        info!(
+            target: "combat",
             "Starting combat - {}",
             if event.is_retry { "Auto Retry" } else { "New Dungeon Entry" }
         );
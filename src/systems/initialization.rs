@@ -8,12 +8,14 @@ use crate::{StartupConfig, GameProgress};
 pub fn player_init_system(
     mut commands: Commands,
     config: Res<StartupConfig>,
+    balance: Res<BalanceConfig>,
 ) {
-    let base_hp = BigFloat::from(100.0);
-    let base_attack = BigFloat::from(10.0);
-    let base_defense = BigFloat::from(5.0);
-    let base_speed = BigFloat::from(1.0);
-    let base_cost = BigFloat::from(10.0);
+    let player_balance = &balance.player;
+    let base_hp = BigFloat::from(player_balance.base_hp);
+    let base_attack = BigFloat::from(player_balance.base_attack);
+    let base_defense = BigFloat::from(player_balance.base_defense);
+    let base_speed = BigFloat::from(player_balance.base_speed);
+    let base_cost = BigFloat::from(player_balance.base_upgrade_cost);
 
     // Create player entity with management stats (using config values)
     commands.spawn((
@@ -23,77 +25,143 @@ pub fn player_init_system(
         BaseDefense(base_defense.clone()),
         BaseSpeed(base_speed.clone()),
         Experience(BigFloat::from(config.experience as f64)),
-        Level(config.level),
+        // player_level_system raises this to config.level and applies the level bonuses
+        Level(1),
+        LifetimeExperience(balance.leveling.exp_to_reach(config.level)),
         RebirthPoints(BigFloat::from(0.0)),
     ));
 
     // Create upgradeable stat entities using typed bundles with config values
     commands.spawn((
         UpgradeableHp,
+        UpgradeableStat::new("HP"),
         Player,
         CurrentValue(base_hp.clone()),
         BaseValue(base_hp.clone()),
         UpgradeLevel(config.hp_level),
         UpgradeCost(base_cost.clone()),
-        UpgradeMultiplier(1.15),
-        CostMultiplier(1.3),
+        UpgradeMultiplier(player_balance.upgrade_multiplier),
+        CostMultiplier(player_balance.cost_multiplier),
     ));
 
     commands.spawn((
         UpgradeableAttack,
+        UpgradeableStat::new("Attack"),
         Player,
         CurrentValue(base_attack.clone()),
         BaseValue(base_attack.clone()),
         UpgradeLevel(config.attack_level),
         UpgradeCost(base_cost.clone()),
-        UpgradeMultiplier(1.15),
-        CostMultiplier(1.3),
+        UpgradeMultiplier(player_balance.upgrade_multiplier),
+        CostMultiplier(player_balance.cost_multiplier),
     ));
 
     commands.spawn((
         UpgradeableDefense,
+        UpgradeableStat::new("Defense"),
         Player,
         CurrentValue(base_defense.clone()),
         BaseValue(base_defense.clone()),
         UpgradeLevel(config.defense_level),
         UpgradeCost(base_cost.clone()),
-        UpgradeMultiplier(1.15),
-        CostMultiplier(1.3),
+        UpgradeMultiplier(player_balance.upgrade_multiplier),
+        CostMultiplier(player_balance.cost_multiplier),
     ));
 
     commands.spawn((
         UpgradeableSpeed,
+        UpgradeableStat::new("Speed"),
         Player,
         CurrentValue(base_speed),
         BaseValue(base_speed),
         UpgradeLevel(config.speed_level),
         UpgradeCost(base_cost),
-        UpgradeMultiplier(1.15),
-        CostMultiplier(1.3),
+        UpgradeMultiplier(player_balance.upgrade_multiplier),
+        CostMultiplier(player_balance.cost_multiplier),
     ));
+
+    spawn_rating_upgrades(&mut commands, &balance, &fresh_rating_upgrades(&balance));
+}
+
+// Combat rating upgrades of a new game: base values from the balance config, level 0
+pub fn fresh_rating_upgrades(balance: &BalanceConfig) -> [RebornUpgrade; 4] {
+    balance.player.base_ratings().map(|base| RebornUpgrade {
+        base_value: BigFloat::from(base),
+        level: 0,
+        cost: BigFloat::from(balance.player.base_upgrade_cost),
+    })
+}
+
+// Spawns the upgrade entities of StatKind::RATINGS, in that order
+pub fn spawn_rating_upgrades(commands: &mut Commands, balance: &BalanceConfig, upgrades: &[RebornUpgrade]) {
+    let upgrade_multiplier = balance.player.upgrade_multiplier;
+    for (stat, upgrade) in StatKind::RATINGS.into_iter().zip(upgrades) {
+        let mut entity = commands.spawn((
+            UpgradeableStat::new(stat.label()),
+            Player,
+            BaseValue(upgrade.base_value),
+            UpgradeCost(upgrade.cost),
+            UpgradeMultiplier(upgrade_multiplier),
+            CostMultiplier(balance.player.cost_multiplier),
+            upgrade.start(upgrade_multiplier),
+        ));
+        match stat {
+            StatKind::CritChance => entity.insert(UpgradeableCritChance),
+            StatKind::CritMultiplier => entity.insert(UpgradeableCritMultiplier),
+            StatKind::Evasion => entity.insert(UpgradeableEvasion),
+            _ => entity.insert(UpgradeableAccuracy),
+        };
+    }
+}
+
+// Upgraded value of a stat, falling back to the base stat before its upgrade entity exists
+fn upgraded_value<T: Component>(query: &Query<&CurrentValue, With<T>>, base: BigFloat) -> BigFloat {
+    query.single().map(|value| value.0).unwrap_or(base)
 }
 
 // Initialize combat by copying management stats to combat stats
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn combat_init_system(
     mut commands: Commands,
     player_query: Query<(Entity, &BaseHp, &BaseAttack, &BaseDefense, &BaseSpeed), (With<Player>, Without<CurrentHp>)>,
+    hp_query: Query<&CurrentValue, With<UpgradeableHp>>,
+    attack_query: Query<&CurrentValue, With<UpgradeableAttack>>,
+    defense_query: Query<&CurrentValue, With<UpgradeableDefense>>,
+    speed_query: Query<&CurrentValue, With<UpgradeableSpeed>>,
+    rating_queries: (
+        Query<&CurrentValue, With<UpgradeableCritChance>>,
+        Query<&CurrentValue, With<UpgradeableCritMultiplier>>,
+        Query<&CurrentValue, With<UpgradeableEvasion>>,
+        Query<&CurrentValue, With<UpgradeableAccuracy>>,
+    ),
     enemies: Query<Entity, With<Enemy>>,
     game_progress: Res<GameProgress>,
+    balance: Res<BalanceConfig>,
 ) {
     if let Ok((player_entity, base_hp, base_attack, base_defense, base_speed)) = player_query.single() {
-        // Add combat stats to player entity
-        commands.entity(player_entity).insert((
-            CurrentHp(base_hp.0),
-            MaxHp(base_hp.0),
-            CombatAttack(base_attack.0),
-            CombatDefense(base_defense.0),
-            CombatSpeed(base_speed.0),
+        // Upgrades bought (or started with) before combat count from the first fight
+        let hp = upgraded_value(&hp_query, base_hp.0);
+        let (crit_chance_query, crit_multiplier_query, evasion_query, accuracy_query) = &rating_queries;
+        let [crit_chance, crit_multiplier, evasion, accuracy] = balance.player.base_ratings().map(BigFloat::from);
+        // A rebirth in the same frame may despawn this player first
+        commands.entity(player_entity).try_insert((
+            CurrentHp(hp),
+            MaxHp(hp),
+            CombatAttack(upgraded_value(&attack_query, base_attack.0)),
+            CombatDefense(upgraded_value(&defense_query, base_defense.0)),
+            CombatSpeed(upgraded_value(&speed_query, base_speed.0)),
+            CombatCritChance(upgraded_value(crit_chance_query, crit_chance)),
+            CombatCritMultiplier(upgraded_value(crit_multiplier_query, crit_multiplier)),
+            CombatEvasion(upgraded_value(evasion_query, evasion)),
+            CombatAccuracy(upgraded_value(accuracy_query, accuracy)),
             AttackCooldown(0.0), // Start ready to attack
+            Mana::full(balance.skills.max_mana),
+            SkillCooldowns::default(),
         ));
 
         // Spawn initial enemy if none exist
         if enemies.iter().count() == 0 {
-            spawn_enemy(&mut commands, game_progress.current_enemy_number);
+            spawn_enemy(&mut commands, &balance, game_progress.current_enemy_number);
         }
 
         // Add combat timer
@@ -103,59 +171,183 @@ pub fn combat_init_system(
     }
 }
 
+// Enemy stats for a given enemy number
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnemyStats {
+    pub archetype: EnemyArchetype,
+    pub is_boss: bool,
+    pub hp: BigFloat,
+    pub attack: BigFloat,
+    pub defense: BigFloat,
+    pub speed: BigFloat,
+    pub exp_reward: BigFloat,
+    pub crit_chance: BigFloat,
+    pub crit_multiplier: BigFloat,
+    pub evasion: BigFloat,
+    pub accuracy: BigFloat,
+}
+
+pub fn enemy_stats(balance: &BalanceConfig, enemy_number: u32) -> EnemyStats {
+    let enemy = &balance.enemy;
+    let archetype = enemy.archetypes.select(enemy_number);
+    let modifiers = enemy.archetypes.balance(archetype);
+    let is_boss = balance.boss.is_boss(enemy_number);
+    let boss = |multiplier: f64| BigFloat::from(if is_boss { multiplier } else { 1.0 });
+    EnemyStats {
+        archetype,
+        is_boss,
+        hp: enemy.hp.at(enemy_number) * BigFloat::from(modifiers.hp) * boss(balance.boss.hp),
+        attack: enemy.attack.at(enemy_number) * BigFloat::from(modifiers.attack) * boss(balance.boss.attack),
+        defense: enemy.defense.at(enemy_number) * BigFloat::from(modifiers.defense) * boss(balance.boss.defense),
+        speed: enemy.speed.at(enemy_number) * BigFloat::from(modifiers.speed) * boss(balance.boss.speed),
+        exp_reward: enemy.exp_reward.at(enemy_number) * BigFloat::from(modifiers.exp_reward) * boss(balance.boss.exp_reward),
+        crit_chance: enemy.crit_chance.at(enemy_number),
+        crit_multiplier: enemy.crit_multiplier.at(enemy_number),
+        evasion: enemy.evasion.at(enemy_number),
+        accuracy: enemy.accuracy.at(enemy_number),
+    }
+}
+
 // Helper function to spawn enemies (keeping existing logic)
-pub fn spawn_enemy(commands: &mut Commands, enemy_number: u32) {
-    let base_hp = calculate_exponential_growth(BigFloat::from(20.0), 1.5, enemy_number);
-    let base_attack = calculate_exponential_growth(BigFloat::from(3.0), 1.3, enemy_number);
-    let base_defense = calculate_exponential_growth(BigFloat::from(2.0), 1.3, enemy_number);
-    let base_speed = calculate_exponential_growth(BigFloat::from(0.8), 1.1, enemy_number);
-    let base_exp = calculate_exponential_growth(BigFloat::from(5.0), 1.15, enemy_number);
+pub fn spawn_enemy(commands: &mut Commands, balance: &BalanceConfig, enemy_number: u32) {
+    let stats = enemy_stats(balance, enemy_number);
 
-    commands.spawn((
+    let mut enemy = commands.spawn((
         Enemy,
-        CurrentHp(base_hp),
-        MaxHp(base_hp),
-        CombatAttack(base_attack),
-        CombatDefense(base_defense),
-        CombatSpeed(base_speed),
-        ExpReward(base_exp),
+        stats.archetype,
+        CurrentHp(stats.hp),
+        MaxHp(stats.hp),
+        CombatAttack(stats.attack),
+        CombatDefense(stats.defense),
+        CombatSpeed(stats.speed),
+        CombatCritChance(stats.crit_chance),
+        CombatCritMultiplier(stats.crit_multiplier),
+        CombatEvasion(stats.evasion),
+        CombatAccuracy(stats.accuracy),
+        ExpReward(stats.exp_reward),
         EnemyNumber(enemy_number),
         AttackCooldown(0.0), // Start ready to attack
     ));
+
+    if stats.is_boss {
+        enemy.insert((
+            Boss,
+            EnrageTimer {
+                timer: Timer::from_seconds(balance.boss.enrage_secs, TimerMode::Once),
+                attack_multiplier: balance.boss.enrage_attack,
+                enraged: false,
+            },
+        ));
+        info!(target: "combat", "Boss #{} appears!", enemy_number);
+    }
+}
+
+// Starting stats after a rebirth with the given rebirth points
+#[derive(Clone, Debug, PartialEq)]
+pub struct RebirthStats {
+    pub bonus: BigFloat,
+    pub hp: BigFloat,
+    pub attack: BigFloat,
+    pub defense: BigFloat,
+    pub speed: BigFloat,
+    pub upgrade_cost: BigFloat,
+}
+
+pub fn rebirth_stats(balance: &BalanceConfig, rebirth_points: BigFloat) -> RebirthStats {
+    let player = &balance.player;
+    let rebirth = &balance.rebirth;
+    let one = BigFloat::from(1.0);
+    let bonus = rebirth_points * BigFloat::from(rebirth.stat_bonus_per_point) + one;
+    let cost_divisor = rebirth_points * BigFloat::from(rebirth.cost_reduction_per_point) + one;
+
+    RebirthStats {
+        bonus,
+        hp: BigFloat::from(player.base_hp) * bonus,
+        attack: BigFloat::from(player.base_attack) * bonus,
+        defense: BigFloat::from(player.base_defense) * bonus,
+        speed: BigFloat::from(player.base_speed) * bonus,
+        upgrade_cost: BigFloat::from(player.base_upgrade_cost) / cost_divisor,
+    }
+}
+
+// Starting state of one upgradeable stat after a rebirth
+#[derive(Clone, Debug, PartialEq)]
+pub struct RebornUpgrade {
+    pub base_value: BigFloat,
+    pub level: u32,
+    pub cost: BigFloat,
+}
+
+impl RebornUpgrade {
+    fn start(&self, upgrade_multiplier: f64) -> (UpgradeLevel, CurrentValue) {
+        let level = UpgradeLevel(self.level);
+        let value = recalculate_current_value(&BaseValue(self.base_value), &level, &UpgradeMultiplier(upgrade_multiplier));
+        (level, CurrentValue(value))
+    }
+}
+
+// Rebirth shop perks on top of rebirth_stats, in StatKind order.
+// Starting levels are free; their cost is as if they had been bought.
+// Combat ratings start from their base values and have no cost perk.
+// The player keeps `player_level`, so its level bonuses are already in the base values.
+pub fn reborn_upgrades(balance: &BalanceConfig, stats: &RebirthStats, shop: &RebirthShop, player_level: u32) -> [RebornUpgrade; StatKind::COUNT] {
+    let shop_balance = &balance.rebirth.shop;
+    let level = shop.starting_level(shop_balance);
+    let level_cost = big_pow(BigFloat::from(balance.player.cost_multiplier), level);
+    let [crit_chance, crit_multiplier, evasion, accuracy] = balance.player.base_ratings().map(BigFloat::from);
+    let base_values = [stats.hp, stats.attack, stats.defense, stats.speed, crit_chance, crit_multiplier, evasion, accuracy];
+    let level_bonuses = balance.leveling.bonuses_at(player_level);
+    std::array::from_fn(|index| {
+        let cost_divisor = RebirthPerk::CHEAPER_STATS
+            .get(index)
+            .map_or(BigFloat::from(1.0), |perk| shop.cost_divisor(*perk, shop_balance));
+        RebornUpgrade {
+            base_value: base_values[index] + BigFloat::from(level_bonuses[index]),
+            level,
+            cost: stats.upgrade_cost / cost_divisor * level_cost,
+        }
+    })
 }
 
 // Rebirth system for restarting with enhanced stats
+// `rebirth_points` is the lifetime total from RebirthCurrency; Level and lifetime EXP carry over
 pub fn rebirth_player_system(
     commands: &mut Commands,
-    additional_rebirth_points: BigFloat,
+    balance: &BalanceConfig,
+    rebirth_points: BigFloat,
+    shop: &RebirthShop,
+    (level, lifetime_experience): (Level, LifetimeExperience),
 ) {
-    let rebirth_bonus = additional_rebirth_points * BigFloat::from(0.1) + BigFloat::from(1.0);
-    
-    let base_hp = BigFloat::from(100.0) * rebirth_bonus;
-    let base_attack = BigFloat::from(10.0) * rebirth_bonus;
-    let base_defense = BigFloat::from(5.0) * rebirth_bonus;
-    let base_speed = BigFloat::from(1.0) * rebirth_bonus;
-    let base_cost = BigFloat::from(10.0) / (additional_rebirth_points * BigFloat::from(0.05) + BigFloat::from(1.0));
+    let stats = rebirth_stats(balance, rebirth_points);
+    let [hp, attack, defense, speed, ratings @ ..] = reborn_upgrades(balance, &stats, shop, level.0);
+    let upgrade_multiplier = balance.player.upgrade_multiplier;
+    let cost_multiplier = balance.player.cost_multiplier;
 
-    println!("Reborn with enhanced stats! Rebirth bonus: {}x", rebirth_bonus);
+    info!(target: "rebirth", "Reborn with enhanced stats! Rebirth bonus: {}x", stats.bonus);
 
     // Create new player with enhanced base stats
     commands.spawn((
         Player,
-        BaseHp(base_hp),
-        BaseAttack(base_attack),
-        BaseDefense(base_defense),
-        BaseSpeed(base_speed),
+        BaseHp(stats.hp),
+        BaseAttack(stats.attack),
+        BaseDefense(stats.defense),
+        BaseSpeed(stats.speed),
         Experience(BigFloat::from(0.0)),
-        Level(1),
-        RebirthPoints(additional_rebirth_points),
+        level,
+        lifetime_experience,
+        RebirthPoints(rebirth_points),
     ));
 
     // Create new upgradeable stat entities using typed bundles
-    commands.spawn(UpgradeableHpBundle::new(base_hp, base_cost, 1.15, 1.3));
-    commands.spawn(UpgradeableAttackBundle::new(base_attack, base_cost, 1.15, 1.3));
-    commands.spawn(UpgradeableDefenseBundle::new(base_defense, base_cost, 1.15, 1.3));
-    commands.spawn(UpgradeableSpeedBundle::new(base_speed, base_cost, 1.15, 1.3));
+    commands.spawn((UpgradeableHpBundle::new(hp.base_value, hp.cost, upgrade_multiplier, cost_multiplier), UpgradeableStat::new("HP"), Player))
+        .insert(hp.start(upgrade_multiplier));
+    commands.spawn((UpgradeableAttackBundle::new(attack.base_value, attack.cost, upgrade_multiplier, cost_multiplier), UpgradeableStat::new("Attack"), Player))
+        .insert(attack.start(upgrade_multiplier));
+    commands.spawn((UpgradeableDefenseBundle::new(defense.base_value, defense.cost, upgrade_multiplier, cost_multiplier), UpgradeableStat::new("Defense"), Player))
+        .insert(defense.start(upgrade_multiplier));
+    commands.spawn((UpgradeableSpeedBundle::new(speed.base_value, speed.cost, upgrade_multiplier, cost_multiplier), UpgradeableStat::new("Speed"), Player))
+        .insert(speed.start(upgrade_multiplier));
+    spawn_rating_upgrades(commands, balance, &ratings);
 
     // Add combat timer
     commands.spawn(CombatTimer {
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+use crate::components::*;
+
+// Raise Level from lifetime EXP and add the per-level bonuses to each stat's base value.
+// Level never goes down, so saves from before leveling keep their level.
+#[allow(clippy::type_complexity)]
+pub fn player_level_system(
+    mut player_query: Query<(&LifetimeExperience, &mut Level), (With<Player>, Changed<LifetimeExperience>)>,
+    mut stat_query: Query<(&mut BaseValue, StatMarkers), With<UpgradeableStat>>,
+    balance: Res<BalanceConfig>,
+) {
+    let Ok((lifetime_exp, mut level)) = player_query.single_mut() else { return };
+    let reached = balance.leveling.level_for(lifetime_exp.0);
+    if reached <= level.0 {
+        return;
+    }
+
+    let gained = (reached - level.0) as f64;
+    let bonuses = balance.leveling.stat_bonuses();
+    for (mut base_value, markers) in &mut stat_query {
+        let bonus = bonuses[markers.kind().index()];
+        if bonus != 0.0 {
+            base_value.0 += BigFloat::from(bonus * gained);
+        }
+    }
+    level.0 = reached;
+    info!(target: "progress", "Level up! Now level {}", reached);
+}
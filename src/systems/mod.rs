@@ -3,9 +3,27 @@ pub mod combat_core;
 pub mod combat_start;
 pub mod combat_end;
 pub mod upgrades;
+pub mod persistence;
+pub mod offline_progress;
+pub mod rebirth;
+pub mod leveling;
+pub mod achievement_tracking;
+pub mod statistics_tracking;
+pub mod combat_log_tracking;
+pub mod status_effect_tracking;
+pub mod skill_tracking;
 
 pub use initialization::*;
 pub use combat_core::*;
 pub use combat_start::*;
 pub use combat_end::*;
-pub use upgrades::*;
\ No newline at end of file
+pub use upgrades::*;
+pub use persistence::*;
+pub use offline_progress::*;
+pub use rebirth::*;
+pub use leveling::*;
+pub use achievement_tracking::*;
+pub use statistics_tracking::*;
+pub use combat_log_tracking::*;
+pub use status_effect_tracking::*;
+pub use skill_tracking::*;
\ No newline at end of file
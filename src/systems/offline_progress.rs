@@ -0,0 +1,411 @@
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+use crate::components::*;
+use crate::systems::initialization::{enemy_stats, reborn_upgrades, rebirth_stats, EnemyStats, RebornUpgrade};
+use crate::systems::achievement_tracking::record_offline_kill;
+use crate::systems::combat_end::exp_gain_multiplier;
+use crate::systems::combat_core::AttackRoll;
+use crate::systems::persistence::{
+    AchievementsSave, CombatRatingsSave, StatisticsSave, LoadedSave, RebirthSave, SaveData, UpgradeStrategySave, SaveSettings, UpgradeSave, unix_now,
+};
+use crate::systems::upgrades::{choose_upgrade, purchase_upgrade, UpgradeOption};
+
+// Upper bound on simulated fights so a stalled simulation always terminates
+const MAX_OFFLINE_FIGHTS: u64 = 1_000_000;
+
+// Base attack cooldown set after every attack (see execute_attack_if_ready)
+const BASE_ATTACK_TIME_MS: f64 = 1000.0;
+
+// Summary of what happened while the game was closed
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct OfflineProgressReport {
+    pub elapsed_secs: u64,
+    pub enemies_killed: u64,
+    pub exp_earned: BigFloat,
+    pub upgrades_bought: u32,
+    pub deaths: u32,
+    pub boss_retreats: u32,
+    pub rebirth_points_gained: BigFloat,
+    pub highest_enemy: u32,
+}
+
+impl OfflineProgressReport {
+    fn new(elapsed_secs: u64, enemy_number: u32) -> Self {
+        Self {
+            elapsed_secs,
+            enemies_killed: 0,
+            exp_earned: BigFloat::from(0.0),
+            upgrades_bought: 0,
+            deaths: 0,
+            boss_retreats: 0,
+            rebirth_points_gained: BigFloat::from(0.0),
+            highest_enemy: enemy_number,
+        }
+    }
+}
+
+// Working copy of one upgradeable stat during the simulation
+struct OfflineStat {
+    base_value: BaseValue,
+    level: UpgradeLevel,
+    cost: UpgradeCost,
+    upgrade_multiplier: UpgradeMultiplier,
+    cost_multiplier: CostMultiplier,
+}
+
+impl OfflineStat {
+    fn new(balance: &BalanceConfig, base_value: BigFloat, level: u32, cost: BigFloat) -> Self {
+        Self {
+            base_value: BaseValue(base_value),
+            level: UpgradeLevel(level),
+            cost: UpgradeCost(cost),
+            upgrade_multiplier: UpgradeMultiplier(balance.player.upgrade_multiplier),
+            cost_multiplier: CostMultiplier(balance.player.cost_multiplier),
+        }
+    }
+
+    fn reborn(balance: &BalanceConfig, upgrade: &RebornUpgrade) -> Self {
+        Self::new(balance, upgrade.base_value, upgrade.level, upgrade.cost)
+    }
+
+    fn from_save(balance: &BalanceConfig, save: &UpgradeSave) -> Self {
+        Self::new(balance, save.base_value.into(), save.level, save.cost.into())
+    }
+
+    fn value(&self) -> BigFloat {
+        recalculate_current_value(&self.base_value, &self.level, &self.upgrade_multiplier)
+    }
+
+    fn upgrade(&self) -> RebornUpgrade {
+        RebornUpgrade {
+            base_value: self.base_value.0,
+            level: self.level.0,
+            cost: self.cost.0,
+        }
+    }
+
+    fn write_to(&self, save: &mut UpgradeSave) {
+        save.base_value = self.base_value.0.into();
+        save.level = self.level.0;
+        save.cost = self.cost.0.into();
+    }
+}
+
+// Stats are in StatKind order
+struct OfflineRun {
+    stats: [OfflineStat; StatKind::COUNT],
+    experience: BigFloat,
+    lifetime_experience: BigFloat,
+    level: u32,
+    current_hp: BigFloat,
+    // Remaining player cooldown in the same units as AttackCooldown
+    player_cooldown: f64,
+    // Rebirth shop perks and achievement bonuses (see exp_gain_system and player_attack_system)
+    exp_multiplier: BigFloat,
+    damage_multiplier: BigFloat,
+    player_attack_time_ms: f64,
+}
+
+impl OfflineRun {
+    fn hp(&self) -> BigFloat { self.stats[0].value() }
+    fn attack(&self) -> BigFloat { self.stats[1].value() }
+    fn defense(&self) -> BigFloat { self.stats[2].value() }
+    fn speed(&self) -> f64 { self.stats[3].value().to_f64().unwrap_or(1.0) }
+    fn rating(&self, stat: StatKind) -> BigFloat { self.stats[stat.index()].value() }
+
+    // Same as exp_gain_system + player_level_system; level bonuses land on the base values
+    fn gain_exp(&mut self, amount: BigFloat, leveling: &LevelingBalance) {
+        self.experience += amount;
+        self.lifetime_experience += amount;
+        let reached = leveling.level_for(self.lifetime_experience);
+        if reached <= self.level {
+            return;
+        }
+        let gained = (reached - self.level) as f64;
+        for (stat, bonus) in self.stats.iter_mut().zip(leveling.stat_bonuses()) {
+            stat.base_value.0 += BigFloat::from(bonus * gained);
+        }
+        self.level = reached;
+        // A bigger max HP refills health like hp_sync_system
+        if leveling.hp_per_level != 0.0 {
+            self.current_hp = self.hp();
+        }
+    }
+
+    // Same loop as upgradeable_stat_upgrade_system; buying HP refills health like hp_sync_system
+    fn buy_upgrades(&mut self, strategy: &mut UpgradeStrategy, enemy: &EnemyStats, damage: &DamageBalance) -> u32 {
+        let mut bought = 0;
+        while strategy.enabled {
+            let options = self.stats.each_ref()
+                .map(|stat| UpgradeOption::new(&stat.base_value, &stat.level, &stat.cost, &stat.upgrade_multiplier));
+            let Some(index) = choose_upgrade(strategy, &options, self.experience, enemy, damage) else { break };
+            let stat = &mut self.stats[index];
+            if !purchase_upgrade(&mut self.experience, &mut stat.level, &mut stat.cost, &stat.cost_multiplier) {
+                break;
+            }
+            strategy.bought(index);
+            bought += 1;
+            if index == 0 {
+                self.current_hp = stat.value();
+            }
+        }
+        bought
+    }
+}
+
+enum FightOutcome {
+    Won { duration_ms: f64, damage_dealt: BigFloat, damage_taken: BigFloat },
+    Lost { duration_ms: f64, damage_dealt: BigFloat, damage_taken: BigFloat },
+}
+
+// Time between attacks: cooldown attack_time/speed drained at `speed` per millisecond
+fn attack_interval_ms(attack_time_ms: f64, speed: f64) -> f64 {
+    attack_time_ms / speed / speed
+}
+
+fn hits_to_kill(hp: BigFloat, damage: BigFloat) -> f64 {
+    (hp / damage).to_f64().map(f64::ceil).unwrap_or(f64::INFINITY).max(1.0)
+}
+
+// Enemy hits land at 0, interval, 2*interval...; hits after the first `calm_hits` deal enraged damage
+fn enemy_hits_to_kill(hp: BigFloat, damage: BigFloat, enraged_damage: BigFloat, calm_hits: f64) -> f64 {
+    let calm_kill = hits_to_kill(hp, damage);
+    if calm_kill <= calm_hits {
+        return calm_kill;
+    }
+    calm_hits + hits_to_kill(hp - damage * BigFloat::from(calm_hits), enraged_damage)
+}
+
+fn enemy_damage_dealt(hits: f64, damage: BigFloat, enraged_damage: BigFloat, calm_hits: f64) -> BigFloat {
+    let calm = hits.min(calm_hits);
+    let enraged = (hits - calm_hits).max(0.0);
+    damage * BigFloat::from(calm) + enraged_damage * BigFloat::from(enraged)
+}
+
+// Resolve one fight in closed form - stats cannot change until someone dies.
+// Misses, crits and poison/burn damage are folded into the average damage per attack;
+// stuns, slows and skills are left out, so auto-cast does not help while offline.
+fn resolve_fight(run: &OfflineRun, balance: &BalanceConfig, enemy_number: u32) -> FightOutcome {
+    let enemy = enemy_stats(balance, enemy_number);
+    let player_roll = AttackRoll::new(
+        run.rating(StatKind::CritChance),
+        run.rating(StatKind::CritMultiplier),
+        run.rating(StatKind::Accuracy),
+        enemy.evasion,
+    );
+    let enemy_roll = AttackRoll::new(enemy.crit_chance, enemy.crit_multiplier, enemy.accuracy, run.rating(StatKind::Evasion));
+    let player_speed = run.speed();
+    let enemy_speed = enemy.speed.to_f64().unwrap_or(1.0);
+    let player_interval = attack_interval_ms(run.player_attack_time_ms, player_speed);
+    let enemy_interval = attack_interval_ms(BASE_ATTACK_TIME_MS, enemy_speed);
+
+    let player_dot = balance.status.player.expected_dot_factor(player_interval / 1000.0);
+    let enemy_dot = balance.status.enemy.expected_dot_factor(enemy_interval / 1000.0);
+    let player_factor = BigFloat::from(player_roll.expected_factor() * (1.0 + player_dot));
+    let enemy_factor = BigFloat::from(enemy_roll.expected_factor() * (1.0 + enemy_dot));
+    let damage_to_enemy = balance.damage.damage(run.attack(), enemy.defense) * run.damage_multiplier * player_factor;
+    let damage_to_player = balance.damage.damage(enemy.attack, run.defense()) * enemy_factor;
+
+    // Bosses switch to enraged damage after enrage_secs (see boss_enrage_system)
+    let (enraged_damage, calm_hits) = if enemy.is_boss {
+        let enraged_attack = enemy.attack * BigFloat::from(balance.boss.enrage_attack);
+        let enrage_ms = balance.boss.enrage_secs as f64 * 1000.0;
+        (balance.damage.damage(enraged_attack, run.defense()) * enemy_factor, (enrage_ms / enemy_interval).ceil())
+    } else {
+        (damage_to_player, f64::INFINITY)
+    };
+
+    let first_hit_ms = run.player_cooldown / player_speed;
+    let player_hits = hits_to_kill(enemy.hp, damage_to_enemy);
+    let player_kill_ms = first_hit_ms + (player_hits - 1.0) * player_interval;
+    let enemy_hits = enemy_hits_to_kill(run.current_hp, damage_to_player, enraged_damage, calm_hits);
+    let enemy_kill_ms = (enemy_hits - 1.0) * enemy_interval;
+
+    // Simultaneous kills count as a loss with no EXP for the enemy, as in death_detection_system
+    if player_kill_ms < enemy_kill_ms {
+        let enemy_hits = (player_kill_ms / enemy_interval).floor() + 1.0;
+        FightOutcome::Won {
+            duration_ms: player_kill_ms,
+            damage_dealt: damage_to_enemy * BigFloat::from(player_hits),
+            damage_taken: enemy_damage_dealt(enemy_hits, damage_to_player, enraged_damage, calm_hits),
+        }
+    } else {
+        let landed = if enemy_kill_ms < first_hit_ms {
+            0.0
+        } else {
+            ((enemy_kill_ms - first_hit_ms) / player_interval).floor() + 1.0
+        };
+        FightOutcome::Lost {
+            duration_ms: enemy_kill_ms,
+            damage_dealt: damage_to_enemy * BigFloat::from(landed),
+            damage_taken: enemy_damage_dealt(enemy_hits, damage_to_player, enraged_damage, calm_hits),
+        }
+    }
+}
+
+// Fast-forward a saved game by `elapsed_secs`, one fight at a time instead of frame by frame
+pub fn simulate_offline_progress(
+    save: &mut SaveData,
+    balance: &BalanceConfig,
+    elapsed_secs: u64,
+) -> OfflineProgressReport {
+    let mut report = OfflineProgressReport::new(elapsed_secs, save.progress.current_enemy_number);
+    if !save.combat.in_dungeon || save.combat.is_game_over {
+        return report;
+    }
+
+    let shop = RebirthShop::from(&save.shop);
+    let mut strategy = UpgradeStrategy::from(&save.upgrade_strategy);
+    let mut achievements = Achievements::from(&save.achievements);
+    let mut statistics = Statistics::from(&save.statistics);
+    let [crit_chance, crit_multiplier, evasion, accuracy] = save.combat_ratings.upgrades();
+    let mut run = OfflineRun {
+        stats: [
+            OfflineStat::from_save(balance, &save.hp),
+            OfflineStat::from_save(balance, &save.attack),
+            OfflineStat::from_save(balance, &save.defense),
+            OfflineStat::from_save(balance, &save.speed),
+            OfflineStat::reborn(balance, &crit_chance),
+            OfflineStat::reborn(balance, &crit_multiplier),
+            OfflineStat::reborn(balance, &evasion),
+            OfflineStat::reborn(balance, &accuracy),
+        ],
+        experience: save.player.experience.into(),
+        lifetime_experience: save.player.lifetime_experience.into(),
+        level: save.player.level,
+        current_hp: BigFloat::from(0.0),
+        player_cooldown: 0.0,
+        exp_multiplier: exp_gain_multiplier(balance, &shop, &achievements),
+        damage_multiplier: achievements.damage_multiplier(),
+        player_attack_time_ms: BASE_ATTACK_TIME_MS * shop.attack_time_multiplier(&balance.rebirth.shop),
+    };
+    run.current_hp = run.hp();
+
+    let budget_ms = elapsed_secs as f64 * 1000.0;
+    let mut elapsed_ms = 0.0;
+    let mut enemy_number = save.progress.current_enemy_number;
+
+    for _ in 0..MAX_OFFLINE_FIGHTS {
+        // Mirror achievement_system; new bonuses apply from the next fight on
+        if !achievements.check(save.rebirth.rebirth_count).is_empty() {
+            run.exp_multiplier = exp_gain_multiplier(balance, &shop, &achievements);
+            run.damage_multiplier = achievements.damage_multiplier();
+        }
+        match resolve_fight(&run, balance, enemy_number) {
+            FightOutcome::Won { duration_ms, damage_dealt, damage_taken } => {
+                elapsed_ms += duration_ms;
+                if elapsed_ms > budget_ms {
+                    break;
+                }
+                statistics.record_damage_dealt(damage_dealt);
+                statistics.record_damage_taken(damage_taken);
+                let base_reward = enemy_stats(balance, enemy_number).exp_reward;
+                let reward = base_reward * run.exp_multiplier;
+                record_offline_kill(&mut achievements, enemy_number, base_reward);
+                statistics.record_kill(enemy_number);
+                statistics.record_exp(reward);
+                run.current_hp = run.current_hp - damage_taken;
+                run.player_cooldown = run.player_attack_time_ms / run.speed();
+                run.gain_exp(reward, &balance.leveling);
+                report.exp_earned += reward;
+                report.enemies_killed += 1;
+                enemy_number += 1;
+                report.highest_enemy = report.highest_enemy.max(enemy_number);
+                report.upgrades_bought += run.buy_upgrades(&mut strategy, &enemy_stats(balance, enemy_number), &balance.damage);
+                let highest_level = run.stats.iter().map(|stat| stat.level.0).max().unwrap_or(0);
+                achievements.highest_upgrade_level = achievements.highest_upgrade_level.max(highest_level);
+            }
+            FightOutcome::Lost { duration_ms, damage_dealt, damage_taken } => {
+                elapsed_ms += duration_ms;
+                if elapsed_ms > budget_ms {
+                    break;
+                }
+                statistics.record_damage_dealt(damage_dealt);
+                statistics.record_damage_taken(damage_taken);
+                // Mirror boss_failure_system: heal and retreat, the run goes on
+                if balance.boss.is_boss(enemy_number) {
+                    report.boss_retreats += 1;
+                    run.current_hp = run.hp();
+                    run.player_cooldown = 0.0;
+                    enemy_number = balance.boss.checkpoint(enemy_number);
+                    continue;
+                }
+                // Mirror player_death_system + rebirth_player_system
+                let rebirth_gain = BigFloat::from(enemy_number as f64);
+                let mut currency = RebirthCurrency::from(&save.rebirth);
+                currency.record(rebirth_gain, enemy_number, RebirthCause::Death);
+                let reborn = rebirth_stats(balance, currency.lifetime_points);
+                let upgrades = reborn_upgrades(balance, &reborn, &shop, run.level);
+                report.deaths += 1;
+                achievements.deaths += 1;
+                statistics.deaths += 1;
+                report.rebirth_points_gained += rebirth_gain;
+
+                save.player.base_hp = reborn.hp.into();
+                save.player.base_attack = reborn.attack.into();
+                save.player.base_defense = reborn.defense.into();
+                save.player.base_speed = reborn.speed.into();
+                save.player.rebirth_points = currency.lifetime_points.into();
+                save.rebirth = RebirthSave::from(&currency);
+                statistics.sync_rebirths(currency.rebirth_count);
+                save.progress.has_died_once = true;
+                save.automation.auto_retry_unlocked = true;
+
+                run.stats = upgrades.each_ref().map(|upgrade| OfflineStat::reborn(balance, upgrade));
+                run.experience = BigFloat::from(0.0);
+                run.current_hp = run.hp();
+                run.player_cooldown = 0.0;
+                enemy_number = 1;
+
+                if !save.automation.auto_retry_enabled {
+                    save.combat.is_game_over = true;
+                    save.combat.in_dungeon = false;
+                    break;
+                }
+            }
+        }
+    }
+
+    save.player.experience = run.experience.into();
+    save.player.lifetime_experience = run.lifetime_experience.into();
+    save.player.level = run.level;
+    run.stats[0].write_to(&mut save.hp);
+    run.stats[1].write_to(&mut save.attack);
+    run.stats[2].write_to(&mut save.defense);
+    run.stats[3].write_to(&mut save.speed);
+    let [_, _, _, _, ratings @ ..] = run.stats.each_ref().map(OfflineStat::upgrade);
+    save.combat_ratings = CombatRatingsSave::from_upgrades(&ratings);
+    save.progress.current_enemy_number = enemy_number;
+    save.upgrade_strategy = UpgradeStrategySave::from(&strategy);
+    achievements.check(save.rebirth.rebirth_count);
+    save.achievements = AchievementsSave::from(&achievements);
+    save.statistics = StatisticsSave::from(&statistics);
+    report
+}
+
+// Apply offline progress to the loaded save before it is restored
+pub fn offline_progress_system(
+    mut commands: Commands,
+    mut loaded: ResMut<LoadedSave>,
+    settings: Res<SaveSettings>,
+    balance: Res<BalanceConfig>,
+) {
+    let elapsed_secs = unix_now()
+        .saturating_sub(loaded.0.saved_at)
+        .min(settings.max_offline_duration.as_secs());
+    if elapsed_secs == 0 {
+        return;
+    }
+
+    let report = simulate_offline_progress(&mut loaded.0, &balance, elapsed_secs);
+    info!(
+        target: "offline",
+        "While you were away ({}s): defeated {} enemies, gained {} EXP, bought {} upgrades, died {} times, retreated from {} bosses",
+        report.elapsed_secs, report.enemies_killed, report.exp_earned, report.upgrades_bought, report.deaths,
+        report.boss_retreats
+    );
+    if loaded.0.automation.auto_cast_enabled {
+        debug!(target: "offline", "Skills are not simulated offline; auto-cast resumes once the game is running");
+    }
+    commands.insert_resource(report);
+}
@@ -0,0 +1,767 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use too_big_float::BigFloat;
+use crate::components::*;
+use crate::{CombatState, GameProgress, AutomationConfig, BalanceConfig, NumberNotation};
+use crate::systems::initialization::{spawn_rating_upgrades, RebornUpgrade};
+
+// Bump when the layout of SaveData changes
+pub const SAVE_VERSION: u32 = 1;
+
+// Scaling step used to bring a BigFloat into f64 range (2^512)
+const SCALE_BITS: i64 = 512;
+
+#[derive(Resource, Clone, Debug)]
+pub struct SaveSettings {
+    pub path: PathBuf,
+    pub autosave_interval: Duration,
+    // Offline progress is capped at this much wall time
+    pub max_offline_duration: Duration,
+}
+
+impl Default for SaveSettings {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("savegame.ron"),
+            autosave_interval: Duration::from_secs(30),
+            max_offline_duration: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+// Save read at startup; its presence replaces player_init_system
+#[derive(Resource, Clone, Debug)]
+pub struct LoadedSave(pub SaveData);
+
+#[derive(Resource)]
+pub struct AutosaveTimer {
+    pub timer: Timer,
+}
+
+// BigFloat stored as mantissa * 2^exponent so values beyond f64 range survive a round trip.
+// Scaling only by powers of two keeps the mantissa bits untouched.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct SavedBigFloat {
+    pub mantissa: f64,
+    pub exponent: i64,
+}
+
+impl From<BigFloat> for SavedBigFloat {
+    fn from(value: BigFloat) -> Self {
+        let zero = BigFloat::from(0.0);
+        let negative = value < zero;
+        let mut magnitude = if negative { zero - value } else { value };
+        let mut exponent = 0;
+
+        if magnitude != zero {
+            let upper = BigFloat::from(2f64.powi(SCALE_BITS as i32));
+            let lower = BigFloat::from(2f64.powi(-SCALE_BITS as i32));
+            if magnitude > upper {
+                for (step, bits) in power_ladder(magnitude, upper).into_iter().rev() {
+                    if magnitude > step {
+                        magnitude = magnitude / step;
+                        exponent += bits;
+                    }
+                }
+            } else if magnitude < lower {
+                let inverse = BigFloat::from(1.0) / magnitude;
+                for (step, bits) in power_ladder(inverse, upper).into_iter().rev() {
+                    if magnitude * step < BigFloat::from(1.0) {
+                        magnitude = magnitude * step;
+                        exponent -= bits;
+                    }
+                }
+            }
+        }
+
+        let mantissa = magnitude.to_f64().unwrap_or(0.0);
+        Self {
+            mantissa: if negative { -mantissa } else { mantissa },
+            exponent,
+        }
+    }
+}
+
+impl From<SavedBigFloat> for BigFloat {
+    fn from(saved: SavedBigFloat) -> Self {
+        let value = BigFloat::from(saved.mantissa);
+        if saved.exponent == 0 {
+            return value;
+        }
+        let step = BigFloat::from(2f64.powi(SCALE_BITS as i32));
+        let mut scale = BigFloat::from(1.0);
+        let mut square = step;
+        let mut remaining = saved.exponent.unsigned_abs() / SCALE_BITS as u64;
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                scale = scale * square;
+            }
+            square = square * square;
+            remaining >>= 1;
+        }
+        if saved.exponent > 0 { value * scale } else { value / scale }
+    }
+}
+
+// Repeated squares of `step` (with their bit counts) that do not exceed `limit`
+fn power_ladder(limit: BigFloat, step: BigFloat) -> Vec<(BigFloat, i64)> {
+    let mut ladder = vec![(step, SCALE_BITS)];
+    while let Some(&(power, bits)) = ladder.last() {
+        let squared = power * power;
+        if squared > limit {
+            break;
+        }
+        ladder.push((squared, bits * 2));
+    }
+    ladder
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PlayerSave {
+    pub base_hp: SavedBigFloat,
+    pub base_attack: SavedBigFloat,
+    pub base_defense: SavedBigFloat,
+    pub base_speed: SavedBigFloat,
+    pub experience: SavedBigFloat,
+    pub level: u32,
+    pub lifetime_experience: SavedBigFloat,
+    pub rebirth_points: SavedBigFloat,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct UpgradeSave {
+    pub level: u32,
+    pub cost: SavedBigFloat,
+    pub base_value: SavedBigFloat,
+}
+
+impl From<&UpgradeSave> for RebornUpgrade {
+    fn from(save: &UpgradeSave) -> Self {
+        Self {
+            base_value: save.base_value.into(),
+            level: save.level,
+            cost: save.cost.into(),
+        }
+    }
+}
+
+impl From<&RebornUpgrade> for UpgradeSave {
+    fn from(upgrade: &RebornUpgrade) -> Self {
+        Self {
+            level: upgrade.level,
+            cost: upgrade.cost.into(),
+            base_value: upgrade.base_value.into(),
+        }
+    }
+}
+
+// Combat rating upgrades, in StatKind::RATINGS order
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CombatRatingsSave {
+    pub crit_chance: UpgradeSave,
+    pub crit_multiplier: UpgradeSave,
+    pub evasion: UpgradeSave,
+    pub accuracy: UpgradeSave,
+}
+
+impl CombatRatingsSave {
+    pub fn from_upgrades(upgrades: &[RebornUpgrade; 4]) -> Self {
+        let [crit_chance, crit_multiplier, evasion, accuracy] = upgrades.each_ref().map(UpgradeSave::from);
+        Self { crit_chance, crit_multiplier, evasion, accuracy }
+    }
+
+    pub fn upgrades(&self) -> [RebornUpgrade; 4] {
+        [&self.crit_chance, &self.crit_multiplier, &self.evasion, &self.accuracy].map(RebornUpgrade::from)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ProgressSave {
+    pub current_enemy_number: u32,
+    pub has_died_once: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CombatSave {
+    pub is_game_over: bool,
+    pub in_dungeon: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AutomationSave {
+    pub auto_retry_unlocked: bool,
+    pub auto_retry_enabled: bool,
+    pub auto_cast_enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RebirthRecordSave {
+    pub points_gained: SavedBigFloat,
+    pub enemy_reached: u32,
+    pub cause: RebirthCause,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RebirthSave {
+    pub points: SavedBigFloat,
+    pub lifetime_points: SavedBigFloat,
+    pub rebirth_count: u32,
+    pub history: Vec<RebirthRecordSave>,
+}
+
+impl From<&RebirthCurrency> for RebirthSave {
+    fn from(currency: &RebirthCurrency) -> Self {
+        Self {
+            points: currency.points.into(),
+            lifetime_points: currency.lifetime_points.into(),
+            rebirth_count: currency.rebirth_count,
+            history: currency.history.iter().map(|record| RebirthRecordSave {
+                points_gained: record.points_gained.into(),
+                enemy_reached: record.enemy_reached,
+                cause: record.cause,
+            }).collect(),
+        }
+    }
+}
+
+impl From<&RebirthSave> for RebirthCurrency {
+    fn from(save: &RebirthSave) -> Self {
+        Self {
+            points: save.points.into(),
+            lifetime_points: save.lifetime_points.into(),
+            rebirth_count: save.rebirth_count,
+            history: save.history.iter().map(|record| RebirthRecord {
+                points_gained: record.points_gained.into(),
+                enemy_reached: record.enemy_reached,
+                cause: record.cause,
+            }).collect::<VecDeque<_>>(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RebirthShopSave {
+    pub exp_multiplier: u32,
+    pub starting_levels: u32,
+    pub cheaper_hp: u32,
+    pub cheaper_attack: u32,
+    pub cheaper_defense: u32,
+    pub cheaper_speed: u32,
+    pub faster_cooldown: u32,
+}
+
+impl From<&RebirthShop> for RebirthShopSave {
+    fn from(shop: &RebirthShop) -> Self {
+        Self {
+            exp_multiplier: shop.exp_multiplier,
+            starting_levels: shop.starting_levels,
+            cheaper_hp: shop.cheaper_hp,
+            cheaper_attack: shop.cheaper_attack,
+            cheaper_defense: shop.cheaper_defense,
+            cheaper_speed: shop.cheaper_speed,
+            faster_cooldown: shop.faster_cooldown,
+        }
+    }
+}
+
+impl From<&RebirthShopSave> for RebirthShop {
+    fn from(save: &RebirthShopSave) -> Self {
+        Self {
+            exp_multiplier: save.exp_multiplier,
+            starting_levels: save.starting_levels,
+            cheaper_hp: save.cheaper_hp,
+            cheaper_attack: save.cheaper_attack,
+            cheaper_defense: save.cheaper_defense,
+            cheaper_speed: save.cheaper_speed,
+            faster_cooldown: save.faster_cooldown,
+        }
+    }
+}
+
+// Unlocked achievements are stored by id so the list can grow between versions
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct AchievementsSave {
+    pub unlocked: Vec<String>,
+    pub highest_enemy_defeated: u32,
+    pub deaths: u32,
+    pub highest_upgrade_level: u32,
+    pub total_exp: SavedBigFloat,
+}
+
+impl From<&Achievements> for AchievementsSave {
+    fn from(achievements: &Achievements) -> Self {
+        Self {
+            unlocked: ACHIEVEMENTS.iter()
+                .zip(achievements.unlocked)
+                .filter(|(_, unlocked)| *unlocked)
+                .map(|(achievement, _)| achievement.id.to_string())
+                .collect(),
+            highest_enemy_defeated: achievements.highest_enemy_defeated,
+            deaths: achievements.deaths,
+            highest_upgrade_level: achievements.highest_upgrade_level,
+            total_exp: achievements.total_exp.into(),
+        }
+    }
+}
+
+impl From<&AchievementsSave> for Achievements {
+    fn from(save: &AchievementsSave) -> Self {
+        Self {
+            // Ids that no longer exist are dropped
+            unlocked: std::array::from_fn(|index| save.unlocked.iter().any(|id| id == ACHIEVEMENTS[index].id)),
+            highest_enemy_defeated: save.highest_enemy_defeated,
+            deaths: save.deaths,
+            highest_upgrade_level: save.highest_upgrade_level,
+            total_exp: save.total_exp.into(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct StatTotalsSave {
+    pub damage_dealt: SavedBigFloat,
+    pub damage_taken: SavedBigFloat,
+    pub kills: u64,
+    pub highest_enemy: u32,
+    pub exp_earned: SavedBigFloat,
+    pub time_played_secs: f64,
+}
+
+impl From<&StatTotals> for StatTotalsSave {
+    fn from(totals: &StatTotals) -> Self {
+        Self {
+            damage_dealt: totals.damage_dealt.into(),
+            damage_taken: totals.damage_taken.into(),
+            kills: totals.kills,
+            highest_enemy: totals.highest_enemy,
+            exp_earned: totals.exp_earned.into(),
+            time_played_secs: totals.time_played_secs,
+        }
+    }
+}
+
+impl From<&StatTotalsSave> for StatTotals {
+    fn from(save: &StatTotalsSave) -> Self {
+        Self {
+            damage_dealt: save.damage_dealt.into(),
+            damage_taken: save.damage_taken.into(),
+            kills: save.kills,
+            highest_enemy: save.highest_enemy,
+            exp_earned: save.exp_earned.into(),
+            time_played_secs: save.time_played_secs,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct StatisticsSave {
+    pub run: StatTotalsSave,
+    pub lifetime: StatTotalsSave,
+    pub deaths: u32,
+    pub rebirths: u32,
+}
+
+impl From<&Statistics> for StatisticsSave {
+    fn from(statistics: &Statistics) -> Self {
+        Self {
+            run: StatTotalsSave::from(&statistics.run),
+            lifetime: StatTotalsSave::from(&statistics.lifetime),
+            deaths: statistics.deaths,
+            rebirths: statistics.rebirths,
+        }
+    }
+}
+
+impl From<&StatisticsSave> for Statistics {
+    fn from(save: &StatisticsSave) -> Self {
+        Self {
+            run: StatTotals::from(&save.run),
+            lifetime: StatTotals::from(&save.lifetime),
+            deaths: save.deaths,
+            rebirths: save.rebirths,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct UpgradeStrategySave {
+    pub enabled: bool,
+    pub kind: UpgradeStrategyKind,
+    pub weights: Vec<f64>,
+    pub round_robin_next: usize,
+}
+
+impl Default for UpgradeStrategySave {
+    fn default() -> Self {
+        Self::from(&UpgradeStrategy::default())
+    }
+}
+
+impl From<&UpgradeStrategy> for UpgradeStrategySave {
+    fn from(strategy: &UpgradeStrategy) -> Self {
+        Self {
+            enabled: strategy.enabled,
+            kind: strategy.kind,
+            weights: strategy.weights.to_vec(),
+            round_robin_next: strategy.round_robin_next,
+        }
+    }
+}
+
+impl From<&UpgradeStrategySave> for UpgradeStrategy {
+    fn from(save: &UpgradeStrategySave) -> Self {
+        let mut weights = UpgradeStrategy::default().weights;
+        for (weight, saved) in weights.iter_mut().zip(&save.weights) {
+            *weight = *saved;
+        }
+        Self {
+            enabled: save.enabled,
+            kind: save.kind,
+            weights,
+            round_robin_next: save.round_robin_next,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct SettingsSave {
+    pub notation: NumberNotation,
+}
+
+impl From<&DisplaySettings> for SettingsSave {
+    fn from(settings: &DisplaySettings) -> Self {
+        Self {
+            notation: settings.notation,
+        }
+    }
+}
+
+impl From<&SettingsSave> for DisplaySettings {
+    fn from(save: &SettingsSave) -> Self {
+        Self {
+            notation: save.notation,
+        }
+    }
+}
+
+// The seed plus how far into its stream the game has drawn
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct RngSave {
+    pub seed: u64,
+    pub word_pos: u64,
+}
+
+impl From<&GameRng> for RngSave {
+    fn from(rng: &GameRng) -> Self {
+        Self {
+            seed: rng.seed(),
+            word_pos: rng.word_pos(),
+        }
+    }
+}
+
+impl From<&RngSave> for GameRng {
+    fn from(save: &RngSave) -> Self {
+        GameRng::resume(save.seed, save.word_pos)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SaveData {
+    pub version: u32,
+    // Unix seconds at save time
+    pub saved_at: u64,
+    pub player: PlayerSave,
+    pub hp: UpgradeSave,
+    pub attack: UpgradeSave,
+    pub defense: UpgradeSave,
+    pub speed: UpgradeSave,
+    pub combat_ratings: CombatRatingsSave,
+    pub progress: ProgressSave,
+    pub combat: CombatSave,
+    pub automation: AutomationSave,
+    pub rebirth: RebirthSave,
+    pub shop: RebirthShopSave,
+    pub upgrade_strategy: UpgradeStrategySave,
+    pub achievements: AchievementsSave,
+    pub statistics: StatisticsSave,
+    pub settings: SettingsSave,
+    pub rng: RngSave,
+}
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(std::io::Error),
+    Parse(ron::error::SpannedError),
+    Serialize(ron::Error),
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Io(err) => write!(f, "I/O error: {}", err),
+            SaveError::Parse(err) => write!(f, "invalid save file: {}", err),
+            SaveError::Serialize(err) => write!(f, "could not serialize save: {}", err),
+            SaveError::UnsupportedVersion(version) => write!(
+                f, "save version {} is newer than supported version {}", version, SAVE_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+pub fn save_to_string(data: &SaveData) -> Result<String, SaveError> {
+    ron::ser::to_string_pretty(data, ron::ser::PrettyConfig::default()).map_err(SaveError::Serialize)
+}
+
+pub fn save_from_str(contents: &str) -> Result<SaveData, SaveError> {
+    let data: SaveData = ron::from_str(contents).map_err(SaveError::Parse)?;
+    if data.version > SAVE_VERSION {
+        return Err(SaveError::UnsupportedVersion(data.version));
+    }
+    Ok(data)
+}
+
+// Write through a temporary file so a crash mid-write never corrupts the existing save
+pub fn write_save_file(path: &Path, data: &SaveData) -> Result<(), SaveError> {
+    let contents = save_to_string(data)?;
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, contents).map_err(SaveError::Io)?;
+    fs::rename(&tmp_path, path).map_err(SaveError::Io)
+}
+
+pub fn read_save_file(path: &Path) -> Result<Option<SaveData>, SaveError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = fs::read_to_string(path).map_err(SaveError::Io)?;
+    save_from_str(&contents).map(Some)
+}
+
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+// Player components a save file records
+type SavedPlayer = (
+    &'static BaseHp,
+    &'static BaseAttack,
+    &'static BaseDefense,
+    &'static BaseSpeed,
+    &'static Experience,
+    &'static Level,
+    &'static LifetimeExperience,
+    &'static RebirthPoints,
+);
+
+// Read-only view of everything that goes into a save file
+#[derive(SystemParam)]
+pub struct SaveSnapshot<'w, 's> {
+    player_query: Query<'w, 's, SavedPlayer, With<Player>>,
+    hp_query: Query<'w, 's, (&'static UpgradeLevel, &'static UpgradeCost, &'static BaseValue), With<UpgradeableHp>>,
+    attack_query: Query<'w, 's, (&'static UpgradeLevel, &'static UpgradeCost, &'static BaseValue), With<UpgradeableAttack>>,
+    defense_query: Query<'w, 's, (&'static UpgradeLevel, &'static UpgradeCost, &'static BaseValue), With<UpgradeableDefense>>,
+    speed_query: Query<'w, 's, (&'static UpgradeLevel, &'static UpgradeCost, &'static BaseValue), With<UpgradeableSpeed>>,
+    crit_chance_query: Query<'w, 's, (&'static UpgradeLevel, &'static UpgradeCost, &'static BaseValue), With<UpgradeableCritChance>>,
+    crit_multiplier_query: Query<'w, 's, (&'static UpgradeLevel, &'static UpgradeCost, &'static BaseValue), With<UpgradeableCritMultiplier>>,
+    evasion_query: Query<'w, 's, (&'static UpgradeLevel, &'static UpgradeCost, &'static BaseValue), With<UpgradeableEvasion>>,
+    accuracy_query: Query<'w, 's, (&'static UpgradeLevel, &'static UpgradeCost, &'static BaseValue), With<UpgradeableAccuracy>>,
+    game_progress: Res<'w, GameProgress>,
+    combat_state: Res<'w, CombatState>,
+    automation_config: Res<'w, AutomationConfig>,
+    rebirth_currency: Res<'w, RebirthCurrency>,
+    rebirth_shop: Res<'w, RebirthShop>,
+    upgrade_strategy: Res<'w, UpgradeStrategy>,
+    achievements: Res<'w, Achievements>,
+    statistics: Res<'w, Statistics>,
+    display_settings: Res<'w, DisplaySettings>,
+    rng: Res<'w, GameRng>,
+}
+
+fn upgrade_save((level, cost, base_value): (&UpgradeLevel, &UpgradeCost, &BaseValue)) -> UpgradeSave {
+    UpgradeSave {
+        level: level.0,
+        cost: cost.0.into(),
+        base_value: base_value.0.into(),
+    }
+}
+
+impl SaveSnapshot<'_, '_> {
+    // None while the player is between despawn and respawn (e.g. mid-rebirth)
+    pub fn capture(&self) -> Option<SaveData> {
+        let (base_hp, base_attack, base_defense, base_speed, experience, level, lifetime_experience, rebirth_points) =
+            self.player_query.single().ok()?;
+
+        Some(SaveData {
+            version: SAVE_VERSION,
+            saved_at: unix_now(),
+            player: PlayerSave {
+                base_hp: base_hp.0.into(),
+                base_attack: base_attack.0.into(),
+                base_defense: base_defense.0.into(),
+                base_speed: base_speed.0.into(),
+                experience: experience.0.into(),
+                level: level.0,
+                lifetime_experience: lifetime_experience.0.into(),
+                rebirth_points: rebirth_points.0.into(),
+            },
+            hp: upgrade_save(self.hp_query.single().ok()?),
+            attack: upgrade_save(self.attack_query.single().ok()?),
+            defense: upgrade_save(self.defense_query.single().ok()?),
+            speed: upgrade_save(self.speed_query.single().ok()?),
+            combat_ratings: CombatRatingsSave {
+                crit_chance: upgrade_save(self.crit_chance_query.single().ok()?),
+                crit_multiplier: upgrade_save(self.crit_multiplier_query.single().ok()?),
+                evasion: upgrade_save(self.evasion_query.single().ok()?),
+                accuracy: upgrade_save(self.accuracy_query.single().ok()?),
+            },
+            progress: ProgressSave {
+                current_enemy_number: self.game_progress.current_enemy_number,
+                has_died_once: self.game_progress.has_died_once,
+            },
+            combat: CombatSave {
+                is_game_over: self.combat_state.is_game_over,
+                in_dungeon: self.combat_state.in_dungeon,
+            },
+            automation: AutomationSave {
+                auto_retry_unlocked: self.automation_config.auto_retry_unlocked,
+                auto_retry_enabled: self.automation_config.auto_retry_enabled,
+                auto_cast_enabled: self.automation_config.auto_cast_enabled,
+            },
+            rebirth: RebirthSave::from(&*self.rebirth_currency),
+            shop: RebirthShopSave::from(&*self.rebirth_shop),
+            upgrade_strategy: UpgradeStrategySave::from(&*self.upgrade_strategy),
+            achievements: AchievementsSave::from(&*self.achievements),
+            statistics: StatisticsSave::from(&*self.statistics),
+            settings: SettingsSave::from(&*self.display_settings),
+            rng: RngSave::from(&*self.rng),
+        })
+    }
+}
+
+fn write_snapshot(settings: &SaveSettings, snapshot: &SaveSnapshot) {
+    let Some(data) = snapshot.capture() else { return };
+    match write_save_file(&settings.path, &data) {
+        Ok(()) => info!(target: "save", "Game saved to {}", settings.path.display()),
+        Err(err) => error!(target: "save", "Failed to save game to {}: {}", settings.path.display(), err),
+    }
+}
+
+// Read the save file before the player is spawned
+pub fn load_save_system(
+    mut commands: Commands,
+    settings: Res<SaveSettings>,
+) {
+    commands.insert_resource(AutosaveTimer {
+        timer: Timer::new(settings.autosave_interval, TimerMode::Repeating),
+    });
+
+    match read_save_file(&settings.path) {
+        Ok(Some(data)) => {
+            info!(target: "save", "Loaded save from {}", settings.path.display());
+            commands.insert_resource(LoadedSave(data));
+        }
+        Ok(None) => info!(target: "save", "No save found at {}, starting a new game", settings.path.display()),
+        Err(err) => {
+            // Keep the unreadable file around instead of overwriting it on the next autosave
+            let backup = settings.path.with_extension("corrupt");
+            warn!(target: "save", "Could not load {}: {} (moved to {})", settings.path.display(), err, backup.display());
+            let _ = fs::rename(&settings.path, &backup);
+        }
+    }
+}
+
+// Spawn the player and restore resources from the loaded save
+pub fn restore_game_system(
+    mut commands: Commands,
+    loaded: Res<LoadedSave>,
+    balance: Res<BalanceConfig>,
+) {
+    let data = &loaded.0;
+    let player = &data.player;
+
+    commands.spawn((
+        Player,
+        BaseHp(player.base_hp.into()),
+        BaseAttack(player.base_attack.into()),
+        BaseDefense(player.base_defense.into()),
+        BaseSpeed(player.base_speed.into()),
+        Experience(player.experience.into()),
+        Level(player.level),
+        LifetimeExperience(player.lifetime_experience.into()),
+        RebirthPoints(player.rebirth_points.into()),
+    ));
+
+    commands.spawn((UpgradeableHp, UpgradeableStat::new("HP"), Player, restored_upgrade(&balance, &data.hp)));
+    commands.spawn((UpgradeableAttack, UpgradeableStat::new("Attack"), Player, restored_upgrade(&balance, &data.attack)));
+    commands.spawn((UpgradeableDefense, UpgradeableStat::new("Defense"), Player, restored_upgrade(&balance, &data.defense)));
+    commands.spawn((UpgradeableSpeed, UpgradeableStat::new("Speed"), Player, restored_upgrade(&balance, &data.speed)));
+    spawn_rating_upgrades(&mut commands, &balance, &data.combat_ratings.upgrades());
+
+    commands.insert_resource(GameProgress {
+        current_enemy_number: data.progress.current_enemy_number,
+        has_died_once: data.progress.has_died_once,
+    });
+    commands.insert_resource(CombatState {
+        is_game_over: data.combat.is_game_over,
+        in_dungeon: data.combat.in_dungeon,
+    });
+    commands.insert_resource(AutomationConfig {
+        auto_retry_unlocked: data.automation.auto_retry_unlocked,
+        auto_retry_enabled: data.automation.auto_retry_enabled,
+        auto_cast_enabled: data.automation.auto_cast_enabled,
+    });
+    commands.insert_resource(RebirthCurrency::from(&data.rebirth));
+    commands.insert_resource(RebirthShop::from(&data.shop));
+    commands.insert_resource(UpgradeStrategy::from(&data.upgrade_strategy));
+    commands.insert_resource(Achievements::from(&data.achievements));
+    commands.insert_resource(Statistics::from(&data.statistics));
+    commands.insert_resource(DisplaySettings::from(&data.settings));
+    commands.insert_resource(GameRng::from(&data.rng));
+}
+
+// CurrentValue is recomputed by update_current_value_on_change once spawned
+fn restored_upgrade(balance: &BalanceConfig, save: &UpgradeSave) -> impl Bundle {
+    let base_value: BigFloat = save.base_value.into();
+    (
+        CurrentValue(base_value),
+        BaseValue(base_value),
+        UpgradeLevel(save.level),
+        UpgradeCost(save.cost.into()),
+        UpgradeMultiplier(balance.player.upgrade_multiplier),
+        CostMultiplier(balance.player.cost_multiplier),
+    )
+}
+
+pub fn autosave_system(
+    time: Res<Time>,
+    mut autosave: ResMut<AutosaveTimer>,
+    settings: Res<SaveSettings>,
+    snapshot: SaveSnapshot,
+) {
+    autosave.timer.tick(time.delta());
+    if autosave.timer.just_finished() {
+        write_snapshot(&settings, &snapshot);
+    }
+}
+
+pub fn save_on_exit_system(
+    mut exit_events: EventReader<AppExit>,
+    settings: Res<SaveSettings>,
+    snapshot: SaveSnapshot,
+) {
+    if exit_events.read().next().is_some() {
+        write_snapshot(&settings, &snapshot);
+    }
+}
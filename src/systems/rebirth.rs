@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+use crate::components::*;
+use crate::events::*;
+use crate::systems::initialization::{rebirth_player_system, rebirth_stats, spawn_enemy, RebirthStats};
+use crate::{BalanceConfig, GameProgress};
+
+// Rebirth points earned by resetting now - the same for deaths and manual rebirths
+pub fn rebirth_gain(game_progress: &GameProgress) -> BigFloat {
+    BigFloat::from(game_progress.current_enemy_number as f64)
+}
+
+// What rebirthing right now would give, computed with rebirth_player_system's formula
+#[derive(Clone, Debug, PartialEq)]
+pub struct RebirthPreview {
+    pub points_gained: BigFloat,
+    pub lifetime_points: BigFloat,
+    pub current_bonus: BigFloat,
+    pub stats: RebirthStats,
+    // Upgrade cost relative to the base cost, e.g. 0.8 = 20% cheaper
+    pub cost_multiplier: BigFloat,
+    pub available: bool,
+}
+
+impl RebirthPreview {
+    pub fn new(balance: &BalanceConfig, game_progress: &GameProgress, currency: &RebirthCurrency) -> Self {
+        let points_gained = rebirth_gain(game_progress);
+        let stats = rebirth_stats(balance, currency.lifetime_points + points_gained);
+        let cost_multiplier = stats.upgrade_cost / BigFloat::from(balance.player.base_upgrade_cost);
+        Self {
+            points_gained,
+            lifetime_points: currency.lifetime_points,
+            current_bonus: rebirth_stats(balance, currency.lifetime_points).bonus,
+            stats,
+            cost_multiplier,
+            available: game_progress.current_enemy_number >= balance.rebirth.manual_min_enemy,
+        }
+    }
+}
+
+// What the reborn player keeps from `player`; without one it starts over at level 1
+pub fn kept_level(player: Option<(&Level, &LifetimeExperience)>) -> (Level, LifetimeExperience) {
+    player.map_or((Level(1), LifetimeExperience(BigFloat::from(0.0))), |(level, exp)| (level.clone(), exp.clone()))
+}
+
+// Bank the points, despawn the player, its stats and enemies, then spawn a reborn player facing enemy #1
+#[allow(clippy::too_many_arguments)]
+pub fn reset_for_rebirth(
+    commands: &mut Commands,
+    balance: &BalanceConfig,
+    game_progress: &mut GameProgress,
+    currency: &mut RebirthCurrency,
+    shop: &RebirthShop,
+    cause: RebirthCause,
+    kept: (Level, LifetimeExperience),
+    entities: impl IntoIterator<Item = Entity>,
+) -> BigFloat {
+    let points_gained = rebirth_gain(game_progress);
+    currency.record(points_gained, game_progress.current_enemy_number, cause);
+
+    for entity in entities {
+        commands.entity(entity).despawn();
+    }
+    game_progress.current_enemy_number = 1;
+    rebirth_player_system(commands, balance, currency.lifetime_points, shop, kept);
+    spawn_enemy(commands, balance, 1);
+    points_gained
+}
+
+// Voluntary rebirth from the Rebirth tab; combat state is left as it is
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn manual_rebirth_system(
+    mut commands: Commands,
+    mut rebirth_events: EventReader<RebirthRequestEvent>,
+    mut player_death_events: EventReader<PlayerDeathEvent>,
+    mut game_progress: ResMut<GameProgress>,
+    mut currency: ResMut<RebirthCurrency>,
+    shop: Res<RebirthShop>,
+    balance: Res<BalanceConfig>,
+    reset_query: Query<Entity, Or<(With<Player>, With<Enemy>)>>,
+    level_query: Query<(&Level, &LifetimeExperience), With<Player>>,
+) {
+    let requested = rebirth_events.read().count() > 0;
+    // A death this frame already rebirths the player
+    let died = player_death_events.read().count() > 0;
+    if !requested || died {
+        return;
+    }
+
+    if game_progress.current_enemy_number < balance.rebirth.manual_min_enemy {
+        info!(target: "rebirth", "Rebirth locked until enemy #{}", balance.rebirth.manual_min_enemy);
+        return;
+    }
+
+    let points_gained = reset_for_rebirth(
+        &mut commands,
+        &balance,
+        &mut game_progress,
+        &mut currency,
+        &shop,
+        RebirthCause::Manual,
+        kept_level(level_query.single().ok()),
+        reset_query.iter(),
+    );
+    info!(target: "rebirth", "Manual rebirth! Gained {} rebirth points (lifetime: {})", points_gained, currency.lifetime_points);
+}
+
+// Buy perks requested from the Rebirth tab shop
+pub fn rebirth_shop_purchase_system(
+    mut purchase_events: EventReader<RebirthShopPurchaseEvent>,
+    mut shop: ResMut<RebirthShop>,
+    mut currency: ResMut<RebirthCurrency>,
+    balance: Res<BalanceConfig>,
+) {
+    for purchase in purchase_events.read() {
+        if shop.purchase(purchase.perk, &balance.rebirth.shop, &mut currency) {
+            info!(target: "rebirth", "Bought {} level {} (rebirth points left: {})", purchase.perk.name(), shop.level(purchase.perk), currency.points);
+        }
+    }
+}
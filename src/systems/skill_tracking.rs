@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+use crate::components::*;
+use crate::events::*;
+use crate::{AutomationConfig, BalanceConfig, CombatState};
+
+// Refill mana, count skill cooldowns down and let shields run out while in the dungeon
+pub fn skill_cooldown_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    combat_state: Res<CombatState>,
+    balance: Res<BalanceConfig>,
+    mut player_query: Query<(Entity, &mut Mana, &mut SkillCooldowns, Option<&mut Shielded>), With<Player>>,
+) {
+    if combat_state.is_game_over || !combat_state.in_dungeon {
+        return;
+    }
+
+    let delta = time.delta_secs();
+    for (entity, mut mana, mut cooldowns, shielded) in player_query.iter_mut() {
+        mana.current = (mana.current + balance.skills.mana_per_sec * delta as f64).min(mana.max);
+        for remaining in cooldowns.0.iter_mut().filter(|remaining| **remaining > 0.0) {
+            *remaining = (*remaining - delta).max(0.0);
+        }
+        if let Some(mut shielded) = shielded {
+            shielded.remaining_secs -= delta;
+            if shielded.remaining_secs <= 0.0 {
+                commands.entity(entity).try_remove::<Shielded>();
+            }
+        }
+    }
+}
+
+// With auto-cast on, fire Power Strike whenever it is ready, Heal once the missing HP
+// would be fully restored and Shield whenever none is up
+#[allow(clippy::type_complexity)]
+pub fn skill_auto_cast_system(
+    automation: Res<AutomationConfig>,
+    combat_state: Res<CombatState>,
+    balance: Res<BalanceConfig>,
+    player_query: Query<(&CurrentHp, &MaxHp, &Mana, &SkillCooldowns, Has<Shielded>), (With<Player>, Without<Stunned>)>,
+    enemy_query: Query<(), With<Enemy>>,
+    mut cast_events: EventWriter<SkillCastEvent>,
+) {
+    if !automation.auto_cast_enabled || combat_state.is_game_over || !combat_state.in_dungeon || enemy_query.is_empty() {
+        return;
+    }
+
+    let Ok((current_hp, max_hp, mana, cooldowns, shielded)) = player_query.single() else {
+        return;
+    };
+    let mut mana_left = mana.current;
+    for skill in SkillKind::ALL {
+        let stats = balance.skills.get(skill);
+        if !cooldowns.is_ready(skill) || mana_left < stats.cost {
+            continue;
+        }
+        let wanted = match skill {
+            SkillKind::PowerStrike => true,
+            SkillKind::Heal => current_hp.0 <= max_hp.0 * BigFloat::from(1.0 - stats.potency),
+            SkillKind::Shield => !shielded,
+        };
+        if wanted {
+            mana_left -= stats.cost;
+            cast_events.write(SkillCastEvent { skill });
+        }
+    }
+}
+
+// Cast requested skills that are off cooldown and affordable; Power Strike lands as an
+// ordinary AttackEvent so damage, death and on-hit effects follow the normal pipeline
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn skill_cast_system(
+    mut commands: Commands,
+    mut cast_events: EventReader<SkillCastEvent>,
+    mut attack_events: EventWriter<AttackEvent>,
+    mut used_events: EventWriter<SkillUsedEvent>,
+    mut player_query: Query<
+        (Entity, &CombatAttack, &mut CurrentHp, &MaxHp, &mut Mana, &mut SkillCooldowns),
+        (With<Player>, Without<Enemy>, Without<Stunned>),
+    >,
+    enemy_query: Query<(Entity, &CombatDefense), (With<Enemy>, Without<Player>)>,
+    combat_state: Res<CombatState>,
+    achievements: Res<Achievements>,
+    balance: Res<BalanceConfig>,
+) {
+    if combat_state.is_game_over || !combat_state.in_dungeon {
+        cast_events.clear();
+        return;
+    }
+    let Ok((player_entity, attack, mut current_hp, max_hp, mut mana, mut cooldowns)) = player_query.single_mut() else {
+        cast_events.clear();
+        return;
+    };
+
+    for cast in cast_events.read() {
+        let skill = cast.skill;
+        let stats = balance.skills.get(skill);
+        if !cooldowns.is_ready(skill) || mana.current < stats.cost || current_hp.0 <= BigFloat::from(0.0) {
+            continue;
+        }
+
+        let amount = match skill {
+            SkillKind::PowerStrike => {
+                let Ok((enemy_entity, enemy_defense)) = enemy_query.single() else {
+                    continue;
+                };
+                let damage = balance.damage.damage(attack.0, enemy_defense.0)
+                    * BigFloat::from(stats.potency)
+                    * achievements.damage_multiplier();
+                attack_events.write(AttackEvent {
+                    attacker: player_entity,
+                    target: enemy_entity,
+                    damage,
+                    critical: false,
+                    missed: false,
+                });
+                BigFloat::from(0.0)
+            }
+            SkillKind::Heal => {
+                let old_hp = current_hp.0;
+                let healed = current_hp.0 + max_hp.0 * BigFloat::from(stats.potency);
+                current_hp.0 = if healed > max_hp.0 { max_hp.0 } else { healed };
+                current_hp.0 - old_hp
+            }
+            SkillKind::Shield => {
+                let absorb = max_hp.0 * BigFloat::from(stats.potency);
+                commands.entity(player_entity).try_insert(Shielded { absorb, remaining_secs: stats.duration_secs });
+                absorb
+            }
+        };
+
+        mana.current -= stats.cost;
+        cooldowns.0[skill.index()] = stats.cooldown_secs;
+        debug!(target: "combat", "Player uses {} ({} mana left)", skill.label(), mana.current);
+        used_events.write(SkillUsedEvent { skill, amount });
+    }
+}
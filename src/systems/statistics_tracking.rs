@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::events::*;
+use crate::systems::combat_end::exp_gain_multiplier;
+
+// Feed Statistics from combat events; a new rebirth in RebirthCurrency starts a fresh run
+#[allow(clippy::too_many_arguments)]
+pub fn statistics_system(
+    time: Res<Time>,
+    mut attack_events: EventReader<AttackEvent>,
+    mut status_events: EventReader<StatusEffectEvent>,
+    mut enemy_death_events: EventReader<EnemyDeathEvent>,
+    mut player_death_events: EventReader<PlayerDeathEvent>,
+    mut exp_events: EventReader<ExpGainEvent>,
+    player_query: Query<(), (With<Player>, With<CurrentHp>)>,
+    currency: Res<RebirthCurrency>,
+    shop: Res<RebirthShop>,
+    achievements: Res<Achievements>,
+    balance: Res<BalanceConfig>,
+    mut statistics: ResMut<Statistics>,
+) {
+    for attack in attack_events.read() {
+        if player_query.contains(attack.attacker) {
+            statistics.record_damage_dealt(attack.damage);
+        } else if player_query.contains(attack.target) {
+            statistics.record_damage_taken(attack.damage);
+        }
+    }
+    // Poison and burn ticks; every damage over time on an enemy came from the player
+    for (target, damage) in status_events.read().filter_map(|event| event.damage.map(|damage| (event.target, damage))) {
+        if player_query.contains(target) {
+            statistics.record_damage_taken(damage);
+        } else {
+            statistics.record_damage_dealt(damage);
+        }
+    }
+    for death in enemy_death_events.read() {
+        statistics.record_kill(death.enemy_number);
+    }
+    let multiplier = exp_gain_multiplier(&balance, &shop, &achievements);
+    for exp in exp_events.read() {
+        statistics.record_exp(exp.amount * multiplier);
+    }
+    statistics.deaths += player_death_events.read().count() as u32;
+    statistics.record_time(time.delta_secs_f64());
+    // After the events so the fatal fight still counts toward the run that ended
+    statistics.sync_rebirths(currency.rebirth_count);
+}
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use rand::Rng;
+use too_big_float::BigFloat;
+use crate::components::*;
+use crate::events::*;
+use crate::{BalanceConfig, CombatState};
+
+fn damage_over_time(hit: BigFloat, effect: &StatusEffectBalance) -> DamageOverTime {
+    DamageOverTime::new(hit * BigFloat::from(effect.potency * DOT_TICK_SECS as f64), effect.duration_secs)
+}
+
+// Roll the attacker's on-hit effects for every landed attack whose target survived it
+pub fn status_effect_application_system(
+    mut commands: Commands,
+    mut attack_events: EventReader<AttackEvent>,
+    mut status_events: EventWriter<StatusEffectEvent>,
+    player_query: Query<(), With<Player>>,
+    hp_query: Query<&CurrentHp>,
+    balance: Res<BalanceConfig>,
+    mut rng: ResMut<GameRng>,
+) {
+    for attack in attack_events.read() {
+        let alive = hp_query.get(attack.target).is_ok_and(|hp| hp.0 > BigFloat::from(0.0));
+        if attack.missed || !alive {
+            continue;
+        }
+        let effects = if player_query.contains(attack.attacker) { &balance.status.player } else { &balance.status.enemy };
+        for kind in StatusKind::ALL {
+            let effect = effects.get(kind);
+            if effect.chance <= 0.0 || effect.duration_secs <= 0.0 || !rng.gen_bool(effect.chance.min(1.0)) {
+                continue;
+            }
+            // The target may still be despawned before these commands run
+            let mut target = commands.entity(attack.target);
+            match kind {
+                StatusKind::Poison => target.try_insert(Poisoned(damage_over_time(attack.damage, effect))),
+                StatusKind::Burn => target.try_insert(Burning(damage_over_time(attack.damage, effect))),
+                StatusKind::Stun => target.try_insert(Stunned { remaining_secs: effect.duration_secs }),
+                StatusKind::Slow => target.try_insert(Slowed {
+                    factor: effect.potency.max(0.0) as f32,
+                    remaining_secs: effect.duration_secs,
+                }),
+            };
+            status_events.write(StatusEffectEvent { target: attack.target, kind, damage: None });
+        }
+    }
+}
+
+// Count status effects down in real time; poison and burn deal damage every DOT_TICK_SECS
+#[allow(clippy::type_complexity)]
+pub fn status_effect_tick_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    combat_state: Res<CombatState>,
+    mut query: Query<(
+        Entity,
+        &mut CurrentHp,
+        Has<Player>,
+        Option<&mut Poisoned>,
+        Option<&mut Burning>,
+        Option<&mut Stunned>,
+        Option<&mut Slowed>,
+    )>,
+    mut status_events: EventWriter<StatusEffectEvent>,
+    mut death_events: EventWriter<DeathEvent>,
+) {
+    if combat_state.is_game_over || !combat_state.in_dungeon {
+        return;
+    }
+
+    let delta = time.delta_secs();
+    for (entity, mut current_hp, is_player, poisoned, burning, stunned, slowed) in query.iter_mut() {
+        let was_alive = current_hp.0 > BigFloat::from(0.0);
+        let dots = [
+            poisoned.map(|effect| (StatusKind::Poison, effect.map_unchanged(|effect| &mut effect.0))),
+            burning.map(|effect| (StatusKind::Burn, effect.map_unchanged(|effect| &mut effect.0))),
+        ];
+        for (kind, mut dot) in dots.into_iter().flatten() {
+            let ticks = dot.advance(delta);
+            if ticks > 0 && was_alive {
+                let damage = dot.damage_per_tick * BigFloat::from(ticks as f64);
+                current_hp.0 = (current_hp.0 - damage).max(BigFloat::from(0.0));
+                status_events.write(StatusEffectEvent { target: entity, kind, damage: Some(damage) });
+                trace!(target: "combat", "{} deals {} damage, HP now {}", kind.label(), damage, current_hp.0);
+            }
+            if dot.expired() {
+                match kind {
+                    StatusKind::Poison => commands.entity(entity).try_remove::<Poisoned>(),
+                    _ => commands.entity(entity).try_remove::<Burning>(),
+                };
+            }
+        }
+        if let Some(mut stunned) = stunned {
+            stunned.remaining_secs -= delta;
+            if stunned.remaining_secs <= 0.0 {
+                commands.entity(entity).try_remove::<Stunned>();
+            }
+        }
+        if let Some(mut slowed) = slowed {
+            slowed.remaining_secs -= delta;
+            if slowed.remaining_secs <= 0.0 {
+                commands.entity(entity).try_remove::<Slowed>();
+            }
+        }
+
+        if was_alive && current_hp.0 <= BigFloat::from(0.0) {
+            death_events.write(DeathEvent {
+                entity,
+                entity_type: if is_player { DeathEntityType::Player } else { DeathEntityType::Enemy },
+            });
+        }
+    }
+}
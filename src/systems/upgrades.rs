@@ -1,8 +1,163 @@
 use bevy::prelude::*;
 use too_big_float::BigFloat;
 use crate::components::*;
+use crate::events::ManualUpgradeEvent;
+use crate::systems::combat_core::{hit_chance, AttackRoll};
+use crate::systems::initialization::{enemy_stats, EnemyStats};
+use crate::GameProgress;
 
-// Core upgrade system - handles automatic upgrades when resources are available
+// One upgradeable stat as seen by an UpgradeStrategy
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpgradeOption {
+    pub level: u32,
+    pub cost: BigFloat,
+    pub value: BigFloat,
+    pub next_value: BigFloat,
+}
+
+// Stats that already reached their cap are never bought
+fn below_cap(index: usize, options: &[UpgradeOption; StatKind::COUNT]) -> bool {
+    let option = &options[index];
+    StatKind::ALL[index].cap().is_none_or(|cap| option.value < BigFloat::from(cap))
+}
+
+impl UpgradeOption {
+    pub fn new(base_value: &BaseValue, level: &UpgradeLevel, cost: &UpgradeCost, multiplier: &UpgradeMultiplier) -> Self {
+        let value = recalculate_current_value(base_value, level, multiplier);
+        Self {
+            level: level.0,
+            cost: cost.0,
+            value,
+            next_value: value * BigFloat::from(multiplier.0),
+        }
+    }
+}
+
+// Average damage factor of the player's attacks with the given crit chance and multiplier
+fn crit_factor(crit_chance: BigFloat, crit_multiplier: BigFloat, enemy: &EnemyStats, accuracy: BigFloat) -> BigFloat {
+    BigFloat::from(AttackRoll::new(crit_chance, crit_multiplier, accuracy, enemy.evasion).expected_factor())
+}
+
+// How much one level of each stat multiplies the player's win margin (time to die / time to kill)
+// against `enemy`, using the damage, cooldown and hit formulas of combat_core
+fn upgrade_gain(kind: StatKind, options: &[UpgradeOption; StatKind::COUNT], enemy: &EnemyStats, damage: &DamageBalance) -> f64 {
+    let option = &options[kind.index()];
+    let defense = options[StatKind::Defense.index()].value;
+    let crit_chance = options[StatKind::CritChance.index()].value;
+    let crit_multiplier = options[StatKind::CritMultiplier.index()].value;
+    let evasion = options[StatKind::Evasion.index()].value;
+    let accuracy = options[StatKind::Accuracy.index()].value;
+    let ratio = match kind {
+        // HP: hits survived
+        StatKind::Hp => option.next_value / option.value,
+        // Attack: damage per hit after the enemy's defense
+        StatKind::Attack => damage.damage(option.next_value, enemy.defense) / damage.damage(option.value, enemy.defense),
+        // Defense: damage taken per enemy hit
+        StatKind::Defense => damage.damage(enemy.attack, defense) / damage.damage(enemy.attack, option.next_value),
+        // Speed shortens the cooldown and drains it faster
+        StatKind::Speed => (option.next_value / option.value) * (option.next_value / option.value),
+        // Crits: average damage per attack
+        StatKind::CritChance => crit_factor(option.next_value, crit_multiplier, enemy, accuracy) / crit_factor(option.value, crit_multiplier, enemy, accuracy),
+        StatKind::CritMultiplier => crit_factor(crit_chance, option.next_value, enemy, accuracy) / crit_factor(crit_chance, option.value, enemy, accuracy),
+        // Evasion: share of enemy attacks that land
+        StatKind::Evasion => BigFloat::from(hit_chance(enemy.accuracy, evasion) / hit_chance(enemy.accuracy, option.next_value)),
+        // Accuracy: share of player attacks that land
+        StatKind::Accuracy => BigFloat::from(hit_chance(option.next_value, enemy.evasion) / hit_chance(option.value, enemy.evasion)),
+    };
+    ratio.to_f64().map(f64::ln).unwrap_or(0.0)
+}
+
+// Index of the stat to buy next, or None to stop buying for now.
+// Strategies other than CheapestFirst wait for their pick instead of buying something else.
+pub fn choose_upgrade(
+    strategy: &UpgradeStrategy,
+    options: &[UpgradeOption; StatKind::COUNT],
+    experience: BigFloat,
+    enemy: &EnemyStats,
+    damage: &DamageBalance,
+) -> Option<usize> {
+    let choice = match strategy.kind {
+        UpgradeStrategyKind::CheapestFirst => (0..options.len())
+            .filter(|&index| below_cap(index, options) && experience >= options[index].cost)
+            .min_by(|&a, &b| options[a].cost.partial_cmp(&options[b].cost).unwrap_or(std::cmp::Ordering::Equal)),
+        UpgradeStrategyKind::RoundRobin => (0..options.len())
+            .map(|step| (strategy.round_robin_next + step) % options.len())
+            .find(|&index| below_cap(index, options)),
+        UpgradeStrategyKind::Weighted => (0..options.len())
+            .filter(|&index| strategy.weights[index] > 0.0 && below_cap(index, options))
+            .min_by(|&a, &b| {
+                let behind = |index: usize| options[index].level as f64 / strategy.weights[index];
+                behind(a).total_cmp(&behind(b))
+            }),
+        UpgradeStrategyKind::MaxEfficiency => {
+            (0..options.len()).filter(|&index| below_cap(index, options)).max_by(|&a, &b| {
+                // Kept in BigFloat: late-game costs overflow f64
+                let efficiency = |index: usize| {
+                    BigFloat::from(upgrade_gain(StatKind::ALL[index], options, enemy, damage)) / options[index].cost
+                };
+                efficiency(a).partial_cmp(&efficiency(b)).unwrap_or(std::cmp::Ordering::Equal).then(b.cmp(&a))
+            })
+        }
+    }?;
+    (experience >= options[choice].cost).then_some(choice)
+}
+
+// Upgrade entities in StatKind order, whatever the ECS iteration order
+fn stat_entities(
+    stat_order: &Query<(Entity, StatMarkers), With<UpgradeableStat>>,
+) -> Option<[Entity; StatKind::COUNT]> {
+    let mut entities = [None; StatKind::COUNT];
+    for (entity, markers) in stat_order.iter() {
+        entities[markers.kind().index()] = Some(entity);
+    }
+    entities.iter().all(Option::is_some).then(|| entities.map(Option::unwrap))
+}
+
+// Levels affordable with `experience` (at most `limit`) and what they cost in total
+pub fn affordable_levels(experience: BigFloat, cost: &UpgradeCost, cost_multiplier: &CostMultiplier, limit: u32) -> (u32, BigFloat) {
+    levels_within(experience, cost.0, cost_multiplier.0, limit)
+}
+
+// Buys up to `limit` levels in one go; returns how many were bought
+pub fn purchase_levels(
+    experience: &mut BigFloat,
+    level: &mut UpgradeLevel,
+    upgrade_cost: &mut UpgradeCost,
+    cost_multiplier: &CostMultiplier,
+    limit: u32,
+) -> u32 {
+    let (levels, total) = affordable_levels(*experience, upgrade_cost, cost_multiplier, limit);
+    if levels > 0 {
+        *experience = *experience - total;
+        level.0 += levels;
+        upgrade_cost.0 = upgrade_cost.0 * big_pow(BigFloat::from(cost_multiplier.0), levels);
+    }
+    levels
+}
+
+// Manual purchases from the Combat tab; buys as many levels as affordable up to the requested amount
+pub fn manual_upgrade_system(
+    mut upgrade_events: EventReader<ManualUpgradeEvent>,
+    mut player_experience_query: Query<&mut Experience, With<Player>>,
+    mut upgradeable_stats: Query<(&mut CurrentValue, &BaseValue, &mut UpgradeLevel, &mut UpgradeCost, &UpgradeMultiplier, &CostMultiplier)>,
+    stat_order: Query<(Entity, StatMarkers), With<UpgradeableStat>>,
+) {
+    let Ok(mut player_exp) = player_experience_query.single_mut() else { return };
+    let Some(entities) = stat_entities(&stat_order) else { return };
+
+    for upgrade in upgrade_events.read() {
+        let Ok((mut current_value, base_value, mut level, mut upgrade_cost, upgrade_multiplier, cost_multiplier)) =
+            upgradeable_stats.get_mut(entities[upgrade.stat.index()]) else { continue };
+        let limit = upgrade.amount.limit().min(upgrade.stat.levels_to_cap(current_value.0, upgrade_multiplier.0));
+        let bought = purchase_levels(&mut player_exp.0, &mut level, &mut upgrade_cost, cost_multiplier, limit);
+        if bought > 0 {
+            current_value.0 = recalculate_current_value(base_value, &level, upgrade_multiplier);
+            info!(target: "upgrades", "Bought {} {} level(s), now level {}", bought, upgrade.stat.label(), level.0);
+        }
+    }
+}
+
+// Core upgrade system - spends EXP in the order chosen by the UpgradeStrategy
 pub fn upgradeable_stat_upgrade_system(
     mut player_experience_query: Query<&mut Experience, With<Player>>,
     mut upgradeable_stats: Query<(
@@ -14,36 +169,56 @@ pub fn upgradeable_stat_upgrade_system(
         &UpgradeMultiplier,
         &CostMultiplier,
     )>,
+    stat_order: Query<(Entity, StatMarkers), With<UpgradeableStat>>,
+    mut strategy: ResMut<UpgradeStrategy>,
+    game_progress: Res<GameProgress>,
+    balance: Res<BalanceConfig>,
 ) {
+    if !strategy.enabled {
+        return;
+    }
     let Ok(mut player_exp) = player_experience_query.single_mut() else { return };
-    
-    let mut upgraded = true;
-    while upgraded {
-        upgraded = false;
-        
-        for (stat, mut current_value, base_value, mut level, mut upgrade_cost, upgrade_multiplier, cost_multiplier) in upgradeable_stats.iter_mut() {
-            if can_upgrade(&player_exp.0, &upgrade_cost) {
-                let cost = upgrade_cost.0;
-                player_exp.0 = player_exp.0 - cost;
-                
-                // Level up
-                level.0 += 1;
-                
-                // Recalculate current value
-                current_value.0 = recalculate_current_value(base_value, &level, upgrade_multiplier);
-                
-                // Update cost
-                upgrade_cost.0 = upgrade_cost.0 * BigFloat::from(cost_multiplier.0);
-                
-                upgraded = true;
-                println!("DEBUG UPGRADE: {} upgraded! New level: {}, New value: {}, Cost was: {}", 
-                    stat.name, level.0, current_value.0, cost);
-            }
+    let Some(entities) = stat_entities(&stat_order) else { return };
+    let enemy = enemy_stats(&balance, game_progress.current_enemy_number);
+
+    loop {
+        let Ok(stats) = upgradeable_stats.get_many(entities) else { return };
+        let options = stats.map(|(_, _, base_value, level, cost, multiplier, _)| {
+            UpgradeOption::new(base_value, level, cost, multiplier)
+        });
+        let Some(index) = choose_upgrade(&strategy, &options, player_exp.0, &enemy, &balance.damage) else { break };
+
+        let Ok((stat, mut current_value, base_value, mut level, mut upgrade_cost, upgrade_multiplier, cost_multiplier)) =
+            upgradeable_stats.get_mut(entities[index]) else { break };
+        let cost = upgrade_cost.0;
+        if !purchase_upgrade(&mut player_exp.0, &mut level, &mut upgrade_cost, cost_multiplier) {
+            break;
         }
+        current_value.0 = recalculate_current_value(base_value, &level, upgrade_multiplier);
+        strategy.bought(index);
+        debug!(target: "upgrades", "{} upgraded! New level: {}, New value: {}, Cost was: {}",
+            stat.name, level.0, current_value.0, cost);
     }
 }
 
+// Buys one level if affordable - shared with offline progress simulation
+pub fn purchase_upgrade(
+    experience: &mut BigFloat,
+    level: &mut UpgradeLevel,
+    upgrade_cost: &mut UpgradeCost,
+    cost_multiplier: &CostMultiplier,
+) -> bool {
+    if !can_upgrade(experience, upgrade_cost) {
+        return false;
+    }
+    *experience = *experience - upgrade_cost.0;
+    level.0 += 1;
+    upgrade_cost.0 = upgrade_cost.0 * BigFloat::from(cost_multiplier.0);
+    true
+}
+
 // Reactive system - recalculates current value when base components change
+#[allow(clippy::type_complexity)]
 pub fn update_current_value_on_change(
     mut query: Query<(
         &mut CurrentValue,
@@ -106,4 +281,48 @@ pub fn speed_sync_system(
             combat_speed.0 = current_value.0;
         }
     }
+}
+
+pub fn crit_chance_sync_system(
+    mut player_query: Query<&mut CombatCritChance, With<Player>>,
+    crit_chance_stats: Query<&CurrentValue, (With<UpgradeableCritChance>, Changed<CurrentValue>)>,
+) {
+    if let Ok(mut combat_crit_chance) = player_query.single_mut() {
+        for current_value in crit_chance_stats.iter() {
+            combat_crit_chance.0 = current_value.0;
+        }
+    }
+}
+
+pub fn crit_multiplier_sync_system(
+    mut player_query: Query<&mut CombatCritMultiplier, With<Player>>,
+    crit_multiplier_stats: Query<&CurrentValue, (With<UpgradeableCritMultiplier>, Changed<CurrentValue>)>,
+) {
+    if let Ok(mut combat_crit_multiplier) = player_query.single_mut() {
+        for current_value in crit_multiplier_stats.iter() {
+            combat_crit_multiplier.0 = current_value.0;
+        }
+    }
+}
+
+pub fn evasion_sync_system(
+    mut player_query: Query<&mut CombatEvasion, With<Player>>,
+    evasion_stats: Query<&CurrentValue, (With<UpgradeableEvasion>, Changed<CurrentValue>)>,
+) {
+    if let Ok(mut combat_evasion) = player_query.single_mut() {
+        for current_value in evasion_stats.iter() {
+            combat_evasion.0 = current_value.0;
+        }
+    }
+}
+
+pub fn accuracy_sync_system(
+    mut player_query: Query<&mut CombatAccuracy, With<Player>>,
+    accuracy_stats: Query<&CurrentValue, (With<UpgradeableAccuracy>, Changed<CurrentValue>)>,
+) {
+    if let Ok(mut combat_accuracy) = player_query.single_mut() {
+        for current_value in accuracy_stats.iter() {
+            combat_accuracy.0 = current_value.0;
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+    use crate::components::*;
+    use crate::tests::fixtures::{game_app, manual_automation, startup_config};
+    use crate::{AutomationConfig, CombatStartEvent, GameProgress, SIMULATION_STEP};
+
+    // Headless game driven by fixed time steps, already in the dungeon
+    fn simulated_app() -> App {
+        let automation = AutomationConfig { auto_retry_unlocked: true, auto_retry_enabled: true, ..manual_automation() };
+        let mut app = game_app(startup_config(), automation);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(SIMULATION_STEP));
+        app.update();
+        app.world_mut().send_event(CombatStartEvent { is_retry: false });
+        app
+    }
+
+    // Real-time play: the wall clock hands out uneven frames that only average 60 Hz
+    fn run_like_real_time(app: &mut App, seconds: u32) {
+        let frames = [Duration::from_millis(9), Duration::from_millis(31), Duration::from_millis(10)];
+        let mut remaining = SIMULATION_STEP * 60 * seconds;
+        for frame in frames.iter().cycle() {
+            if remaining.is_zero() {
+                break;
+            }
+            let frame = (*frame).min(remaining);
+            remaining -= frame;
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(frame));
+            app.update();
+        }
+    }
+
+    fn run_for(app: &mut App, seconds: u32) {
+        for _ in 0..seconds * 60 {
+            app.update();
+        }
+    }
+
+    fn player_experience(app: &mut App) -> f64 {
+        let mut query = app.world_mut().query_filtered::<&Experience, (With<Player>, With<Level>)>();
+        query.single(app.world()).unwrap().0.to_f64().unwrap()
+    }
+
+    #[test]
+    fn test_manual_steps_advance_simulated_time() {
+        let mut app = simulated_app();
+        run_for(&mut app, 10);
+
+        let elapsed = app.world().resource::<Time>().elapsed_secs_f64();
+        // The very first update only starts the clock
+        let expected = SIMULATION_STEP.as_secs_f64() * (10 * 60) as f64;
+        assert!((elapsed - expected).abs() < 1e-6, "elapsed {} != {}", elapsed, expected);
+    }
+
+    #[test]
+    fn test_simulated_combat_makes_progress() {
+        let mut app = simulated_app();
+        run_for(&mut app, 30);
+
+        // Default balance: the first enemy falls in about four seconds
+        assert!(app.world().resource::<GameProgress>().current_enemy_number > 1);
+    }
+
+    #[test]
+    fn test_simulation_is_deterministic() {
+        let mut first = simulated_app();
+        let mut second = simulated_app();
+        run_for(&mut first, 20);
+        run_for(&mut second, 20);
+
+        assert_eq!(
+            first.world().resource::<GameProgress>().current_enemy_number,
+            second.world().resource::<GameProgress>().current_enemy_number
+        );
+        assert_eq!(player_experience(&mut first), player_experience(&mut second));
+    }
+
+    #[test]
+    fn test_simulation_matches_real_time_play() {
+        let mut simulated = simulated_app();
+        let mut real_time = simulated_app();
+        run_for(&mut simulated, 20);
+        run_like_real_time(&mut real_time, 20);
+
+        let elapsed = |app: &App| app.world().resource::<Time>().elapsed_secs_f64();
+        assert!((elapsed(&simulated) - elapsed(&real_time)).abs() < 1e-6);
+        assert_eq!(
+            simulated.world().resource::<GameProgress>().current_enemy_number,
+            real_time.world().resource::<GameProgress>().current_enemy_number
+        );
+        assert_eq!(player_experience(&mut simulated), player_experience(&mut real_time));
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::events::*;
+    use crate::systems::achievement_tracking::achievement_system;
+    use crate::systems::offline_progress::simulate_offline_progress;
+    use crate::tests::fixtures::save_with_levels;
+
+    fn index_of(id: &str) -> usize {
+        ACHIEVEMENTS.iter().position(|achievement| achievement.id == id).unwrap()
+    }
+
+    fn tracking_app() -> App {
+        let mut app = App::new();
+        app.add_event::<EnemyDeathEvent>()
+            .add_event::<PlayerDeathEvent>()
+            .add_event::<ExpGainEvent>()
+            .init_resource::<Achievements>()
+            .init_resource::<RebirthCurrency>()
+            .add_systems(Update, achievement_system);
+        app
+    }
+
+    #[test]
+    fn test_achievement_ids_are_unique() {
+        for (index, achievement) in ACHIEVEMENTS.iter().enumerate() {
+            assert_eq!(index_of(achievement.id), index);
+        }
+    }
+
+    #[test]
+    fn test_check_unlocks_met_goals_once() {
+        let mut achievements = Achievements {
+            highest_enemy_defeated: 12,
+            ..default()
+        };
+
+        let unlocked = achievements.check(1);
+
+        assert_eq!(unlocked, vec![index_of("defeat_1"), index_of("defeat_10"), index_of("rebirths_1")]);
+        assert!(achievements.check(1).is_empty());
+        // 2% + 5% EXP, 5% damage
+        assert!((achievements.exp_multiplier().to_f64().unwrap() - 1.07).abs() < 1e-9);
+        assert!((achievements.damage_multiplier().to_f64().unwrap() - 1.05).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_events_drive_progress() {
+        let mut app = tracking_app();
+        app.world_mut().send_event(EnemyDeathEvent {
+            enemy_entity: Entity::PLACEHOLDER,
+            enemy_number: 10,
+            exp_reward: BigFloat::from(600.0),
+        });
+        app.world_mut().send_event(ExpGainEvent { amount: BigFloat::from(600.0) });
+        app.world_mut().send_event(ExpGainEvent { amount: BigFloat::from(600.0) });
+        app.world_mut().send_event(PlayerDeathEvent { player_entity: Entity::PLACEHOLDER });
+        app.world_mut().spawn((UpgradeableStat::new("HP"), UpgradeLevel(10)));
+        app.update();
+
+        let achievements = app.world().resource::<Achievements>();
+        assert_eq!(achievements.highest_enemy_defeated, 10);
+        assert_eq!(achievements.deaths, 1);
+        assert_eq!(achievements.highest_upgrade_level, 10);
+        for id in ["defeat_1", "defeat_10", "deaths_1", "upgrade_10", "exp_1k"] {
+            assert!(achievements.unlocked[index_of(id)], "{id} should be unlocked");
+        }
+        assert!(!achievements.unlocked[index_of("rebirths_1")]);
+    }
+
+    #[test]
+    fn test_quiet_frames_leave_resource_unchanged() {
+        let mut app = tracking_app();
+        app.update();
+        app.update();
+
+        assert!(!app.world().resource_ref::<Achievements>().is_changed());
+    }
+
+    #[test]
+    fn test_offline_progress_unlocks_achievements() {
+        let mut save = save_with_levels(5, true, false);
+
+        let report = simulate_offline_progress(&mut save, &BalanceConfig::default(), 10);
+
+        assert!(report.enemies_killed > 0);
+        assert_eq!(save.achievements.highest_enemy_defeated, report.highest_enemy - 1);
+        assert!(save.achievements.unlocked.contains(&"defeat_1".to_string()));
+        assert!(save.achievements.highest_upgrade_level >= 5);
+    }
+}
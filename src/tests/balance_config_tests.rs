@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::systems::initialization::{enemy_stats, rebirth_stats};
+
+    #[test]
+    fn test_example_file_matches_defaults() {
+        let config = BalanceConfig::from_ron(include_str!("../../balance.ron")).unwrap();
+        assert_eq!(config, BalanceConfig::default());
+    }
+
+    #[test]
+    fn test_partial_config_falls_back_to_defaults() {
+        let config = BalanceConfig::from_ron("(enemy: (hp: (base: 50.0, growth: 2.0)))").unwrap();
+
+        assert_eq!(config.enemy.hp, GrowthCurve::new(50.0, 2.0));
+        assert_eq!(config.enemy.attack, EnemyBalance::default().attack);
+        assert_eq!(config.player, PlayerBalance::default());
+        assert_eq!(config.rebirth, RebirthBalance::default());
+    }
+
+    #[test]
+    fn test_invalid_config_is_rejected() {
+        assert!(BalanceConfig::from_ron("(enemy: 5)").is_err());
+        for scale in ["0.0", "-10.0"] {
+            let config = format!("(damage: (formula: Mitigation, mitigation_scale: {}))", scale);
+            assert!(matches!(
+                BalanceConfig::from_ron(&config),
+                Err(BalanceConfigError::NotPositive("damage.mitigation_scale"))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_values_that_break_the_formulas_are_rejected() {
+        assert!(matches!(
+            BalanceConfig::from_ron("(leveling: (exp: (base: 0.0, growth: 1.2)))"),
+            Err(BalanceConfigError::NotPositive("leveling.exp.base"))
+        ));
+        assert!(matches!(
+            BalanceConfig::from_ron("(enemy: (hp: (base: 20.0, growth: -1.5)))"),
+            Err(BalanceConfigError::NotPositive("enemy.hp.growth"))
+        ));
+        assert!(matches!(
+            BalanceConfig::from_ron("(player: (upgrade_multiplier: 0.0))"),
+            Err(BalanceConfigError::NotPositive("player.upgrade_multiplier"))
+        ));
+        for multiplier in ["1.0", "0.5"] {
+            let config = format!("(player: (cost_multiplier: {}))", multiplier);
+            assert!(matches!(BalanceConfig::from_ron(&config), Err(BalanceConfigError::CostMultiplierTooLow(_))));
+        }
+        assert!(matches!(
+            BalanceConfig::from_ron("(player: (base_crit_chance: 1.5))"),
+            Err(BalanceConfigError::ChanceOutOfRange("player.base_crit_chance"))
+        ));
+        assert!(matches!(
+            BalanceConfig::from_ron("(status: (enemy: (stun: (chance: -0.1, duration_secs: 1.0, potency: 0.0))))"),
+            Err(BalanceConfigError::ChanceOutOfRange("status.enemy.stun.chance"))
+        ));
+    }
+
+    #[test]
+    fn test_dot_credit_stops_at_the_next_attack() {
+        let effects = OnHitEffects {
+            poison: StatusEffectBalance::new(0.5, 4.0, 0.1),
+            ..OnHitEffects::default()
+        };
+
+        assert!((effects.expected_dot_factor(10.0) - 0.2).abs() < 1e-9);
+        assert!((effects.expected_dot_factor(1.0) - 0.05).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_default_enemy_stats_match_original_formula() {
+        let stats = enemy_stats(&BalanceConfig::default(), 3);
+
+        assert_eq!(stats.hp, calculate_exponential_growth(BigFloat::from(20.0), 1.5, 3));
+        assert_eq!(stats.exp_reward, calculate_exponential_growth(BigFloat::from(5.0), 1.15, 3));
+    }
+
+    #[test]
+    fn test_rebirth_stats_use_configured_coefficients() {
+        let mut config = BalanceConfig::default();
+        config.rebirth.stat_bonus_per_point = 0.5;
+        config.rebirth.cost_reduction_per_point = 1.0;
+
+        let stats = rebirth_stats(&config, BigFloat::from(2.0));
+
+        assert_eq!(stats.bonus, BigFloat::from(2.0));
+        assert_eq!(stats.hp, BigFloat::from(200.0));
+        assert_eq!(stats.upgrade_cost, BigFloat::from(10.0) / BigFloat::from(3.0));
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use too_big_float::BigFloat;
+    use crate::number_format::NumberNotation;
+    use crate::plugins::balance_check::*;
+
+    fn sample_record() -> BalanceRecord {
+        BalanceRecord {
+            report: 3,
+            elapsed_secs: 3.0,
+            enemy_number: Some(7),
+            level: Some(1),
+            lifetime_experience: Some(BigFloat::from(60.0)),
+            experience: Some(BigFloat::from(42.5)),
+            rebirth_points: Some(BigFloat::from(0.0)),
+            hp: Some(BigFloat::from(80.0)),
+            max_hp: Some(BigFloat::from(100.0)),
+            attack: Some(BigFloat::from(10.0)),
+            defense: Some(BigFloat::from(5.0)),
+            speed: Some(BigFloat::from(1.0)),
+            crit_chance: Some(BigFloat::from(0.25)),
+            crit_multiplier: Some(BigFloat::from(1.5)),
+            evasion: Some(BigFloat::from(0.05)),
+            accuracy: Some(BigFloat::from(1.0)),
+            hp_level: Some(2),
+            attack_level: Some(1),
+            defense_level: Some(0),
+            speed_level: Some(0),
+            crit_chance_level: Some(4),
+            crit_multiplier_level: Some(0),
+            evasion_level: Some(0),
+            accuracy_level: Some(1),
+            enemy_count: 1,
+            enemy_type: Some("Tank"),
+            damage_formula: "ratio",
+            auto_cast: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_csv_row_matches_header_columns() {
+        let header_columns = BalanceRecord::CSV_HEADER.split(',').count();
+        let row = sample_record().to_csv_row();
+
+        assert_eq!(row.split(',').count(), header_columns);
+        assert!(row.starts_with("3,3,7,1,60,42.5,"));
+        assert!(row.contains(",1,0.25,1.5,0.05,1,2,1,0,0,4,0,0,1,"));
+        assert!(row.ends_with(",ratio,true"));
+    }
+
+    #[test]
+    fn test_missing_values_are_empty_csv_fields() {
+        let row = BalanceRecord::default().to_csv_row();
+
+        assert!(row.starts_with("0,0,,,"));
+    }
+
+    #[test]
+    fn test_json_line_contains_fields() {
+        let line = sample_record().to_json_line();
+
+        assert!(!line.contains('\n'));
+        assert!(line.contains("\"enemy_number\":7"));
+        assert!(line.contains("\"experience\":42.5"));
+        assert!(line.contains("\"enemy_hp\":null"));
+        assert!(line.contains("\"enemy_type\":\"Tank\""));
+        assert!(line.contains("\"damage_formula\":\"ratio\""));
+        assert!(line.contains("\"auto_cast\":true"));
+        assert!(line.contains("\"crit_chance\":0.25"));
+        assert!(line.contains("\"crit_chance_level\":4"));
+        assert!(line.contains("\"accuracy_level\":1"));
+    }
+
+    #[test]
+    fn test_text_report_formats_stats_from_bigfloat() {
+        let record = BalanceRecord {
+            enemy_number: Some(12),
+            enemy_hp: Some(BigFloat::from(2.5e250)),
+            enemy_max_hp: Some(BigFloat::from(4e250)),
+            ..sample_record()
+        };
+        let mut out = Vec::new();
+
+        write_text_report(&mut out, &record, NumberNotation::Scientific).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("HP 2.50e250/4.00e250"));
+        assert!(text.contains("HP: 80.00/100.00"));
+        assert!(text.contains("Damage Formula: ratio"));
+        assert!(text.contains("Auto Cast: on"));
+        assert!(text.contains("Crit Multiplier: 1.50"));
+        assert!(text.contains("Crit Chance Level: 4"));
+    }
+
+    #[test]
+    fn test_values_beyond_f64_keep_their_digits() {
+        // 1.5e400 and -2.25e-400 are far outside f64 range
+        let huge = BigFloat::from(1.5e200) * BigFloat::from(1e200);
+        let tiny_debt = BigFloat::from(0.0) - BigFloat::from(2.25e-200) * BigFloat::from(1e-200);
+        let record = BalanceRecord { experience: Some(huge), rebirth_points: Some(tiny_debt), ..sample_record() };
+
+        let line = record.to_json_line();
+        assert!(line.contains("\"experience\":\"1.5e400\""));
+        assert!(line.contains("\"rebirth_points\":\"-2.25e-400\""));
+        assert!(line.contains("\"hp\":80.0"));
+
+        let row = record.to_csv_row();
+        assert!(row.starts_with("3,3,7,1,60,1.5e400,-2.25e-400,80,"));
+    }
+}
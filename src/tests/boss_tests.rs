@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy::time::{TimePlugin, TimeUpdateStrategy};
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::systems::initialization::enemy_stats;
+    use crate::tests::fixtures::{certain_hit, game_app, manual_automation, startup_config};
+    use crate::{
+        AutomationConfig, CombatStartEvent, CombatState, GameProgress, StartupConfig, SIMULATION_STEP,
+        boss_enrage_system,
+    };
+
+    // Every second enemy is a boss nobody can survive; archetypes stay out of the way
+    fn deadly_boss_balance() -> BalanceConfig {
+        let mut balance = BalanceConfig::default();
+        balance.enemy.archetypes.standard_until = u32::MAX;
+        balance.boss.interval = 2;
+        balance.boss.hp = 1e9;
+        balance.boss.attack = 1000.0;
+        balance
+    }
+
+    #[test]
+    fn test_boss_milestones_and_checkpoints() {
+        let boss = BossBalance::default();
+
+        assert!(!boss.is_boss(9));
+        assert!(boss.is_boss(10));
+        assert!(boss.is_boss(20));
+        assert_eq!(boss.checkpoint(10), 1);
+        assert_eq!(boss.checkpoint(20), 11);
+        assert_eq!(boss.checkpoint(15), 11);
+
+        let disabled = BossBalance { interval: 0, ..Default::default() };
+        assert!(!disabled.is_boss(10));
+        assert_eq!(disabled.checkpoint(10), 10);
+    }
+
+    #[test]
+    fn test_boss_stats_are_boosted() {
+        let mut balance = BalanceConfig::default();
+        balance.enemy.archetypes.standard_until = u32::MAX;
+        let mut no_bosses = balance.clone();
+        no_bosses.boss.interval = 0;
+
+        let boss = enemy_stats(&balance, 10);
+        let regular = enemy_stats(&no_bosses, 10);
+
+        assert!(boss.is_boss);
+        assert!(!regular.is_boss);
+        assert_eq!(boss.hp, regular.hp * BigFloat::from(balance.boss.hp));
+        assert_eq!(boss.exp_reward, regular.exp_reward * BigFloat::from(balance.boss.exp_reward));
+    }
+
+    #[test]
+    fn test_enrage_multiplies_attack_once() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), TimePlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(SIMULATION_STEP))
+            .insert_resource(CombatState { is_game_over: false, in_dungeon: true })
+            .add_systems(Update, boss_enrage_system);
+        let boss = app.world_mut().spawn((
+            Enemy,
+            Boss,
+            CombatAttack(BigFloat::from(10.0)),
+            EnrageTimer {
+                timer: Timer::from_seconds(0.5, TimerMode::Once),
+                attack_multiplier: 2.0,
+                enraged: false,
+            },
+        )).id();
+
+        for _ in 0..120 {
+            app.update();
+        }
+
+        assert!(app.world().get::<EnrageTimer>(boss).unwrap().enraged);
+        assert_eq!(app.world().get::<CombatAttack>(boss).unwrap().0, BigFloat::from(20.0));
+    }
+
+    #[test]
+    fn test_boss_failure_retreats_without_rebirth() {
+        let config = StartupConfig { attack_level: 10, defense_level: 10, ..startup_config() };
+        let automation = AutomationConfig { auto_retry_unlocked: true, auto_retry_enabled: true, ..manual_automation() };
+        let mut app = game_app(config, automation);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(SIMULATION_STEP))
+            .insert_resource(deadly_boss_balance());
+        app.update();
+        app.world_mut().send_event(CombatStartEvent { is_retry: false });
+
+        let mut fought_boss = false;
+        for _ in 0..60 * 30 {
+            app.update();
+            let mut bosses = app.world_mut().query_filtered::<(), With<Boss>>();
+            fought_boss |= bosses.iter(app.world()).next().is_some();
+        }
+
+        assert!(fought_boss);
+        let progress = app.world().resource::<GameProgress>();
+        assert!(!progress.has_died_once);
+        assert!(progress.current_enemy_number <= 2);
+
+        let mut players = app.world_mut().query_filtered::<&RebirthPoints, With<Level>>();
+        let points: Vec<&RebirthPoints> = players.iter(app.world()).collect();
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].0, BigFloat::from(0.0));
+        assert!(app.world().resource::<CombatState>().in_dungeon);
+    }
+
+    // Start a fight, then leave both sides one hit from death with attacks ready on the same tick
+    fn trade_fatal_blows(balance: BalanceConfig) -> App {
+        let mut app = game_app(startup_config(), manual_automation());
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(SIMULATION_STEP))
+            .insert_resource(balance);
+        app.update();
+        app.world_mut().send_event(CombatStartEvent { is_retry: false });
+        app.update();
+
+        let mut fighters = app.world_mut().query_filtered::<Entity, (With<CurrentHp>, Or<(With<Player>, With<Enemy>)>)>();
+        let fighters: Vec<Entity> = fighters.iter(app.world()).collect();
+        assert_eq!(fighters.len(), 2);
+        for entity in fighters {
+            app.world_mut().entity_mut(entity).insert((
+                certain_hit(),
+                CurrentHp(BigFloat::from(1.0)),
+                CombatAttack(BigFloat::from(1e6)),
+            ));
+        }
+        app.update();
+        app
+    }
+
+    fn enemy_numbers(app: &mut App) -> Vec<u32> {
+        let mut enemies = app.world_mut().query_filtered::<&EnemyNumber, With<Enemy>>();
+        enemies.iter(app.world()).map(|number| number.0).collect()
+    }
+
+    fn player_experience(app: &mut App) -> BigFloat {
+        let mut players = app.world_mut().query_filtered::<&Experience, With<Player>>();
+        players.single(app.world()).unwrap().0
+    }
+
+    #[test]
+    fn test_simultaneous_boss_kill_still_retreats_to_one_enemy() {
+        let mut balance = deadly_boss_balance();
+        balance.boss.interval = 1;
+        let mut app = trade_fatal_blows(balance);
+
+        assert_eq!(enemy_numbers(&mut app), vec![1]);
+        assert_eq!(app.world().resource::<GameProgress>().current_enemy_number, 1);
+        assert_eq!(player_experience(&mut app), BigFloat::from(0.0));
+
+        // Combat carries on against the single checkpoint enemy
+        for _ in 0..60 {
+            app.update();
+        }
+        assert_eq!(enemy_numbers(&mut app).len(), 1);
+    }
+
+    #[test]
+    fn test_simultaneous_kill_rebirths_without_spawning_the_next_enemy() {
+        let mut balance = BalanceConfig::default();
+        balance.enemy.archetypes.standard_until = u32::MAX;
+        let mut app = trade_fatal_blows(balance);
+
+        let progress = app.world().resource::<GameProgress>();
+        assert!(progress.has_died_once);
+        assert_eq!(progress.current_enemy_number, 1);
+        assert_eq!(enemy_numbers(&mut app), vec![1]);
+        assert_eq!(player_experience(&mut app), BigFloat::from(0.0));
+    }
+}
@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::events::*;
+    use crate::systems::combat_log_tracking::combat_log_system;
+
+    fn tracking_app() -> App {
+        let mut app = App::new();
+        app.add_event::<AttackEvent>()
+            .add_event::<StatusEffectEvent>()
+            .add_event::<SkillUsedEvent>()
+            .add_event::<EnemyDeathEvent>()
+            .add_event::<PlayerDeathEvent>()
+            .add_event::<ExpGainEvent>()
+            .init_resource::<CombatLog>()
+            .init_resource::<RebirthCurrency>()
+            .init_resource::<RebirthShop>()
+            .init_resource::<Achievements>()
+            .init_resource::<BalanceConfig>()
+            .init_resource::<DisplaySettings>()
+            .add_systems(Update, combat_log_system);
+        app
+    }
+
+    fn messages(log: &CombatLog) -> Vec<String> {
+        log.visible().map(|entry| entry.message.clone()).collect()
+    }
+
+    #[test]
+    fn test_full_log_drops_oldest_entries() {
+        let mut log = CombatLog::with_capacity(3);
+        for index in 0..5 {
+            log.push(CombatLogKind::Attack, format!("hit {}", index));
+        }
+
+        assert_eq!(log.len(), 3);
+        assert_eq!(messages(&log), vec!["hit 4", "hit 3", "hit 2"]);
+    }
+
+    #[test]
+    fn test_filter_hides_kinds() {
+        let mut log = CombatLog::default();
+        log.push(CombatLogKind::Attack, "hit");
+        log.push(CombatLogKind::Kill, "kill");
+        log.push(CombatLogKind::Exp, "exp");
+
+        log.toggle(CombatLogKind::Attack);
+        assert!(!log.is_shown(CombatLogKind::Attack));
+        assert_eq!(messages(&log), vec!["exp", "kill"]);
+
+        // Hidden entries are kept, only filtered out
+        log.toggle(CombatLogKind::Attack);
+        assert_eq!(messages(&log), vec!["exp", "kill", "hit"]);
+    }
+
+    #[test]
+    fn test_system_logs_combat_events() {
+        let mut app = tracking_app();
+        let player = app.world_mut().spawn(Player).id();
+        let enemy = app.world_mut().spawn((Enemy, EnemyNumber(3))).id();
+        app.world_mut().send_event(AttackEvent { attacker: player, target: enemy, damage: BigFloat::from(12.0), critical: false, missed: false });
+        app.world_mut().send_event(AttackEvent { attacker: enemy, target: player, damage: BigFloat::from(2.5), critical: false, missed: false });
+        app.world_mut().send_event(EnemyDeathEvent {
+            enemy_entity: enemy,
+            enemy_number: 3,
+            exp_reward: BigFloat::from(20.0),
+        });
+        app.world_mut().send_event(ExpGainEvent { amount: BigFloat::from(20.0) });
+        app.update();
+
+        assert_eq!(
+            messages(app.world().resource::<CombatLog>()),
+            vec!["+20.00 EXP", "Enemy #3 defeated", "Enemy #3 hits Player for 2.50", "Player hits Enemy #3 for 12.00"]
+        );
+    }
+
+    #[test]
+    fn test_system_marks_crits_and_misses() {
+        let mut app = tracking_app();
+        let player = app.world_mut().spawn(Player).id();
+        let enemy = app.world_mut().spawn((Enemy, EnemyNumber(2))).id();
+        app.world_mut().send_event(AttackEvent { attacker: player, target: enemy, damage: BigFloat::from(30.0), critical: true, missed: false });
+        app.world_mut().send_event(AttackEvent { attacker: enemy, target: player, damage: BigFloat::from(0.0), critical: false, missed: true });
+        app.update();
+
+        assert_eq!(
+            messages(app.world().resource::<CombatLog>()),
+            vec!["Enemy #2 attacks Player - MISS", "Player hits Enemy #2 for 30.00 CRIT!"]
+        );
+    }
+
+    #[test]
+    fn test_system_logs_status_effects() {
+        let mut app = tracking_app();
+        let player = app.world_mut().spawn(Player).id();
+        let enemy = app.world_mut().spawn((Enemy, EnemyNumber(4))).id();
+        app.world_mut().send_event(StatusEffectEvent { target: enemy, kind: StatusKind::Poison, damage: None });
+        app.world_mut().send_event(StatusEffectEvent { target: player, kind: StatusKind::Stun, damage: None });
+        app.world_mut().send_event(StatusEffectEvent { target: enemy, kind: StatusKind::Burn, damage: Some(BigFloat::from(6.0)) });
+        app.update();
+
+        let log = app.world().resource::<CombatLog>();
+        assert_eq!(messages(log), vec!["Burn deals 6.00 to Enemy #4", "Player is stunned", "Enemy #4 is poisoned"]);
+        assert!(log.visible().all(|entry| entry.kind == CombatLogKind::Status));
+    }
+
+    #[test]
+    fn test_system_logs_skills() {
+        let mut app = tracking_app();
+        app.world_mut().send_event(SkillUsedEvent { skill: SkillKind::PowerStrike, amount: BigFloat::from(0.0) });
+        app.world_mut().send_event(SkillUsedEvent { skill: SkillKind::Heal, amount: BigFloat::from(12.0) });
+        app.world_mut().send_event(SkillUsedEvent { skill: SkillKind::Shield, amount: BigFloat::from(25.0) });
+        app.update();
+
+        let log = app.world().resource::<CombatLog>();
+        assert_eq!(
+            messages(log),
+            vec!["Player uses Shield (absorbs 25.00)", "Player uses Heal (+12.00 HP)", "Player uses Power Strike"]
+        );
+        assert!(log.visible().all(|entry| entry.kind == CombatLogKind::Skill));
+    }
+
+    #[test]
+    fn test_system_logs_upgrades_and_rebirths_but_not_spawns() {
+        let mut app = tracking_app();
+        app.world_mut().resource_mut::<RebirthCurrency>().rebirth_count = 2;
+        let stat = app.world_mut().spawn((UpgradeableStat::new("Attack"), UpgradeLevel(0))).id();
+        app.update();
+        assert!(app.world().resource::<CombatLog>().is_empty());
+
+        app.world_mut().get_mut::<UpgradeLevel>(stat).unwrap().0 = 4;
+        app.world_mut().resource_mut::<RebirthCurrency>().rebirth_count = 3;
+        app.update();
+
+        assert_eq!(
+            messages(app.world().resource::<CombatLog>()),
+            vec!["Rebirth #3", "Attack upgraded to level 4"]
+        );
+    }
+}
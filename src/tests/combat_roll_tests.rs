@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::events::*;
+    use crate::systems::combat_core::{hit_chance, player_attack_system, AttackRoll};
+    use crate::tests::fixtures::{game_app, manual_automation, startup_config};
+    use crate::{CombatState, ManualUpgradeEvent, StartupConfig};
+
+    fn big(value: f64) -> BigFloat {
+        BigFloat::from(value)
+    }
+
+    #[derive(Resource, Default)]
+    struct Attacks(Vec<(BigFloat, bool, bool)>);
+
+    fn collect_attacks(mut events: EventReader<AttackEvent>, mut attacks: ResMut<Attacks>) {
+        attacks.0.extend(events.read().map(|attack| (attack.damage, attack.critical, attack.missed)));
+    }
+
+    // One player attack (attack 20) against an enemy with defense 5 and the given evasion
+    fn attack_once(crit_chance: f64, accuracy: f64, enemy_evasion: f64) -> (BigFloat, bool, bool) {
+        let mut app = App::new();
+        app.add_event::<AttackEvent>()
+            .insert_resource(CombatState { is_game_over: false, in_dungeon: true })
+            .init_resource::<RebirthShop>()
+            .init_resource::<Achievements>()
+            .init_resource::<BalanceConfig>()
+            .init_resource::<GameRng>()
+            .init_resource::<Attacks>()
+            .add_systems(Update, (player_attack_system, collect_attacks).chain());
+        app.world_mut().spawn((
+            Player,
+            CombatAttack(big(20.0)),
+            CombatSpeed(big(1.0)),
+            AttackCooldown(0.0),
+            CombatCritChance(big(crit_chance)),
+            CombatCritMultiplier(big(2.5)),
+            CombatAccuracy(big(accuracy)),
+        ));
+        app.world_mut().spawn((Enemy, CombatDefense(big(5.0)), CombatEvasion(big(enemy_evasion))));
+        app.update();
+
+        let attacks = &app.world().resource::<Attacks>().0;
+        assert_eq!(attacks.len(), 1);
+        attacks[0]
+    }
+
+    #[test]
+    fn test_hit_chance_from_accuracy_and_evasion() {
+        assert_eq!(hit_chance(big(1.0), big(0.0)), 1.0);
+        assert_eq!(hit_chance(big(1.0), big(1.0)), 0.5);
+        assert_eq!(hit_chance(big(0.0), big(0.0)), 1.0);
+    }
+
+    #[test]
+    fn test_expected_factor_folds_in_misses_and_crits() {
+        let roll = AttackRoll::new(big(0.2), big(2.0), big(1.0), big(1.0));
+        // Half the attacks land, a fifth of those deal double
+        assert!((roll.expected_factor() - 0.6).abs() < 1e-12);
+        // Crit chance is capped at 100%
+        assert_eq!(AttackRoll::new(big(3.0), big(2.0), big(1.0), big(0.0)).crit_chance, 1.0);
+    }
+
+    #[test]
+    fn test_certain_crit_multiplies_damage() {
+        assert_eq!(attack_once(1.0, 1.0, 0.0), (big(37.5), true, false));
+        assert_eq!(attack_once(0.0, 1.0, 0.0), (big(15.0), false, false));
+    }
+
+    #[test]
+    fn test_certain_miss_deals_no_damage() {
+        assert_eq!(attack_once(1.0, 0.0, 1.0), (big(0.0), false, true));
+    }
+
+    #[test]
+    fn test_same_seed_rolls_the_same() {
+        let roll = AttackRoll::new(big(0.3), big(2.0), big(1.0), big(1.0));
+        let rolls = |seed| {
+            let mut rng = GameRng::from_seed(seed);
+            (0..200).map(|_| roll.roll(&mut rng)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(rolls(7), rolls(7));
+        assert_ne!(rolls(7), rolls(8));
+        let outcomes = rolls(7);
+        assert!(outcomes.iter().any(|&(critical, _)| critical));
+        assert!(outcomes.iter().any(|&(_, missed)| missed));
+    }
+
+    #[test]
+    fn test_crit_chance_upgrade_reaches_combat_stat() {
+        let mut app = game_app(StartupConfig { experience: 100, ..startup_config() }, manual_automation());
+        app.insert_resource(UpgradeStrategy { enabled: false, ..default() });
+        app.update();
+        app.update();
+
+        app.world_mut().send_event(ManualUpgradeEvent { stat: StatKind::CritChance, amount: BuyAmount::One });
+        app.update();
+
+        let world = app.world_mut();
+        let level = world.query_filtered::<&UpgradeLevel, With<UpgradeableCritChance>>().single(world).unwrap().0;
+        assert_eq!(level, 1);
+        let crit_chance = world.query_filtered::<&CombatCritChance, With<Player>>().single(world).unwrap().0;
+        assert!((crit_chance.to_f64().unwrap() - 0.05 * 1.15).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_crit_chance_purchases_stop_at_the_cap() {
+        // 0.05 * 1.15^22 is the first value past 100%
+        assert_eq!(StatKind::CritChance.levels_to_cap(big(0.05), 1.15), 22);
+        assert_eq!(StatKind::CritChance.levels_to_cap(big(1.0), 1.15), 0);
+        assert_eq!(StatKind::Attack.levels_to_cap(big(1e9), 1.15), u32::MAX);
+
+        let mut app = game_app(StartupConfig { experience: 1_000_000, ..startup_config() }, manual_automation());
+        app.insert_resource(UpgradeStrategy { enabled: false, ..default() });
+        app.update();
+        app.update();
+        for _ in 0..2 {
+            app.world_mut().send_event(ManualUpgradeEvent { stat: StatKind::CritChance, amount: BuyAmount::Max });
+            app.update();
+        }
+
+        let world = app.world_mut();
+        let level = world.query_filtered::<&UpgradeLevel, With<UpgradeableCritChance>>().single(world).unwrap().0;
+        assert_eq!(level, 22);
+    }
+}
@@ -87,6 +87,7 @@ mod tests {
         let automation_config = AutomationConfig {
             auto_retry_unlocked: true,
             auto_retry_enabled: false,
+            auto_cast_enabled: false,
         };
 
         assert!(automation_config.auto_retry_unlocked);
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::events::*;
+    use crate::systems::combat_core::{enemy_attack_system, player_attack_system};
+    use crate::tests::fixtures::certain_hit;
+    use crate::CombatState;
+
+    fn big(value: f64) -> BigFloat {
+        BigFloat::from(value)
+    }
+
+    fn damage(formula: DamageFormula, attack: f64, defense: f64) -> BigFloat {
+        DamageBalance { formula, ..default() }.damage(big(attack), big(defense))
+    }
+
+    #[test]
+    fn test_formulas() {
+        assert_eq!(damage(DamageFormula::Subtractive, 20.0, 5.0), big(15.0));
+        assert_eq!(damage(DamageFormula::Ratio, 20.0, 5.0), big(16.0));
+        assert_eq!(damage(DamageFormula::Mitigation, 20.0, 100.0), big(10.0));
+    }
+
+    #[test]
+    fn test_every_formula_deals_at_least_one_damage() {
+        for formula in DamageFormula::ALL {
+            assert_eq!(damage(formula, 1.0, 1e6), big(1.0), "{}", formula.label());
+            assert_eq!(damage(formula, 0.0, 0.0), big(1.0), "{}", formula.label());
+        }
+    }
+
+    #[test]
+    fn test_defense_keeps_mattering_at_large_scales() {
+        // Subtractive: defense just above attack blocks everything
+        assert_eq!(damage(DamageFormula::Subtractive, 1e30, 1.1e30), big(1.0));
+        // Ratio: the same defense only halves the damage roughly
+        let ratio = damage(DamageFormula::Ratio, 1e30, 1.1e30).to_f64().unwrap();
+        assert!((ratio / 1e30 - 1.0 / 2.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_formula_is_read_from_config() {
+        let config = BalanceConfig::from_ron("(damage: (formula: Mitigation, mitigation_scale: 50.0))").unwrap();
+        assert_eq!(config.damage.formula, DamageFormula::Mitigation);
+        assert_eq!(config.damage.damage(big(30.0), big(50.0)), big(15.0));
+    }
+
+    #[derive(Resource, Default)]
+    struct Damages(Vec<(Entity, BigFloat)>);
+
+    fn collect_damage(mut events: EventReader<AttackEvent>, mut damages: ResMut<Damages>) {
+        damages.0.extend(events.read().map(|attack| (attack.attacker, attack.damage)));
+    }
+
+    #[test]
+    fn test_configured_formula_applies_to_both_sides() {
+        let mut balance = BalanceConfig::default();
+        balance.damage.formula = DamageFormula::Ratio;
+        let mut app = App::new();
+        app.add_event::<AttackEvent>()
+            .insert_resource(CombatState { is_game_over: false, in_dungeon: true })
+            .insert_resource(balance)
+            .init_resource::<RebirthShop>()
+            .init_resource::<Achievements>()
+            .init_resource::<GameRng>()
+            .init_resource::<Damages>()
+            .add_systems(Update, (player_attack_system, enemy_attack_system, collect_damage).chain());
+
+        let player = app.world_mut().spawn((Player, CombatAttack(big(20.0)), CombatDefense(big(30.0)), certain_hit())).id();
+        let enemy = app.world_mut().spawn((Enemy, CombatAttack(big(10.0)), CombatDefense(big(5.0)), certain_hit())).id();
+        app.update();
+
+        let damages = &app.world().resource::<Damages>().0;
+        assert_eq!(damages, &vec![(player, big(16.0)), (enemy, big(2.5))]);
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::systems::initialization::enemy_stats;
+
+    #[test]
+    fn test_early_enemies_are_standard() {
+        let archetypes = EnemyArchetypes::default();
+        for enemy_number in 1..=archetypes.standard_until {
+            assert_eq!(archetypes.select(enemy_number), EnemyArchetype::Standard);
+        }
+    }
+
+    #[test]
+    fn test_cycle_selection_order() {
+        let archetypes = EnemyArchetypes {
+            selection: ArchetypeSelection::Cycle,
+            standard_until: 1,
+            ..Default::default()
+        };
+        let picked: Vec<EnemyArchetype> = (2..=6).map(|n| archetypes.select(n)).collect();
+
+        assert_eq!(picked, vec![
+            EnemyArchetype::Tank,
+            EnemyArchetype::GlassCannon,
+            EnemyArchetype::Speedster,
+            EnemyArchetype::Standard,
+            EnemyArchetype::Tank,
+        ]);
+    }
+
+    #[test]
+    fn test_weighted_selection_is_deterministic() {
+        let archetypes = EnemyArchetypes::default();
+        let reseeded = EnemyArchetypes { seed: 42, ..Default::default() };
+
+        let first: Vec<EnemyArchetype> = (1..200).map(|n| archetypes.select(n)).collect();
+        let second: Vec<EnemyArchetype> = (1..200).map(|n| archetypes.select(n)).collect();
+        let other: Vec<EnemyArchetype> = (1..200).map(|n| reseeded.select(n)).collect();
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn test_weighted_selection_follows_weights() {
+        let mut archetypes = EnemyArchetypes {
+            standard_until: 0,
+            ..Default::default()
+        };
+        archetypes.speedster.weight = 0;
+
+        let mut counts: HashMap<EnemyArchetype, u32> = HashMap::new();
+        for enemy_number in 1..=10_000 {
+            *counts.entry(archetypes.select(enemy_number)).or_default() += 1;
+        }
+
+        // Weights 4:2:2:0 - expect roughly 5000/2500/2500/0
+        assert_eq!(counts.get(&EnemyArchetype::Speedster), None);
+        assert!((4500..5500).contains(&counts[&EnemyArchetype::Standard]));
+        assert!((2000..3000).contains(&counts[&EnemyArchetype::Tank]));
+        assert!((2000..3000).contains(&counts[&EnemyArchetype::GlassCannon]));
+    }
+
+    #[test]
+    fn test_all_weights_zero_falls_back_to_standard() {
+        let mut archetypes = EnemyArchetypes::default();
+        for archetype in [&mut archetypes.standard, &mut archetypes.tank, &mut archetypes.glass_cannon, &mut archetypes.speedster] {
+            archetype.weight = 0;
+        }
+
+        assert_eq!(archetypes.select(50), EnemyArchetype::Standard);
+    }
+
+    #[test]
+    fn test_enemy_stats_apply_archetype_multipliers() {
+        let mut balance = BalanceConfig::default();
+        balance.enemy.archetypes.selection = ArchetypeSelection::Cycle;
+        balance.enemy.archetypes.standard_until = 0;
+
+        // Enemy 1 is a tank in cycle mode
+        let stats = enemy_stats(&balance, 1);
+        let tank = &balance.enemy.archetypes.tank;
+        assert_eq!(stats.archetype, EnemyArchetype::Tank);
+        assert_eq!(stats.hp, balance.enemy.hp.at(1) * BigFloat::from(tank.hp));
+        assert_eq!(stats.defense, balance.enemy.defense.at(1) * BigFloat::from(tank.defense));
+        assert_eq!(stats.exp_reward, balance.enemy.exp_reward.at(1) * BigFloat::from(tank.exp_reward));
+    }
+
+    #[test]
+    fn test_archetype_names() {
+        for archetype in EnemyArchetype::ALL {
+            assert!(!archetype.name().is_empty());
+            assert!(!archetype.label().is_empty());
+        }
+        assert_eq!(EnemyArchetype::GlassCannon.label(), "Glass Cannon");
+    }
+}
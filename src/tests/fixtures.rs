@@ -0,0 +1,101 @@
+// Builders shared by the test modules
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+use crate::components::*;
+use crate::systems::initialization::fresh_rating_upgrades;
+use crate::systems::persistence::*;
+use crate::{AutomationConfig, BalanceConfig, CombatPlugin, PlayerPlugin, StartupConfig, StatsPlugin};
+
+// Level 1 with no experience and no upgrades; override fields with struct update syntax
+pub fn startup_config() -> StartupConfig {
+    StartupConfig {
+        level: 1,
+        experience: 0,
+        hp_level: 0,
+        attack_level: 0,
+        defense_level: 0,
+        speed_level: 0,
+        duration: 0,
+    }
+}
+
+// Every automation toggle off
+pub fn manual_automation() -> AutomationConfig {
+    AutomationConfig {
+        auto_retry_unlocked: false,
+        auto_retry_enabled: false,
+        auto_cast_enabled: false,
+    }
+}
+
+// The game's player, combat and stats plugins; resources inserted afterwards replace the
+// plugin defaults as long as it happens before the first update
+pub fn game_app(config: StartupConfig, automation: AutomationConfig) -> App {
+    let mut app = App::new();
+    app.add_plugins((TaskPoolPlugin::default(), bevy::time::TimePlugin))
+        .insert_resource(config)
+        .insert_resource(automation)
+        .add_plugins((PlayerPlugin, CombatPlugin, StatsPlugin));
+    app
+}
+
+// Attacks ready at once that always land and never crit
+pub fn certain_hit() -> impl Bundle {
+    (
+        CombatSpeed(BigFloat::from(1.0)),
+        AttackCooldown(0.0),
+        CombatCritChance(BigFloat::from(0.0)),
+        CombatCritMultiplier(BigFloat::from(1.0)),
+        CombatAccuracy(BigFloat::from(1.0)),
+        CombatEvasion(BigFloat::from(0.0)),
+    )
+}
+
+// Fresh-game save with the given upgrade level on every stat
+pub fn save_with_levels(level: u32, in_dungeon: bool, auto_retry: bool) -> SaveData {
+    let big = |value: f64| -> SavedBigFloat { BigFloat::from(value).into() };
+    let upgrade = |base: f64| UpgradeSave {
+        level,
+        cost: big(10.0 * 1.3f64.powi(level as i32)),
+        base_value: big(base),
+    };
+    SaveData {
+        version: SAVE_VERSION,
+        saved_at: 1_700_000_000,
+        player: PlayerSave {
+            base_hp: big(100.0),
+            base_attack: big(10.0),
+            base_defense: big(5.0),
+            base_speed: big(1.0),
+            experience: big(0.0),
+            level: 1,
+            lifetime_experience: big(0.0),
+            rebirth_points: big(0.0),
+        },
+        hp: upgrade(100.0),
+        attack: upgrade(10.0),
+        defense: upgrade(5.0),
+        speed: upgrade(1.0),
+        combat_ratings: CombatRatingsSave::from_upgrades(&fresh_rating_upgrades(&BalanceConfig::default())),
+        progress: ProgressSave {
+            current_enemy_number: 1,
+            has_died_once: false,
+        },
+        combat: CombatSave {
+            is_game_over: false,
+            in_dungeon,
+        },
+        automation: AutomationSave {
+            auto_retry_unlocked: auto_retry,
+            auto_retry_enabled: auto_retry,
+            auto_cast_enabled: false,
+        },
+        rebirth: RebirthSave::default(),
+        shop: RebirthShopSave::default(),
+        upgrade_strategy: UpgradeStrategySave::default(),
+        achievements: AchievementsSave::default(),
+        statistics: StatisticsSave::default(),
+        settings: SettingsSave::default(),
+        rng: RngSave::from(&GameRng::default()),
+    }
+}
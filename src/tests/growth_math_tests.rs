@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::systems::upgrades::{affordable_levels, purchase_levels};
+
+    fn assert_close(actual: BigFloat, expected: f64) {
+        let actual = actual.to_f64().unwrap();
+        assert!((actual - expected).abs() <= expected.abs() * 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn test_big_pow_matches_repeated_multiplication() {
+        for exponent in [0, 1, 2, 7, 16, 33] {
+            assert_close(big_pow(BigFloat::from(1.15), exponent), 1.15f64.powi(exponent as i32));
+        }
+        assert_close(calculate_exponential_growth(BigFloat::from(20.0), 1.5, 10), 20.0 * 1.5f64.powi(10));
+    }
+
+    #[test]
+    fn test_growth_at_millions_of_levels() {
+        // 1.000001^2_000_000 = e^2 (to within rounding)
+        let value = recalculate_current_value(
+            &BaseValue(BigFloat::from(3.0)),
+            &UpgradeLevel(2_000_000),
+            &UpgradeMultiplier(1.000001),
+        );
+        let expected = 3.0 * (2_000_000.0 * 1.000001f64.ln()).exp();
+        let actual = value.to_f64().unwrap();
+        assert!((actual - expected).abs() < expected * 1e-6);
+    }
+
+    #[test]
+    fn test_geometric_cost_sum_matches_loop() {
+        for multiplier in [1.3, 1.0, 0.5] {
+            let mut expected = 0.0;
+            let mut cost = 10.0;
+            for levels in 0..12 {
+                assert_close(geometric_cost_sum(BigFloat::from(10.0), multiplier, levels), expected);
+                expected += cost;
+                cost *= multiplier;
+            }
+        }
+    }
+
+    #[test]
+    fn test_affordable_levels_handles_huge_counts() {
+        // Flat 1 EXP per level: exactly as many levels as EXP
+        let (levels, total) = affordable_levels(
+            BigFloat::from(5_000_000.0),
+            &UpgradeCost(BigFloat::from(1.0)),
+            &CostMultiplier(1.0),
+            u32::MAX,
+        );
+        assert_eq!(levels, 5_000_000);
+        assert_close(total, 5_000_000.0);
+
+        // The limit caps the count even when more is affordable
+        let (levels, _) = affordable_levels(
+            BigFloat::from(5_000_000.0),
+            &UpgradeCost(BigFloat::from(1.0)),
+            &CostMultiplier(1.0),
+            100,
+        );
+        assert_eq!(levels, 100);
+    }
+
+    #[test]
+    fn test_purchase_levels_advances_cost() {
+        let mut experience = BigFloat::from(100.0);
+        let mut level = UpgradeLevel(4);
+        let mut cost = UpgradeCost(BigFloat::from(10.0));
+
+        // 10 + 20 + 40 = 70
+        let bought = purchase_levels(&mut experience, &mut level, &mut cost, &CostMultiplier(2.0), u32::MAX);
+
+        assert_eq!(bought, 3);
+        assert_eq!(level.0, 7);
+        assert_close(cost.0, 80.0);
+        assert_close(experience, 30.0);
+    }
+}
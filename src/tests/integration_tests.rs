@@ -53,6 +53,8 @@ mod tests {
             attacker: Entity::PLACEHOLDER,
             target: Entity::PLACEHOLDER,
             damage: BigFloat::from(15.0),
+            critical: false,
+            missed: false,
         };
         assert_eq!(attack_event.damage, BigFloat::from(15.0));
         
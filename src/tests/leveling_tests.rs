@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::systems::offline_progress::simulate_offline_progress;
+    use crate::systems::persistence::*;
+    use crate::tests::fixtures::{game_app, manual_automation, save_with_levels, startup_config};
+    use crate::{ExpGainEvent, StartupConfig};
+
+    fn leveling_app(level: u32) -> App {
+        let mut app = game_app(StartupConfig { level, ..startup_config() }, manual_automation());
+        app.update();
+        app.update();
+        app
+    }
+
+    fn player_level(app: &mut App) -> u32 {
+        let world = app.world_mut();
+        world.query_filtered::<&Level, With<Player>>().single(world).unwrap().0
+    }
+
+    fn base_value<T: Component>(app: &mut App) -> f64 {
+        let world = app.world_mut();
+        world.query_filtered::<&BaseValue, With<T>>().single(world).unwrap().0.to_f64().unwrap()
+    }
+
+    #[test]
+    fn test_exp_curve_thresholds() {
+        let leveling = LevelingBalance::default();
+
+        // 20 EXP to level 2, then 24 more to level 3
+        assert_eq!(leveling.exp_to_reach(1), BigFloat::from(0.0));
+        assert!((leveling.exp_to_reach(3).to_f64().unwrap() - 44.0).abs() < 1e-9);
+        assert_eq!(leveling.level_for(BigFloat::from(0.0)), 1);
+        assert_eq!(leveling.level_for(BigFloat::from(19.9)), 1);
+        assert_eq!(leveling.level_for(BigFloat::from(20.1)), 2);
+        assert_eq!(leveling.level_for(BigFloat::from(43.9)), 2);
+        assert_eq!(leveling.level_for(BigFloat::from(44.1)), 3);
+    }
+
+    #[test]
+    fn test_starting_level_applies_level_bonuses() {
+        let mut app = leveling_app(4);
+        let leveling = LevelingBalance::default();
+
+        assert_eq!(player_level(&mut app), 4);
+        assert!((base_value::<UpgradeableHp>(&mut app) - (100.0 + 3.0 * leveling.hp_per_level)).abs() < 1e-9);
+        assert!((base_value::<UpgradeableAttack>(&mut app) - (10.0 + 3.0 * leveling.attack_per_level)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_exp_gain_levels_up_without_spending() {
+        let mut app = leveling_app(1);
+        // Keep the EXP from being spent so the gain is visible
+        app.world_mut().resource_mut::<UpgradeStrategy>().enabled = false;
+
+        app.world_mut().send_event(ExpGainEvent { amount: BigFloat::from(25.0) });
+        app.update();
+        app.update();
+
+        assert_eq!(player_level(&mut app), 2);
+        assert!((base_value::<UpgradeableHp>(&mut app) - 105.0).abs() < 1e-9);
+        let world = app.world_mut();
+        let (lifetime, max_hp) = world
+            .query_filtered::<(&LifetimeExperience, &MaxHp), With<Player>>()
+            .single(world)
+            .unwrap();
+        assert_eq!(lifetime.0, BigFloat::from(25.0));
+        assert!((max_hp.0.to_f64().unwrap() - 105.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_offline_progress_levels_up() {
+        let mut save = SaveData {
+            upgrade_strategy: UpgradeStrategySave { enabled: false, ..default() },
+            ..save_with_levels(5, true, false)
+        };
+
+        let report = simulate_offline_progress(&mut save, &BalanceConfig::default(), 10);
+
+        assert_eq!(report.deaths, 0);
+        let lifetime: BigFloat = save.player.lifetime_experience.into();
+        assert_eq!(lifetime, report.exp_earned);
+        let level = BalanceConfig::default().leveling.level_for(lifetime);
+        assert!(level > 1);
+        assert_eq!(save.player.level, level);
+        let hp_base: BigFloat = save.hp.base_value.into();
+        assert!((hp_base.to_f64().unwrap() - (100.0 + 5.0 * (level - 1) as f64)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_offline_death_keeps_level() {
+        let leveling = LevelingBalance::default();
+        let mut save = SaveData {
+            upgrade_strategy: UpgradeStrategySave { enabled: false, ..default() },
+            ..save_with_levels(0, true, false)
+        };
+        save.player.level = 3;
+        save.player.lifetime_experience = leveling.exp_to_reach(3).into();
+        let mut balance = BalanceConfig::default();
+        balance.enemy.attack.base = 1e6;
+
+        let report = simulate_offline_progress(&mut save, &balance, 60);
+
+        assert_eq!(report.deaths, 1);
+        assert_eq!(save.player.level, 3);
+        let lifetime: BigFloat = save.player.lifetime_experience.into();
+        assert_eq!(lifetime, leveling.exp_to_reach(3));
+        // Reborn with one rebirth point (x1.1) on the base HP, plus the two levels' bonuses
+        let hp_base: BigFloat = save.hp.base_value.into();
+        assert!((hp_base.to_f64().unwrap() - (110.0 + 2.0 * leveling.hp_per_level)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::systems::offline_progress::simulate_offline_progress;
+    use crate::systems::persistence::*;
+    use crate::systems::upgrades::affordable_levels;
+    use crate::tests::fixtures::{game_app, manual_automation, save_with_levels, startup_config};
+    use crate::{ManualUpgradeEvent, StartupConfig};
+
+    // Auto-upgrades off so only ManualUpgradeEvent spends EXP
+    fn manual_app(experience: u64) -> App {
+        let mut app = game_app(StartupConfig { experience, ..startup_config() }, manual_automation());
+        app.insert_resource(UpgradeStrategy { enabled: false, ..default() });
+        app.update();
+        app.update();
+        app
+    }
+
+    fn level_of<T: Component>(app: &mut App) -> u32 {
+        let world = app.world_mut();
+        world.query_filtered::<&UpgradeLevel, With<T>>().single(world).unwrap().0
+    }
+
+    fn experience(app: &mut App) -> f64 {
+        let world = app.world_mut();
+        world.query_filtered::<&Experience, With<Player>>().single(world).unwrap().0.to_f64().unwrap()
+    }
+
+    #[test]
+    fn test_affordable_levels_sums_growing_costs() {
+        let cost = UpgradeCost(BigFloat::from(10.0));
+        let multiplier = CostMultiplier(2.0);
+
+        // 10 + 20 + 40 = 70 fits in 100, the next 80 does not
+        let (levels, total) = affordable_levels(BigFloat::from(100.0), &cost, &multiplier, u32::MAX);
+        assert_eq!(levels, 3);
+        assert!((total.to_f64().unwrap() - 70.0).abs() < 1e-9);
+
+        let (levels, total) = affordable_levels(BigFloat::from(100.0), &cost, &multiplier, 2);
+        assert_eq!(levels, 2);
+        assert!((total.to_f64().unwrap() - 30.0).abs() < 1e-9);
+
+        assert_eq!(affordable_levels(BigFloat::from(5.0), &cost, &multiplier, 1).0, 0);
+    }
+
+    #[test]
+    fn test_auto_upgrade_disabled_leaves_exp_unspent() {
+        let mut app = manual_app(1000);
+
+        assert_eq!(level_of::<UpgradeableHp>(&mut app), 0);
+        assert!((experience(&mut app) - 1000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_buy_x10_stops_at_ten_levels() {
+        let mut app = manual_app(1_000_000);
+
+        app.world_mut().send_event(ManualUpgradeEvent { stat: StatKind::Attack, amount: BuyAmount::Ten });
+        app.update();
+
+        assert_eq!(level_of::<UpgradeableAttack>(&mut app), 10);
+        assert_eq!(level_of::<UpgradeableHp>(&mut app), 0);
+        assert!(experience(&mut app) < 1_000_000.0);
+    }
+
+    #[test]
+    fn test_buy_max_spends_everything_affordable() {
+        let mut app = manual_app(100);
+        let world = app.world_mut();
+        let (cost, cost_multiplier) = world
+            .query_filtered::<(&UpgradeCost, &CostMultiplier), With<UpgradeableDefense>>()
+            .single(world)
+            .map(|(cost, multiplier)| (cost.clone(), multiplier.clone()))
+            .unwrap();
+        let (expected, total) = affordable_levels(BigFloat::from(100.0), &cost, &cost_multiplier, u32::MAX);
+
+        app.world_mut().send_event(ManualUpgradeEvent { stat: StatKind::Defense, amount: BuyAmount::Max });
+        app.update();
+
+        assert!(expected > 1);
+        assert_eq!(level_of::<UpgradeableDefense>(&mut app), expected);
+        assert!((experience(&mut app) - (100.0 - total.to_f64().unwrap())).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_manual_purchase_updates_current_value() {
+        let mut app = manual_app(10);
+
+        app.world_mut().send_event(ManualUpgradeEvent { stat: StatKind::Speed, amount: BuyAmount::One });
+        app.update();
+
+        let world = app.world_mut();
+        let (value, base, multiplier) = world
+            .query_filtered::<(&CurrentValue, &BaseValue, &UpgradeMultiplier), With<UpgradeableSpeed>>()
+            .single(world)
+            .unwrap();
+        let expected = base.0.to_f64().unwrap() * multiplier.0;
+        assert!((value.0.to_f64().unwrap() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_offline_progress_respects_disabled_auto_upgrade() {
+        let mut save = SaveData {
+            upgrade_strategy: UpgradeStrategySave { enabled: false, ..default() },
+            ..save_with_levels(5, true, false)
+        };
+
+        let report = simulate_offline_progress(&mut save, &BalanceConfig::default(), 10);
+
+        assert!(report.enemies_killed > 0);
+        assert_eq!(report.deaths, 0);
+        assert_eq!(report.upgrades_bought, 0);
+        assert!(save.player.experience.mantissa > 0.0);
+        assert_eq!(save.hp.level + save.attack.level + save.defense.level + save.speed.level, 20);
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use too_big_float::BigFloat;
+    use crate::number_format::NumberNotation;
+    use crate::plugins::balance_check::*;
+
+    fn format(notation: NumberNotation, value: f64) -> String {
+        notation.format(BigFloat::from(value))
+    }
+
+    #[test]
+    fn test_small_numbers_are_plain_in_every_notation() {
+        for notation in NumberNotation::ALL {
+            assert_eq!(format(notation, 0.0), "0.00");
+            assert_eq!(format(notation, 42.5), "42.50");
+            assert_eq!(format(notation, 999.25), "999.25");
+            assert_eq!(notation.format_whole(BigFloat::from(12.0)), "12");
+        }
+    }
+
+    #[test]
+    fn test_suffix_notation() {
+        let notation = NumberNotation::Suffix;
+        assert_eq!(format(notation, 1234.5), "1.23K");
+        assert_eq!(format(notation, 2.5e6), "2.50M");
+        assert_eq!(format(notation, 1.5e15), "1.50Qa");
+        assert_eq!(format(notation, -1234.5), "-1.23K");
+        // Rounding carries into the next suffix
+        assert_eq!(format(notation, 999_999.0), "1.00M");
+        // Past the last suffix
+        assert_eq!(format(notation, 1e36), "1.00e36");
+    }
+
+    #[test]
+    fn test_scientific_and_engineering_notation() {
+        assert_eq!(format(NumberNotation::Scientific, 1234.5), "1.23e3");
+        assert_eq!(format(NumberNotation::Scientific, 6.789e7), "6.79e7");
+        assert_eq!(format(NumberNotation::Scientific, 9.999e7), "1.00e8");
+        assert_eq!(format(NumberNotation::Engineering, 6.789e7), "67.89e6");
+        assert_eq!(format(NumberNotation::Engineering, 1.5e15), "1.50e15");
+    }
+
+    #[test]
+    fn test_letter_notation() {
+        let notation = NumberNotation::Letter;
+        assert_eq!(format(notation, 1000.0), "1.00a");
+        assert_eq!(format(notation, 2.5e6), "2.50b");
+        assert_eq!(format(notation, 1e78), "1.00z");
+        assert_eq!(format(notation, 1e81), "1.00aa");
+    }
+
+    #[test]
+    fn test_values_near_the_f64_limit() {
+        assert_eq!(format(NumberNotation::Scientific, 1e300), "1.00e300");
+        assert_eq!(format(NumberNotation::Letter, 1e300), "1.00cv");
+    }
+
+    #[test]
+    fn test_notations_cycle() {
+        let mut notation = NumberNotation::default();
+        for _ in 0..NumberNotation::ALL.len() {
+            notation = notation.next();
+        }
+        assert_eq!(notation, NumberNotation::default());
+    }
+
+    #[test]
+    fn test_text_report_uses_notation() {
+        let record = BalanceRecord {
+            report: 1,
+            level: Some(2),
+            experience: Some(BigFloat::from(1234.5)),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+
+        write_text_report(&mut out, &record, NumberNotation::Scientific).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("Experience: 1.23e3"));
+        assert!(text.contains("Level: 2"));
+    }
+}
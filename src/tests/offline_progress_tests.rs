@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use too_big_float::BigFloat;
+    use crate::systems::offline_progress::*;
+    use crate::tests::fixtures::save_with_levels;
+    use crate::BalanceConfig;
+
+    #[test]
+    fn test_no_progress_outside_dungeon() {
+        let mut save = save_with_levels(0, false, false);
+        let before = save.clone();
+
+        let report = simulate_offline_progress(&mut save, &BalanceConfig::default(), 3600);
+
+        assert_eq!(report.enemies_killed, 0);
+        assert_eq!(save, before);
+    }
+
+    #[test]
+    fn test_offline_kills_award_exp_and_advance_enemies() {
+        let mut save = save_with_levels(5, true, true);
+
+        let report = simulate_offline_progress(&mut save, &BalanceConfig::default(), 60);
+
+        assert!(report.enemies_killed > 0);
+        assert!(report.exp_earned > BigFloat::from(0.0));
+        assert!(report.highest_enemy > 1);
+        assert!(save.progress.current_enemy_number > 1 || report.deaths > 0);
+    }
+
+    #[test]
+    fn test_long_absence_hits_the_wall_and_rebirths() {
+        let mut save = save_with_levels(0, true, true);
+
+        let report = simulate_offline_progress(&mut save, &BalanceConfig::default(), 10 * 60 * 60);
+
+        assert!(report.deaths > 0);
+        assert!(report.rebirth_points_gained > BigFloat::from(0.0));
+        assert!(save.progress.has_died_once);
+        assert!(save.combat.in_dungeon);
+    }
+
+    #[test]
+    fn test_death_without_auto_retry_stops_combat() {
+        let mut save = save_with_levels(0, true, false);
+
+        let report = simulate_offline_progress(&mut save, &BalanceConfig::default(), 10 * 60 * 60);
+
+        assert_eq!(report.deaths, 1);
+        assert!(save.combat.is_game_over);
+        assert!(!save.combat.in_dungeon);
+        assert!(save.automation.auto_retry_unlocked);
+        assert_eq!(save.progress.current_enemy_number, 1);
+    }
+
+    #[test]
+    fn test_failed_boss_retreats_instead_of_rebirth() {
+        let mut balance = BalanceConfig::default();
+        balance.enemy.archetypes.standard_until = u32::MAX;
+        balance.boss.interval = 2;
+        balance.boss.hp = 1e9;
+        balance.boss.attack = 1000.0;
+        let mut save = save_with_levels(10, true, false);
+
+        let report = simulate_offline_progress(&mut save, &balance, 600);
+
+        assert!(report.boss_retreats > 0);
+        assert_eq!(report.deaths, 0);
+        assert_eq!(report.highest_enemy, 2);
+        assert!(!save.progress.has_died_once);
+        assert!(save.combat.in_dungeon);
+    }
+
+    #[test]
+    fn test_upgrades_are_bought_with_offline_exp() {
+        let mut save = save_with_levels(0, true, true);
+
+        let report = simulate_offline_progress(&mut save, &BalanceConfig::default(), 120);
+
+        assert!(report.upgrades_bought > 0);
+        let total_levels = save.hp.level + save.attack.level + save.defense.level + save.speed.level;
+        assert!(total_levels > 0);
+    }
+
+    #[test]
+    fn test_rebirth_shop_starting_levels_apply_offline() {
+        let mut save = save_with_levels(0, true, true);
+        save.shop.starting_levels = 2;
+
+        let report = simulate_offline_progress(&mut save, &BalanceConfig::default(), 10 * 60 * 60);
+
+        assert!(report.deaths > 0);
+        // Every reborn stat starts at level 2 and can only go up from there
+        for upgrade in [&save.hp, &save.attack, &save.defense, &save.speed] {
+            assert!(upgrade.level >= 2);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::systems::initialization::{reborn_upgrades, rebirth_stats};
+    use crate::tests::fixtures::{game_app, manual_automation, startup_config};
+    use crate::{ExpGainEvent, GameProgress, RebirthRequestEvent, RebirthShopPurchaseEvent};
+
+    fn shop_app() -> App {
+        let mut app = game_app(startup_config(), manual_automation());
+        app.update();
+        app
+    }
+
+    fn with_points(points: f64) -> RebirthCurrency {
+        RebirthCurrency {
+            points: BigFloat::from(points),
+            lifetime_points: BigFloat::from(points),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_purchase_spends_points() {
+        let balance = RebirthShopBalance::default();
+        let mut shop = RebirthShop::default();
+        let mut currency = with_points(20.0);
+
+        // 5, then 5 * 1.5
+        assert!(shop.purchase(RebirthPerk::ExpMultiplier, &balance, &mut currency));
+        assert!(shop.purchase(RebirthPerk::ExpMultiplier, &balance, &mut currency));
+
+        assert_eq!(shop.exp_multiplier, 2);
+        let points = currency.points.to_f64().unwrap();
+        assert!((points - 7.5).abs() < 1e-9);
+        // Spending never lowers the lifetime total that drives the rebirth bonus
+        assert_eq!(currency.lifetime_points, BigFloat::from(20.0));
+        let multiplier = shop.exp_multiplier(&balance).to_f64().unwrap();
+        assert!((multiplier - 1.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_purchase_requires_points_and_respects_max_level() {
+        let mut balance = RebirthShopBalance::default();
+        balance.faster_cooldown.max_level = 1;
+        let mut shop = RebirthShop::default();
+        let mut currency = with_points(1.0);
+
+        assert!(!shop.purchase(RebirthPerk::FasterCooldown, &balance, &mut currency));
+        assert_eq!(shop.faster_cooldown, 0);
+
+        currency.points = BigFloat::from(1000.0);
+        assert!(shop.purchase(RebirthPerk::FasterCooldown, &balance, &mut currency));
+        assert_eq!(shop.next_cost(RebirthPerk::FasterCooldown, &balance), None);
+        assert!(!shop.purchase(RebirthPerk::FasterCooldown, &balance, &mut currency));
+        assert_eq!(shop.faster_cooldown, 1);
+    }
+
+    #[test]
+    fn test_reborn_upgrades_apply_starting_levels_and_stat_discounts() {
+        let balance = BalanceConfig::default();
+        let shop = RebirthShop {
+            starting_levels: 2,
+            cheaper_attack: 5,
+            ..default()
+        };
+        let stats = rebirth_stats(&balance, BigFloat::from(0.0));
+
+        let [hp, attack, ..] = reborn_upgrades(&balance, &stats, &shop, 1);
+
+        assert_eq!(hp.level, 2);
+        assert_eq!(attack.level, 2);
+        // 10 * 1.3^2, and attack / 1.5 on top
+        let hp_cost = hp.cost.to_f64().unwrap();
+        assert!((hp_cost - 16.9).abs() < 1e-9);
+        let attack_cost = attack.cost.to_f64().unwrap();
+        assert!((attack_cost - 16.9 / 1.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rebirth_spawns_stats_at_starting_level() {
+        let mut app = shop_app();
+        app.world_mut().resource_mut::<RebirthShop>().starting_levels = 3;
+        app.world_mut().resource_mut::<GameProgress>().current_enemy_number = 5;
+
+        app.world_mut().send_event(RebirthRequestEvent);
+        app.update();
+        app.update();
+
+        let mut query = app.world_mut().query::<(&UpgradeLevel, &CurrentValue, &BaseValue, &UpgradeMultiplier)>();
+        let stats: Vec<_> = query.iter(app.world()).map(|(level, current, base, multiplier)| {
+            (level.0, current.0.to_f64().unwrap(), base.0.to_f64().unwrap(), multiplier.0)
+        }).collect();
+        assert_eq!(stats.len(), StatKind::COUNT);
+        for (level, current, base, multiplier) in stats {
+            assert_eq!(level, 3);
+            assert!((current - base * multiplier.powi(3)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_exp_multiplier_applies_to_exp_gain() {
+        let mut app = shop_app();
+        app.world_mut().resource_mut::<RebirthShop>().exp_multiplier = 4;
+
+        app.world_mut().send_event(ExpGainEvent { amount: BigFloat::from(1.0) });
+        app.update();
+
+        let mut query = app.world_mut().query_filtered::<&Experience, With<Player>>();
+        let experience = query.single(app.world()).unwrap().0.to_f64().unwrap();
+        // x2 from four levels of +25%; too little to buy an upgrade
+        assert!((experience - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_purchase_event_buys_perk() {
+        let mut app = shop_app();
+        app.world_mut().insert_resource(with_points(10.0));
+
+        app.world_mut().send_event(RebirthShopPurchaseEvent { perk: RebirthPerk::StartingLevels });
+        app.update();
+
+        assert_eq!(app.world().resource::<RebirthShop>().starting_levels, 1);
+        assert_eq!(app.world().resource::<RebirthCurrency>().points, BigFloat::from(0.0));
+    }
+}
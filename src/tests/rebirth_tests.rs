@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::systems::initialization::rebirth_stats;
+    use crate::systems::rebirth::*;
+    use crate::tests::fixtures::{game_app, manual_automation, startup_config};
+    use crate::{GameProgress, RebirthRequestEvent, StartupConfig};
+
+    fn progress(enemy_number: u32) -> GameProgress {
+        GameProgress {
+            current_enemy_number: enemy_number,
+            has_died_once: false,
+        }
+    }
+
+    fn rebirth_app() -> App {
+        let mut app = game_app(startup_config(), manual_automation());
+        app.update();
+        app
+    }
+
+    fn player_rebirth_points(app: &mut App) -> Vec<BigFloat> {
+        let mut query = app.world_mut().query_filtered::<&RebirthPoints, (With<Player>, With<Level>)>();
+        query.iter(app.world()).map(|points| points.0).collect()
+    }
+
+    fn currency(lifetime: f64) -> RebirthCurrency {
+        RebirthCurrency {
+            points: BigFloat::from(lifetime),
+            lifetime_points: BigFloat::from(lifetime),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_preview_uses_rebirth_formula() {
+        let balance = BalanceConfig::default();
+        let preview = RebirthPreview::new(&balance, &progress(8), &currency(0.0));
+
+        assert_eq!(preview.points_gained, BigFloat::from(8.0));
+        assert_eq!(preview.stats, rebirth_stats(&balance, BigFloat::from(8.0)));
+        // 8 points: stats x1.8, cost / 1.4
+        let bonus = preview.stats.bonus.to_f64().unwrap();
+        assert!((bonus - 1.8).abs() < 1e-9);
+        let cost = preview.cost_multiplier.to_f64().unwrap();
+        assert!((cost - 1.0 / 1.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_preview_adds_to_lifetime_points() {
+        let balance = BalanceConfig::default();
+        let preview = RebirthPreview::new(&balance, &progress(5), &currency(10.0));
+
+        assert_eq!(preview.stats, rebirth_stats(&balance, BigFloat::from(15.0)));
+        let current = preview.current_bonus.to_f64().unwrap();
+        assert!((current - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_preview_available_from_min_enemy() {
+        let balance = BalanceConfig::default();
+        let min_enemy = balance.rebirth.manual_min_enemy;
+
+        assert!(RebirthPreview::new(&balance, &progress(min_enemy), &currency(50.0)).available);
+        assert!(!RebirthPreview::new(&balance, &progress(min_enemy - 1), &currency(0.0)).available);
+    }
+
+    #[test]
+    fn test_currency_history_is_capped() {
+        let mut currency = RebirthCurrency::default();
+        for enemy in 0..(MAX_REBIRTH_HISTORY as u32 + 5) {
+            currency.record(BigFloat::from(1.0), enemy, RebirthCause::Death);
+        }
+
+        assert_eq!(currency.history.len(), MAX_REBIRTH_HISTORY);
+        assert_eq!(currency.history.front().unwrap().enemy_reached, 5);
+        assert_eq!(currency.rebirth_count, MAX_REBIRTH_HISTORY as u32 + 5);
+        let lifetime = currency.lifetime_points.to_f64().unwrap();
+        assert!((lifetime - (MAX_REBIRTH_HISTORY + 5) as f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_manual_rebirth_resets_run() {
+        let mut app = rebirth_app();
+        app.world_mut().resource_mut::<GameProgress>().current_enemy_number = 6;
+
+        app.world_mut().send_event(RebirthRequestEvent);
+        app.update();
+
+        assert_eq!(player_rebirth_points(&mut app), vec![BigFloat::from(6.0)]);
+        assert_eq!(app.world().resource::<GameProgress>().current_enemy_number, 1);
+        assert!(!app.world().resource::<GameProgress>().has_died_once);
+
+        let mut stats = app.world_mut().query_filtered::<&BaseValue, With<UpgradeableAttack>>();
+        let attack: Vec<f64> = stats.iter(app.world()).map(|base| base.0.to_f64().unwrap()).collect();
+        assert_eq!(attack.len(), 1);
+        assert!((attack[0] - 16.0).abs() < 1e-9);
+
+        let mut enemies = app.world_mut().query_filtered::<&EnemyNumber, With<Enemy>>();
+        let enemy_numbers: Vec<u32> = enemies.iter(app.world()).map(|number| number.0).collect();
+        assert_eq!(enemy_numbers, vec![1]);
+    }
+
+    #[test]
+    fn test_rebirth_keeps_level_and_lifetime_exp() {
+        let mut app = game_app(StartupConfig { level: 4, ..startup_config() }, manual_automation());
+        app.update();
+        app.update();
+        app.world_mut().resource_mut::<GameProgress>().current_enemy_number = 6;
+
+        app.world_mut().send_event(RebirthRequestEvent);
+        app.update();
+
+        let leveling = LevelingBalance::default();
+        let mut players = app.world_mut().query_filtered::<(&Level, &LifetimeExperience), With<Player>>();
+        let (level, lifetime) = players.single(app.world()).unwrap();
+        assert_eq!(level.0, 4);
+        assert_eq!(lifetime.0, leveling.exp_to_reach(4));
+
+        // Rebirth bonus (x1.6) on the base attack, plus the three levels' bonuses
+        let mut stats = app.world_mut().query_filtered::<&BaseValue, With<UpgradeableAttack>>();
+        let attack = stats.single(app.world()).unwrap().0.to_f64().unwrap();
+        assert!((attack - (16.0 + 3.0 * leveling.attack_per_level)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rebirth_points_accumulate() {
+        let mut app = rebirth_app();
+        app.world_mut().resource_mut::<GameProgress>().current_enemy_number = 6;
+        app.world_mut().send_event(RebirthRequestEvent);
+        app.update();
+
+        app.world_mut().resource_mut::<GameProgress>().current_enemy_number = 7;
+        app.world_mut().send_event(RebirthRequestEvent);
+        app.update();
+
+        // The second player is built from both rebirths
+        assert_eq!(player_rebirth_points(&mut app), vec![BigFloat::from(13.0)]);
+        let currency = app.world().resource::<RebirthCurrency>();
+        assert_eq!(currency.rebirth_count, 2);
+        let enemies: Vec<u32> = currency.history.iter().map(|record| record.enemy_reached).collect();
+        assert_eq!(enemies, vec![6, 7]);
+        assert!(currency.history.iter().all(|record| record.cause == RebirthCause::Manual));
+    }
+
+    #[test]
+    fn test_manual_rebirth_locked_below_min_enemy() {
+        let mut app = rebirth_app();
+        let min_enemy = app.world().resource::<BalanceConfig>().rebirth.manual_min_enemy;
+        app.world_mut().resource_mut::<GameProgress>().current_enemy_number = min_enemy - 1;
+
+        app.world_mut().send_event(RebirthRequestEvent);
+        app.update();
+
+        assert_eq!(player_rebirth_points(&mut app), vec![BigFloat::from(0.0)]);
+        assert_eq!(app.world().resource::<RebirthCurrency>().rebirth_count, 0);
+        assert_eq!(app.world().resource::<GameProgress>().current_enemy_number, min_enemy - 1);
+    }
+}
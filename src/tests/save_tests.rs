@@ -0,0 +1,208 @@
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use rand::RngCore;
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::systems::persistence::*;
+    use crate::{GameProgress, CombatState, AutomationConfig, BalanceConfig, NumberNotation};
+
+    fn big(value: f64) -> SavedBigFloat {
+        BigFloat::from(value).into()
+    }
+
+    fn sample_save() -> SaveData {
+        let upgrade = |level| UpgradeSave {
+            level,
+            cost: big(10.0 * 1.3f64.powi(level as i32)),
+            base_value: big(100.0),
+        };
+        SaveData {
+            version: SAVE_VERSION,
+            saved_at: 1_700_000_000,
+            player: PlayerSave {
+                base_hp: big(110.0),
+                base_attack: big(11.0),
+                base_defense: big(5.5),
+                base_speed: big(1.1),
+                experience: big(1234.5),
+                level: 3,
+                lifetime_experience: big(60.0),
+                rebirth_points: big(1.0),
+            },
+            hp: upgrade(4),
+            attack: upgrade(3),
+            defense: upgrade(2),
+            speed: upgrade(1),
+            combat_ratings: CombatRatingsSave {
+                crit_chance: upgrade(2),
+                crit_multiplier: upgrade(0),
+                evasion: upgrade(1),
+                accuracy: upgrade(0),
+            },
+            progress: ProgressSave {
+                current_enemy_number: 12,
+                has_died_once: true,
+            },
+            combat: CombatSave {
+                is_game_over: false,
+                in_dungeon: true,
+            },
+            automation: AutomationSave {
+                auto_retry_unlocked: true,
+                auto_retry_enabled: true,
+                auto_cast_enabled: true,
+            },
+            rebirth: RebirthSave {
+                points: big(3.0),
+                lifetime_points: big(7.0),
+                rebirth_count: 2,
+                history: vec![
+                    RebirthRecordSave { points_gained: big(3.0), enemy_reached: 3, cause: RebirthCause::Death },
+                    RebirthRecordSave { points_gained: big(4.0), enemy_reached: 4, cause: RebirthCause::Manual },
+                ],
+            },
+            shop: RebirthShopSave {
+                exp_multiplier: 2,
+                cheaper_attack: 1,
+                ..default()
+            },
+            upgrade_strategy: UpgradeStrategySave {
+                enabled: false,
+                kind: UpgradeStrategyKind::Weighted,
+                weights: vec![2.0, 1.0, 1.0, 0.5, 1.0, 0.0, 1.0, 1.0],
+                round_robin_next: 3,
+            },
+            achievements: AchievementsSave {
+                unlocked: vec!["defeat_10".to_string(), "retired_achievement".to_string()],
+                highest_enemy_defeated: 11,
+                deaths: 1,
+                highest_upgrade_level: 4,
+                total_exp: big(500.0),
+            },
+            statistics: StatisticsSave {
+                run: StatTotalsSave { kills: 11, highest_enemy: 11, ..default() },
+                lifetime: StatTotalsSave { kills: 20, highest_enemy: 11, time_played_secs: 90.0, ..default() },
+                deaths: 1,
+                rebirths: 2,
+            },
+            settings: SettingsSave { notation: NumberNotation::Scientific },
+            rng: RngSave { seed: 99, word_pos: 48 },
+        }
+    }
+
+    #[test]
+    fn test_big_float_round_trip() {
+        for value in [0.0, 1.0, 123.456, -42.5, 1e300, 3.5e-300] {
+            let saved: SavedBigFloat = BigFloat::from(value).into();
+            let restored: BigFloat = saved.into();
+            assert_eq!(restored, BigFloat::from(value), "value {} did not survive", value);
+        }
+    }
+
+    #[test]
+    fn test_big_float_scaling_keeps_mantissa_in_range() {
+        let saved: SavedBigFloat = BigFloat::from(1e300).into();
+        assert!(saved.exponent > 0);
+        assert!(saved.mantissa.abs() <= 2f64.powi(512));
+
+        let tiny: SavedBigFloat = BigFloat::from(1e-300).into();
+        assert!(tiny.exponent < 0);
+    }
+
+    #[test]
+    fn test_save_data_text_round_trip() {
+        let data = sample_save();
+        let text = save_to_string(&data).unwrap();
+        let parsed = save_from_str(&text).unwrap();
+
+        assert_eq!(parsed, data);
+    }
+
+    #[test]
+    fn test_newer_save_version_is_rejected() {
+        let mut data = sample_save();
+        data.version = SAVE_VERSION + 1;
+        let text = save_to_string(&data).unwrap();
+
+        assert!(matches!(save_from_str(&text), Err(SaveError::UnsupportedVersion(_))));
+    }
+
+    #[test]
+    fn test_restore_spawns_player_and_resources() {
+        let mut app = App::new();
+        app.insert_resource(LoadedSave(sample_save()))
+            .init_resource::<BalanceConfig>()
+            .add_systems(Update, restore_game_system);
+        app.update();
+
+        let world = app.world_mut();
+        let (experience, level, lifetime_experience) = world
+            .query_filtered::<(&Experience, &Level, &LifetimeExperience), With<Player>>()
+            .single(world)
+            .unwrap();
+        assert_eq!(experience.0, BigFloat::from(1234.5));
+        assert_eq!(level.0, 3);
+        assert_eq!(lifetime_experience.0, BigFloat::from(60.0));
+
+        let hp_level = world
+            .query_filtered::<&UpgradeLevel, With<UpgradeableHp>>()
+            .single(world)
+            .unwrap();
+        assert_eq!(hp_level.0, 4);
+        let crit_chance_level = world
+            .query_filtered::<&UpgradeLevel, With<UpgradeableCritChance>>()
+            .single(world)
+            .unwrap();
+        assert_eq!(crit_chance_level.0, 2);
+
+        assert_eq!(world.resource::<GameProgress>().current_enemy_number, 12);
+        assert!(world.resource::<CombatState>().in_dungeon);
+        assert!(world.resource::<AutomationConfig>().auto_retry_enabled);
+        assert!(world.resource::<AutomationConfig>().auto_cast_enabled);
+
+        let currency = world.resource::<RebirthCurrency>();
+        assert_eq!(currency.points, BigFloat::from(3.0));
+        assert_eq!(currency.lifetime_points, BigFloat::from(7.0));
+        assert_eq!(currency.history.len(), 2);
+        assert_eq!(currency.history[1].cause, RebirthCause::Manual);
+
+        let shop = world.resource::<RebirthShop>();
+        assert_eq!(shop.exp_multiplier, 2);
+        assert_eq!(shop.cheaper_attack, 1);
+
+        let strategy = world.resource::<UpgradeStrategy>();
+        assert!(!strategy.enabled);
+        assert_eq!(strategy.kind, UpgradeStrategyKind::Weighted);
+        assert_eq!(strategy.weights, [2.0, 1.0, 1.0, 0.5, 1.0, 0.0, 1.0, 1.0]);
+
+        // Unknown ids are dropped; the rest map back onto ACHIEVEMENTS
+        let achievements = world.resource::<Achievements>();
+        assert_eq!(achievements.unlocked_count(), 1);
+        let boss_slayer = ACHIEVEMENTS.iter().position(|achievement| achievement.id == "defeat_10").unwrap();
+        assert!(achievements.unlocked[boss_slayer]);
+        assert_eq!(achievements.highest_enemy_defeated, 11);
+
+        let statistics = world.resource::<Statistics>();
+        assert_eq!(statistics.run.kills, 11);
+        assert_eq!(statistics.lifetime.kills, 20);
+        assert_eq!(statistics.rebirths, 2);
+
+        assert_eq!(world.resource::<DisplaySettings>().notation, NumberNotation::Scientific);
+
+        let rng = world.resource::<GameRng>();
+        assert_eq!((rng.seed(), rng.word_pos()), (99, 48));
+    }
+
+    #[test]
+    fn test_rng_save_resumes_the_same_rolls() {
+        let mut rng = GameRng::from_seed(5);
+        for _ in 0..7 {
+            rng.next_u32();
+        }
+        let mut resumed = GameRng::from(&RngSave::from(&rng));
+        let expected: Vec<u32> = (0..16).map(|_| rng.next_u32()).collect();
+        let actual: Vec<u32> = (0..16).map(|_| resumed.next_u32()).collect();
+        assert_eq!(actual, expected);
+    }
+}
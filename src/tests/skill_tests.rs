@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use bevy::prelude::*;
+    use bevy::time::{TimePlugin, TimeUpdateStrategy};
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::events::*;
+    use crate::systems::combat_core::damage_application_system;
+    use crate::systems::skill_tracking::{skill_auto_cast_system, skill_cast_system, skill_cooldown_system};
+    use crate::tests::fixtures::manual_automation;
+    use crate::{AutomationConfig, CombatState};
+
+    fn big(value: f64) -> BigFloat {
+        BigFloat::from(value)
+    }
+
+    #[derive(Resource, Default)]
+    struct Used(Vec<(SkillKind, BigFloat)>);
+
+    fn collect_used(mut events: EventReader<SkillUsedEvent>, mut used: ResMut<Used>) {
+        used.0.extend(events.read().map(|event| (event.skill, event.amount)));
+    }
+
+    // In the dungeon with time advancing 250ms per update (the first update only starts the clock)
+    fn skill_app(auto_cast_enabled: bool) -> App {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), TimePlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(250)))
+            .insert_resource(CombatState { is_game_over: false, in_dungeon: true })
+            .insert_resource(AutomationConfig { auto_cast_enabled, ..manual_automation() })
+            .init_resource::<Achievements>()
+            .init_resource::<BalanceConfig>()
+            .init_resource::<Used>()
+            .add_event::<SkillCastEvent>()
+            .add_event::<SkillUsedEvent>()
+            .add_event::<AttackEvent>()
+            .add_event::<DeathEvent>()
+            .add_systems(Update, (
+                skill_cooldown_system,
+                skill_auto_cast_system,
+                skill_cast_system,
+                damage_application_system,
+                collect_used,
+            ).chain());
+        app
+    }
+
+    // Player with attack 20 and the given HP out of 100, facing an enemy with 100 HP and defense 5
+    fn spawn_fighters(app: &mut App, hp: f64) -> (Entity, Entity) {
+        let player = app.world_mut().spawn((
+            Player,
+            CombatAttack(big(20.0)),
+            CurrentHp(big(hp)),
+            MaxHp(big(100.0)),
+            Mana::full(100.0),
+            SkillCooldowns::default(),
+        )).id();
+        let enemy = app.world_mut().spawn((Enemy, CurrentHp(big(100.0)), CombatDefense(big(5.0)))).id();
+        (player, enemy)
+    }
+
+    #[test]
+    fn test_power_strike_lands_as_an_attack() {
+        let mut app = skill_app(false);
+        let (player, enemy) = spawn_fighters(&mut app, 100.0);
+        app.world_mut().send_event(SkillCastEvent { skill: SkillKind::PowerStrike });
+        app.update();
+
+        // 2.5x the subtractive hit of 20 - 5
+        assert_eq!(app.world().get::<CurrentHp>(enemy).unwrap().0, big(62.5));
+        assert_eq!(app.world().get::<Mana>(player).unwrap().current, 70.0);
+        assert_eq!(app.world().get::<SkillCooldowns>(player).unwrap().remaining(SkillKind::PowerStrike), 6.0);
+        assert_eq!(app.world().resource::<Used>().0, vec![(SkillKind::PowerStrike, big(0.0))]);
+    }
+
+    #[test]
+    fn test_cast_needs_cooldown_and_mana() {
+        let mut app = skill_app(false);
+        let (player, enemy) = spawn_fighters(&mut app, 100.0);
+        // The second request in the same frame finds the skill cooling down
+        app.world_mut().send_event(SkillCastEvent { skill: SkillKind::PowerStrike });
+        app.world_mut().send_event(SkillCastEvent { skill: SkillKind::PowerStrike });
+        app.update();
+        assert_eq!(app.world().get::<CurrentHp>(enemy).unwrap().0, big(62.5));
+
+        app.world_mut().get_mut::<SkillCooldowns>(player).unwrap().0 = [0.0; SkillKind::COUNT];
+        app.world_mut().get_mut::<Mana>(player).unwrap().current = 20.0;
+        app.world_mut().send_event(SkillCastEvent { skill: SkillKind::PowerStrike });
+        app.update();
+
+        assert_eq!(app.world().get::<CurrentHp>(enemy).unwrap().0, big(62.5));
+        assert_eq!(app.world().resource::<Used>().0.len(), 1);
+    }
+
+    #[test]
+    fn test_heal_stops_at_max_hp() {
+        let mut app = skill_app(false);
+        let (player, _) = spawn_fighters(&mut app, 90.0);
+        app.world_mut().send_event(SkillCastEvent { skill: SkillKind::Heal });
+        app.update();
+
+        assert_eq!(app.world().get::<CurrentHp>(player).unwrap().0, big(100.0));
+        assert_eq!(app.world().resource::<Used>().0, vec![(SkillKind::Heal, big(10.0))]);
+    }
+
+    #[test]
+    fn test_shield_absorbs_damage_before_hp() {
+        let mut app = skill_app(false);
+        let (player, enemy) = spawn_fighters(&mut app, 100.0);
+        app.world_mut().send_event(SkillCastEvent { skill: SkillKind::Shield });
+        app.update();
+        assert_eq!(app.world().get::<Shielded>(player).unwrap().absorb, big(25.0));
+
+        let hit = |app: &mut App, damage: f64| {
+            app.world_mut().send_event(AttackEvent { attacker: enemy, target: player, damage: big(damage), critical: false, missed: false });
+            app.update();
+        };
+        hit(&mut app, 10.0);
+        assert_eq!(app.world().get::<CurrentHp>(player).unwrap().0, big(100.0));
+        assert_eq!(app.world().get::<Shielded>(player).unwrap().absorb, big(15.0));
+
+        // The rest of the shield breaks and the overflow reaches HP
+        hit(&mut app, 20.0);
+        assert_eq!(app.world().get::<CurrentHp>(player).unwrap().0, big(95.0));
+        assert!(app.world().get::<Shielded>(player).is_none());
+    }
+
+    #[test]
+    fn test_auto_cast_fires_only_when_enabled() {
+        let mut app = skill_app(false);
+        let (player, _) = spawn_fighters(&mut app, 100.0);
+        app.update();
+        assert!(app.world().resource::<Used>().0.is_empty());
+
+        app.world_mut().resource_mut::<AutomationConfig>().auto_cast_enabled = true;
+        app.update();
+        // Heal waits until the missing HP is worth it
+        let used: Vec<SkillKind> = app.world().resource::<Used>().0.iter().map(|(skill, _)| *skill).collect();
+        assert_eq!(used, vec![SkillKind::PowerStrike, SkillKind::Shield]);
+        assert_eq!(app.world().get::<Mana>(player).unwrap().current, 20.0);
+
+        // One second later mana and cooldowns have moved on, but nothing is affordable yet
+        (0..4).for_each(|_| app.update());
+        let mana = app.world().get::<Mana>(player).unwrap().current;
+        assert!((mana - 25.0).abs() < 1e-6);
+        let cooldown = app.world().get::<SkillCooldowns>(player).unwrap().remaining(SkillKind::PowerStrike);
+        assert!((cooldown - 5.0).abs() < 1e-4);
+        assert_eq!(app.world().resource::<Used>().0.len(), 2);
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::events::*;
+    use crate::systems::statistics_tracking::statistics_system;
+    use crate::systems::offline_progress::simulate_offline_progress;
+    use crate::tests::fixtures::save_with_levels;
+
+    fn tracking_app() -> App {
+        let mut app = App::new();
+        app.add_event::<AttackEvent>()
+            .add_event::<EnemyDeathEvent>()
+            .add_event::<PlayerDeathEvent>()
+            .add_event::<ExpGainEvent>()
+            .add_event::<StatusEffectEvent>()
+            .init_resource::<Time>()
+            .init_resource::<Statistics>()
+            .init_resource::<RebirthCurrency>()
+            .init_resource::<RebirthShop>()
+            .init_resource::<Achievements>()
+            .init_resource::<BalanceConfig>()
+            .add_systems(Update, statistics_system);
+        app
+    }
+
+    #[test]
+    fn test_records_update_run_and_lifetime() {
+        let mut statistics = Statistics::default();
+
+        statistics.record_kill(3);
+        statistics.record_kill(2);
+        statistics.record_exp(BigFloat::from(50.0));
+        statistics.record_damage_dealt(BigFloat::from(12.0));
+
+        for totals in [&statistics.run, &statistics.lifetime] {
+            assert_eq!(totals.kills, 2);
+            assert_eq!(totals.highest_enemy, 3);
+            assert_eq!(totals.exp_earned, BigFloat::from(50.0));
+            assert_eq!(totals.damage_dealt, BigFloat::from(12.0));
+        }
+    }
+
+    #[test]
+    fn test_rebirth_starts_a_fresh_run() {
+        let mut statistics = Statistics::default();
+        statistics.record_kill(5);
+
+        statistics.sync_rebirths(0);
+        assert_eq!(statistics.run.kills, 1);
+
+        statistics.sync_rebirths(1);
+        assert_eq!(statistics.rebirths, 1);
+        assert_eq!(statistics.run, StatTotals::default());
+        assert_eq!(statistics.lifetime.kills, 1);
+        assert_eq!(statistics.lifetime.highest_enemy, 5);
+    }
+
+    #[test]
+    fn test_system_splits_damage_dealt_and_taken() {
+        let mut app = tracking_app();
+        let player = app.world_mut().spawn((Player, CurrentHp(BigFloat::from(100.0)))).id();
+        let enemy = app.world_mut().spawn(Enemy).id();
+        app.world_mut().send_event(AttackEvent { attacker: player, target: enemy, damage: BigFloat::from(15.0), critical: false, missed: false });
+        app.world_mut().send_event(AttackEvent { attacker: enemy, target: player, damage: BigFloat::from(4.0), critical: false, missed: false });
+        app.world_mut().send_event(EnemyDeathEvent {
+            enemy_entity: enemy,
+            enemy_number: 7,
+            exp_reward: BigFloat::from(10.0),
+        });
+        app.world_mut().send_event(PlayerDeathEvent { player_entity: player });
+        app.update();
+
+        let statistics = app.world().resource::<Statistics>();
+        assert_eq!(statistics.run.damage_dealt, BigFloat::from(15.0));
+        assert_eq!(statistics.run.damage_taken, BigFloat::from(4.0));
+        assert_eq!(statistics.lifetime.kills, 1);
+        assert_eq!(statistics.lifetime.highest_enemy, 7);
+        assert_eq!(statistics.deaths, 1);
+    }
+
+    #[test]
+    fn test_system_counts_damage_over_time() {
+        let mut app = tracking_app();
+        let player = app.world_mut().spawn((Player, CurrentHp(BigFloat::from(100.0)))).id();
+        let enemy = app.world_mut().spawn(Enemy).id();
+        app.world_mut().send_event(StatusEffectEvent { target: enemy, kind: StatusKind::Poison, damage: Some(BigFloat::from(3.0)) });
+        app.world_mut().send_event(StatusEffectEvent { target: enemy, kind: StatusKind::Burn, damage: None });
+        app.world_mut().send_event(StatusEffectEvent { target: player, kind: StatusKind::Burn, damage: Some(BigFloat::from(2.0)) });
+        app.update();
+
+        let statistics = app.world().resource::<Statistics>();
+        assert_eq!(statistics.run.damage_dealt, BigFloat::from(3.0));
+        assert_eq!(statistics.run.damage_taken, BigFloat::from(2.0));
+    }
+
+    #[test]
+    fn test_system_resets_run_after_rebirth() {
+        let mut app = tracking_app();
+        app.world_mut().resource_mut::<Statistics>().record_kill(4);
+        app.world_mut().resource_mut::<RebirthCurrency>().rebirth_count = 1;
+        app.update();
+
+        let statistics = app.world().resource::<Statistics>();
+        assert_eq!(statistics.rebirths, 1);
+        assert_eq!(statistics.run.kills, 0);
+        assert_eq!(statistics.lifetime.kills, 1);
+    }
+
+    #[test]
+    fn test_offline_progress_updates_statistics() {
+        let mut save = save_with_levels(5, true, false);
+
+        let report = simulate_offline_progress(&mut save, &BalanceConfig::default(), 10);
+
+        let statistics = Statistics::from(&save.statistics);
+        assert!(report.enemies_killed > 0);
+        assert_eq!(statistics.lifetime.kills, report.enemies_killed);
+        assert_eq!(statistics.run.highest_enemy, report.highest_enemy - 1);
+        assert_eq!(statistics.lifetime.exp_earned, report.exp_earned);
+        assert!(statistics.lifetime.damage_dealt > BigFloat::from(0.0));
+    }
+}
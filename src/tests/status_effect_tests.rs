@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use bevy::prelude::*;
+    use bevy::time::{TimePlugin, TimeUpdateStrategy};
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::events::*;
+    use crate::systems::combat_core::{attack_cooldown_system, damage_application_system, enemy_attack_system, player_attack_system};
+    use crate::systems::status_effect_tracking::{status_effect_application_system, status_effect_tick_system};
+    use crate::tests::fixtures::certain_hit;
+    use crate::CombatState;
+
+    fn big(value: f64) -> BigFloat {
+        BigFloat::from(value)
+    }
+
+    // In the dungeon with time advancing `step` per update (the first update only starts the clock;
+    // virtual time caps a step at 250ms)
+    fn timed_app(step: Duration) -> App {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), TimePlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(step))
+            .insert_resource(CombatState { is_game_over: false, in_dungeon: true })
+            .add_event::<AttackEvent>()
+            .add_event::<StatusEffectEvent>()
+            .add_event::<DeathEvent>();
+        app
+    }
+
+    #[test]
+    fn test_damage_over_time_ticks_once_per_second() {
+        let mut dot = DamageOverTime::new(big(10.0), 3.0);
+        assert_eq!(dot.ticks_left, 3);
+        assert_eq!(dot.advance(0.5), 0);
+        assert_eq!(dot.advance(0.5), 1);
+        assert!((dot.remaining_secs() - 2.0).abs() < 1e-6);
+        // A long frame catches up, but never past the last tick
+        assert_eq!(dot.advance(5.0), 2);
+        assert!(dot.expired());
+        assert_eq!(dot.remaining_secs(), 0.0);
+    }
+
+    #[derive(Resource, Default)]
+    struct Deaths(u32);
+
+    fn count_deaths(mut events: EventReader<DeathEvent>, mut deaths: ResMut<Deaths>) {
+        deaths.0 += events.read().count() as u32;
+    }
+
+    #[test]
+    fn test_poison_damages_until_death_and_wears_off() {
+        let mut app = timed_app(Duration::from_millis(250));
+        app.init_resource::<Deaths>()
+            .add_systems(Update, (status_effect_tick_system, count_deaths).chain());
+        let enemy = app.world_mut().spawn((Enemy, CurrentHp(big(25.0)), Poisoned(DamageOverTime::new(big(10.0), 4.0)))).id();
+        let run_secs = |app: &mut App, secs: u32| (0..secs * 4).for_each(|_| app.update());
+
+        app.update();
+        run_secs(&mut app, 1);
+        assert_eq!(app.world().get::<CurrentHp>(enemy).unwrap().0, big(15.0));
+        run_secs(&mut app, 2);
+        assert_eq!(app.world().get::<CurrentHp>(enemy).unwrap().0, big(0.0));
+        assert_eq!(app.world().resource::<Deaths>().0, 1);
+
+        // The last tick hits a dead target: no damage and no second death
+        run_secs(&mut app, 1);
+        assert_eq!(app.world().resource::<Deaths>().0, 1);
+        assert!(app.world().get::<Poisoned>(enemy).is_none());
+    }
+
+    #[test]
+    fn test_stun_pauses_and_slow_scales_cooldown() {
+        let mut app = timed_app(Duration::from_millis(100));
+        app.add_systems(Update, (status_effect_tick_system, attack_cooldown_system).chain());
+        let mut spawn = || app.world_mut().spawn((CurrentHp(big(10.0)), AttackCooldown(1000.0), CombatSpeed(big(1.0)))).id();
+        let (stunned, slowed, normal) = (spawn(), spawn(), spawn());
+        app.world_mut().entity_mut(stunned).insert(Stunned { remaining_secs: 0.15 });
+        app.world_mut().entity_mut(slowed).insert(Slowed { factor: 0.5, remaining_secs: 5.0 });
+
+        app.update();
+        app.update();
+        let cooldown = |app: &App, entity| app.world().get::<AttackCooldown>(entity).unwrap().0;
+        assert_eq!(cooldown(&app, stunned), 1000.0);
+        assert!((cooldown(&app, slowed) - 950.0).abs() < 1e-3);
+        assert!((cooldown(&app, normal) - 900.0).abs() < 1e-3);
+
+        // The stun runs out on the next step and the cooldown drains again
+        app.update();
+        assert!(app.world().get::<Stunned>(stunned).is_none());
+        assert!((cooldown(&app, stunned) - 900.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_landed_hits_apply_effects_and_stunned_enemies_do_not_attack() {
+        let mut balance = BalanceConfig::default();
+        balance.status.player = OnHitEffects {
+            poison: StatusEffectBalance::new(1.0, 5.0, 0.2),
+            stun: StatusEffectBalance::new(1.0, 2.0, 0.0),
+            ..default()
+        };
+        let mut app = timed_app(Duration::from_millis(10));
+        app.insert_resource(balance)
+            .init_resource::<RebirthShop>()
+            .init_resource::<Achievements>()
+            .init_resource::<GameRng>()
+            .add_systems(Update, (
+                player_attack_system,
+                damage_application_system,
+                status_effect_application_system,
+                enemy_attack_system,
+            ).chain());
+        app.world_mut().spawn((Player, CurrentHp(big(100.0)), CombatAttack(big(20.0)), CombatDefense(big(0.0)), certain_hit()));
+        let enemy = app.world_mut()
+            .spawn((Enemy, CurrentHp(big(100.0)), CombatAttack(big(10.0)), CombatDefense(big(5.0)), certain_hit()))
+            .id();
+        app.update();
+
+        // Subtractive damage 15; poison deals 20% of it per second
+        let poisoned = app.world().get::<Poisoned>(enemy).unwrap();
+        assert_eq!(poisoned.0.damage_per_tick, big(3.0));
+        assert_eq!(poisoned.0.ticks_left, 5);
+        assert!(app.world().get::<Stunned>(enemy).is_some());
+
+        let events = app.world().resource::<Events<AttackEvent>>();
+        let attackers: Vec<Entity> = events.iter_current_update_events().map(|attack| attack.attacker).collect();
+        assert!(!attackers.contains(&enemy));
+        let applied: Vec<StatusKind> = app.world().resource::<Events<StatusEffectEvent>>()
+            .iter_current_update_events().map(|status| status.kind).collect();
+        assert_eq!(applied, vec![StatusKind::Poison, StatusKind::Stun]);
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::schedule::{LogLevel, ScheduleBuildSettings};
+    use bevy::prelude::*;
+    use too_big_float::BigFloat;
+    use crate::tests::fixtures::{game_app, manual_automation, startup_config};
+    use crate::{BalanceConfig, DEFAULT_SEED, NumberNotation, ReportFormat, StartupConfig, SweepRange, SweepRanges, SweepResult, UpgradeStrategy};
+    use crate::plugins::sweep::{run_sweep_case, write_sweep_results};
+
+    fn ranges() -> SweepRanges {
+        SweepRanges {
+            level: SweepRange::single(1),
+            experience: SweepRange::single(0),
+            hp_level: SweepRange::single(0),
+            attack_level: SweepRange::single(0),
+            defense_level: SweepRange::single(0),
+            speed_level: SweepRange::single(0),
+        }
+    }
+
+    #[test]
+    fn test_range_parsing() {
+        assert_eq!("3".parse::<SweepRange>().unwrap(), SweepRange::single(3));
+        assert_eq!("0..4".parse::<SweepRange>().unwrap().values(), vec![0, 1, 2, 3, 4]);
+        assert_eq!("0..10:5".parse::<SweepRange>().unwrap().values(), vec![0, 5, 10]);
+        assert_eq!("1..8:3".parse::<SweepRange>().unwrap().values(), vec![1, 4, 7]);
+
+        assert!("5..1".parse::<SweepRange>().is_err());
+        assert!("0..4:0".parse::<SweepRange>().is_err());
+        assert!("a..4".parse::<SweepRange>().is_err());
+        assert!("".parse::<SweepRange>().is_err());
+    }
+
+    #[test]
+    fn test_range_display_round_trips() {
+        for text in ["7", "0..4", "0..10:5"] {
+            assert_eq!(text.parse::<SweepRange>().unwrap().to_string(), text);
+        }
+    }
+
+    #[test]
+    fn test_configs_cover_every_combination() {
+        let mut ranges = ranges();
+        ranges.hp_level = "0..2".parse().unwrap();
+        ranges.speed_level = "0..1".parse().unwrap();
+
+        let configs = ranges.configs(30);
+        assert_eq!(configs.len(), 6);
+        assert!(configs.iter().all(|config| config.duration == 30));
+
+        let levels: Vec<(u32, u32)> = configs.iter().map(|c| (c.hp_level, c.speed_level)).collect();
+        assert_eq!(levels, vec![(0, 0), (0, 1), (1, 0), (1, 1), (2, 0), (2, 1)]);
+    }
+
+    #[test]
+    fn test_sweep_case_tracks_progress() {
+        let config = StartupConfig {
+            level: 1,
+            experience: 0,
+            hp_level: 0,
+            attack_level: 0,
+            defense_level: 0,
+            speed_level: 0,
+            duration: 30,
+        };
+        let result = run_sweep_case(&BalanceConfig::default(), &UpgradeStrategy::default(), DEFAULT_SEED, true, config);
+
+        assert!(result.auto_cast);
+        assert!(result.highest_enemy > 1);
+        assert_eq!(result.deaths + result.boss_failures > 0, result.time_to_wall_secs.is_some());
+        if let Some(wall) = result.time_to_wall_secs {
+            assert!(wall <= 30.0);
+        }
+    }
+
+    #[test]
+    fn test_same_seed_replays_the_same_run() {
+        let config = StartupConfig {
+            level: 1,
+            experience: 0,
+            hp_level: 0,
+            attack_level: 0,
+            defense_level: 0,
+            speed_level: 0,
+            duration: 60,
+        };
+        let run = |seed| run_sweep_case(&BalanceConfig::default(), &UpgradeStrategy::default(), seed, true, config.clone());
+
+        assert_eq!(run(42), run(42));
+    }
+
+    #[test]
+    fn test_sweep_case_can_run_without_auto_cast() {
+        let config = StartupConfig { duration: 10, ..startup_config() };
+        let result = run_sweep_case(&BalanceConfig::default(), &UpgradeStrategy::default(), DEFAULT_SEED, false, config);
+
+        assert!(!result.auto_cast);
+        assert!(result.to_csv_row().contains(",false,"));
+    }
+
+    #[test]
+    fn test_game_systems_run_in_a_fixed_order() {
+        // Any pair of conflicting systems left unordered would let the executor reorder them
+        let mut app = game_app(startup_config(), manual_automation());
+        app.edit_schedule(Update, |schedule| {
+            schedule.set_build_settings(ScheduleBuildSettings {
+                ambiguity_detection: LogLevel::Error,
+                ..default()
+            });
+        });
+        app.update();
+    }
+
+    #[test]
+    fn test_result_output_formats() {
+        let mut result = SweepResult {
+            level: 1,
+            experience: 0,
+            hp_level: 2,
+            attack_level: 0,
+            defense_level: 0,
+            speed_level: 1,
+            damage_formula: "ratio",
+            upgrade_strategy: "Round Robin",
+            auto_cast: false,
+            highest_enemy: 9,
+            time_to_wall_secs: Some(42.5),
+            deaths: 3,
+            boss_failures: 2,
+            rebirth_points_gained: Some(BigFloat::from(21.0)),
+        };
+
+        assert_eq!(result.to_csv_row(), "1,0,2,0,0,1,ratio,Round Robin,false,9,42.50,3,2,21");
+        let columns = SweepResult::CSV_HEADER.split(',').count();
+        assert_eq!(result.to_csv_row().split(',').count(), columns);
+
+        let json: serde_json::Value = serde_json::from_str(&result.to_json_line()).unwrap();
+        assert_eq!(json["highest_enemy"], 9);
+        assert_eq!(json["upgrade_strategy"], "Round Robin");
+        assert_eq!(json["time_to_wall_secs"], 42.5);
+        assert_eq!(json["auto_cast"], false);
+
+        // Text goes through the number notation
+        result.rebirth_points_gained = Some(BigFloat::from(1.5e6));
+        let mut text = Vec::new();
+        write_sweep_results(&mut text, ReportFormat::Text, &[result], NumberNotation::Suffix).unwrap();
+        let text = String::from_utf8(text).unwrap();
+        assert_eq!(text.lines().count(), 2);
+        assert!(text.contains("Time to Wall") && text.contains("42.5s") && text.contains("Round Robin"));
+        assert!(text.contains("Auto Cast") && text.contains("off"));
+        assert!(text.contains("1.50M"));
+    }
+}
@@ -0,0 +1,188 @@
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use too_big_float::BigFloat;
+    use crate::components::*;
+    use crate::systems::initialization::enemy_stats;
+    use crate::systems::upgrades::{choose_upgrade, UpgradeOption};
+    use crate::tests::fixtures::{game_app, manual_automation, startup_config};
+    use crate::StartupConfig;
+
+    fn option(level: u32, cost: f64, value: f64) -> UpgradeOption {
+        UpgradeOption {
+            level,
+            cost: BigFloat::from(cost),
+            value: BigFloat::from(value),
+            next_value: BigFloat::from(value * 1.15),
+        }
+    }
+
+    // All stats in player_init_system's starting state
+    fn options(costs: [f64; StatKind::COUNT]) -> [UpgradeOption; StatKind::COUNT] {
+        [
+            option(0, costs[0], 100.0),
+            option(0, costs[1], 10.0),
+            option(0, costs[2], 5.0),
+            option(0, costs[3], 1.0),
+            option(0, costs[4], 0.05),
+            option(0, costs[5], 1.5),
+            option(0, costs[6], 0.05),
+            option(0, costs[7], 1.0),
+        ]
+    }
+
+    fn choose(strategy: &UpgradeStrategy, options: &[UpgradeOption; StatKind::COUNT], experience: f64) -> Option<usize> {
+        let balance = BalanceConfig::default();
+        let enemy = enemy_stats(&balance, 1);
+        choose_upgrade(strategy, options, BigFloat::from(experience), &enemy, &balance.damage)
+    }
+
+    #[test]
+    fn test_cheapest_first_picks_lowest_affordable_cost() {
+        let strategy = UpgradeStrategy::new(UpgradeStrategyKind::CheapestFirst);
+        let options = options([30.0, 12.0, 8.0, 20.0, 40.0, 40.0, 40.0, 40.0]);
+
+        assert_eq!(choose(&strategy, &options, 100.0), Some(2));
+        assert_eq!(choose(&strategy, &options, 5.0), None);
+    }
+
+    #[test]
+    fn test_round_robin_waits_for_its_turn() {
+        let mut strategy = UpgradeStrategy::new(UpgradeStrategyKind::RoundRobin);
+        strategy.bought(0);
+        let options = options([10.0, 50.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0]);
+
+        // Attack is next; cheaper stats do not jump the queue
+        assert_eq!(choose(&strategy, &options, 20.0), None);
+        assert_eq!(choose(&strategy, &options, 50.0), Some(1));
+
+        strategy.bought(StatKind::COUNT - 1);
+        assert_eq!(strategy.round_robin_next, 0);
+    }
+
+    #[test]
+    fn test_weighted_keeps_level_ratios() {
+        let mut strategy = UpgradeStrategy::new(UpgradeStrategyKind::Weighted);
+        strategy.weights = [2.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let mut options = options([10.0; StatKind::COUNT]);
+        options[0].level = 3;
+        options[1].level = 1;
+        options[2].level = 2;
+
+        // HP is at 3/2, Attack at 1/1, Defense at 2/1; Speed and the ratings are never bought
+        assert_eq!(choose(&strategy, &options, 100.0), Some(1));
+
+        options[1].level = 2;
+        assert_eq!(choose(&strategy, &options, 100.0), Some(0));
+    }
+
+    #[test]
+    fn test_set_weights_rejects_unusable_input() {
+        let mut strategy = UpgradeStrategy::new(UpgradeStrategyKind::Weighted);
+        assert!(strategy.set_weights(&[0.0; 4]).is_err());
+        assert!(strategy.set_weights(&[1.0, -1.0, 0.0, 0.0]).is_err());
+        assert!(strategy.set_weights(&[1.0, 1.0, 1.0]).is_err());
+        assert!(strategy.set_weights(&[1.0, f64::NAN, 0.0, 0.0]).is_err());
+
+        // Four weights leave the ratings at their defaults
+        let ratings = strategy.weights[4..].to_vec();
+        assert!(strategy.set_weights(&[0.0, 2.0, 0.0, 0.0]).is_ok());
+        assert_eq!(strategy.weights[..4], [0.0, 2.0, 0.0, 0.0]);
+        assert_eq!(strategy.weights[4..], ratings[..]);
+    }
+
+    #[test]
+    fn test_capped_crit_chance_is_never_picked() {
+        let mut options = options([10.0, 50.0, 50.0, 50.0, 1.0, 50.0, 50.0, 50.0]);
+        options[4].value = BigFloat::from(1.0);
+
+        let cheapest = UpgradeStrategy::new(UpgradeStrategyKind::CheapestFirst);
+        assert_eq!(choose(&cheapest, &options, 20.0), Some(0));
+
+        // Round robin moves on to the next stat instead of waiting forever
+        let mut round_robin = UpgradeStrategy::new(UpgradeStrategyKind::RoundRobin);
+        round_robin.round_robin_next = 4;
+        assert_eq!(choose(&round_robin, &options, 100.0), Some(5));
+
+        let mut weighted = UpgradeStrategy::new(UpgradeStrategyKind::Weighted);
+        weighted.weights = [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0];
+        assert_eq!(choose(&weighted, &options, 100.0), None);
+
+        let efficiency = UpgradeStrategy::new(UpgradeStrategyKind::MaxEfficiency);
+        assert_ne!(choose(&efficiency, &options, 100.0), Some(4));
+    }
+
+    #[test]
+    fn test_max_efficiency_prefers_bigger_gain_per_exp() {
+        let strategy = UpgradeStrategy::new(UpgradeStrategyKind::MaxEfficiency);
+
+        let cheap_hp = [1.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0];
+        // Equal costs: speed counts twice (shorter cooldown, drained faster)
+        assert_eq!(choose(&strategy, &options([10.0; StatKind::COUNT]), 100.0), Some(3));
+        // A much cheaper stat wins despite the smaller gain
+        assert_eq!(choose(&strategy, &options(cheap_hp), 100.0), Some(0));
+        // The pick is waited for rather than replaced
+        assert_eq!(choose(&strategy, &options(cheap_hp), 0.5), None);
+    }
+
+    #[test]
+    fn test_max_efficiency_compares_costs_beyond_f64() {
+        let strategy = UpgradeStrategy::new(UpgradeStrategyKind::MaxEfficiency);
+        let huge = BigFloat::from(1e300) * BigFloat::from(1e300);
+        let scaled = |costs: [f64; StatKind::COUNT]| {
+            let mut options = options(costs);
+            for option in options.iter_mut() {
+                option.cost = option.cost * huge;
+            }
+            options
+        };
+        let choose = |options: &[UpgradeOption; StatKind::COUNT]| {
+            let balance = BalanceConfig::default();
+            let enemy = enemy_stats(&balance, 1);
+            choose_upgrade(&strategy, options, BigFloat::from(100.0) * huge, &enemy, &balance.damage)
+        };
+
+        assert_eq!(choose(&scaled([10.0; StatKind::COUNT])), Some(3));
+        assert_eq!(choose(&scaled([1.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0])), Some(0));
+    }
+
+    fn strategy_app(kind: UpgradeStrategyKind, experience: u64) -> App {
+        let mut app = game_app(StartupConfig { experience, ..startup_config() }, manual_automation());
+        app.insert_resource(UpgradeStrategy::new(kind));
+        app.update();
+        app.update();
+        app
+    }
+
+    fn levels(app: &mut App) -> [u32; 4] {
+        let world = app.world_mut();
+        let hp = world.query_filtered::<&UpgradeLevel, With<UpgradeableHp>>().single(world).unwrap().0;
+        let attack = world.query_filtered::<&UpgradeLevel, With<UpgradeableAttack>>().single(world).unwrap().0;
+        let defense = world.query_filtered::<&UpgradeLevel, With<UpgradeableDefense>>().single(world).unwrap().0;
+        let speed = world.query_filtered::<&UpgradeLevel, With<UpgradeableSpeed>>().single(world).unwrap().0;
+        [hp, attack, defense, speed]
+    }
+
+    #[test]
+    fn test_round_robin_spends_in_stat_order() {
+        // 10 + 10 + 10 buys one level each of HP, Attack and Defense; Speed's 10 is not affordable
+        let mut app = strategy_app(UpgradeStrategyKind::RoundRobin, 35);
+
+        assert_eq!(levels(&mut app), [1, 1, 1, 0]);
+        assert_eq!(app.world().resource::<UpgradeStrategy>().round_robin_next, 3);
+    }
+
+    #[test]
+    fn test_weighted_strategy_skips_zero_weight_stats() {
+        let mut app = strategy_app(UpgradeStrategyKind::Weighted, 0);
+        app.world_mut().resource_mut::<UpgradeStrategy>().weights = [1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let mut query = app.world_mut().query_filtered::<&mut Experience, With<Player>>();
+        query.single_mut(app.world_mut()).unwrap().0 = BigFloat::from(1000.0);
+        app.update();
+
+        let [hp, attack, defense, speed] = levels(&mut app);
+        assert!(hp > 0 && attack > 0);
+        assert!(hp.abs_diff(attack) <= 1);
+        assert_eq!((defense, speed), (0, 0));
+    }
+}
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+use crate::{Achievements, AchievementsText, DisplaySettings, ACHIEVEMENTS};
+
+// Achievement list with progress totals for the Achievements tab
+pub fn achievements_ui_system(
+    achievements: Res<Achievements>,
+    settings: Res<DisplaySettings>,
+    mut text_query: Query<(&mut Text, Ref<AchievementsText>)>,
+) {
+    let Ok((mut text, marker)) = text_query.single_mut() else { return };
+    if !achievements.is_changed() && !settings.is_changed() && !marker.is_added() {
+        return;
+    }
+
+    let mut lines = vec![
+        format!("Unlocked: {}/{}", achievements.unlocked_count(), ACHIEVEMENTS.len()),
+        format!(
+            "Bonuses: EXP x{}, damage x{}",
+            settings.notation.format(achievements.exp_multiplier()),
+            settings.notation.format(achievements.damage_multiplier()),
+        ),
+        String::new(),
+    ];
+    for (achievement, unlocked) in ACHIEVEMENTS.iter().zip(achievements.unlocked) {
+        lines.push(format!(
+            "[{}] {} - {} ({})",
+            if unlocked { "x" } else { " " },
+            achievement.name,
+            achievement.describe(),
+            achievement.reward.describe(),
+        ));
+    }
+    text.0 = lines.join("\n");
+}
@@ -0,0 +1,61 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use crate::{CombatLog, CombatLogText, CombatLogPanel, CombatLogFilterButton};
+
+// Pixels scrolled per wheel line
+const LINE_HEIGHT: f32 = 16.0;
+
+// Render the filtered combat log, newest entry first
+pub fn combat_log_ui_system(
+    log: Res<CombatLog>,
+    mut text_query: Query<(&mut Text, Ref<CombatLogText>)>,
+) {
+    let Ok((mut text, marker)) = text_query.single_mut() else { return };
+    if !log.is_changed() && !marker.is_added() {
+        return;
+    }
+
+    let lines: Vec<&str> = log.visible().map(|entry| entry.message.as_str()).collect();
+    text.0 = if lines.is_empty() {
+        "No entries".to_string()
+    } else {
+        lines.join("\n")
+    };
+}
+
+pub fn combat_log_filter_button_system(
+    mut button_query: Query<(Ref<Interaction>, &mut BackgroundColor, &CombatLogFilterButton), With<Button>>,
+    mut log: ResMut<CombatLog>,
+) {
+    for (interaction, mut background_color, button) in &mut button_query {
+        if *interaction == Interaction::Pressed && interaction.is_changed() {
+            log.toggle(button.kind);
+        }
+        *background_color = BackgroundColor(match (*interaction, log.is_shown(button.kind)) {
+            (Interaction::Hovered, _) => Color::srgb(0.6, 0.6, 0.6),
+            (_, true) => Color::srgb(0.2, 0.6, 0.2),
+            (_, false) => Color::srgb(0.4, 0.4, 0.4),
+        });
+    }
+}
+
+// Mouse wheel scrolling for the log panel while the cursor is over it
+pub fn combat_log_scroll_system(
+    mut wheel_events: EventReader<MouseWheel>,
+    mut panel_query: Query<(&Interaction, &mut ScrollPosition), With<CombatLogPanel>>,
+) {
+    let Ok((interaction, mut scroll)) = panel_query.single_mut() else {
+        wheel_events.clear();
+        return;
+    };
+    for wheel in wheel_events.read() {
+        if *interaction == Interaction::None {
+            continue;
+        }
+        let dy = match wheel.unit {
+            MouseScrollUnit::Line => wheel.y * LINE_HEIGHT,
+            MouseScrollUnit::Pixel => wheel.y,
+        };
+        scroll.offset_y = (scroll.offset_y - dy).max(0.0);
+    }
+}
@@ -1,41 +1,101 @@
 use bevy::prelude::*;
+use too_big_float::BigFloat;
 use crate::{
-    Player, Enemy, Experience, CurrentHp, CombatAttack, CombatDefense, CombatSpeed,
-    EnemyNumber, ExpReward, StatsText, CombatText
+    Player, Enemy, Experience, Level, LifetimeExperience, BalanceConfig, CurrentHp, CombatAttack, CombatDefense, CombatSpeed,
+    EnemyNumber, EnemyArchetype, EnrageTimer, ExpReward, StatsText, CombatText, DisplaySettings,
+    CombatCritChance, CombatCritMultiplier, CombatEvasion, CombatAccuracy, NumberNotation, StatusEffects, StatusEffectsItem,
+    Mana, Shielded,
 };
 
+type Ratings<'a> = (&'a CombatCritChance, &'a CombatCritMultiplier, &'a CombatEvasion, &'a CombatAccuracy);
+
+// "Crit: 5.00% x1.50\nEvasion: 0.05 / Accuracy: 1.00"
+fn ratings_text((crit_chance, crit_multiplier, evasion, accuracy): Ratings, notation: NumberNotation) -> String {
+    let certain = BigFloat::from(1.0);
+    let crit_chance = if crit_chance.0 > certain { certain } else { crit_chance.0 };
+    format!(
+        "Crit: {}% x{}\nEvasion: {} / Accuracy: {}",
+        notation.format(crit_chance * BigFloat::from(100.0)),
+        notation.format(crit_multiplier.0),
+        notation.format(evasion.0),
+        notation.format(accuracy.0),
+    )
+}
+
+// "\nStatus: Poison 3.2s, Slow 1.0s", or nothing without active effects
+fn status_text(effects: StatusEffectsItem) -> String {
+    let active = effects.active();
+    if active.is_empty() {
+        return String::new();
+    }
+    let effects: Vec<String> = active.iter().map(|(kind, secs)| format!("{} {:.1}s", kind.label(), secs)).collect();
+    format!("\nStatus: {}", effects.join(", "))
+}
+
+// "\nMana: 70/100 | Shield 25.00 (6.0s)"; only combatants in the dungeon carry mana
+fn mana_text(mana: Option<&Mana>, shielded: Option<&Shielded>, notation: NumberNotation) -> String {
+    let mut text = mana.map_or(String::new(), |mana| format!("\nMana: {:.0}/{:.0}", mana.current, mana.max));
+    if let Some(shielded) = shielded {
+        text += &format!(" | Shield {} ({:.1}s)", notation.format(shielded.absorb), shielded.remaining_secs);
+    }
+    text
+}
+
+#[allow(clippy::type_complexity)]
 pub fn update_ui_system(
-    player_query: Query<(&Experience, &CurrentHp, &CombatAttack, &CombatDefense, &CombatSpeed), With<Player>>,
-    enemy_query: Query<(&EnemyNumber, &CurrentHp, &CombatAttack, &CombatDefense, &CombatSpeed, &ExpReward), With<Enemy>>,
+    player_query: Query<(&Experience, &Level, &LifetimeExperience, &CurrentHp, &CombatAttack, &CombatDefense, &CombatSpeed, Ratings, StatusEffects, Option<&Mana>, Option<&Shielded>), With<Player>>,
+    enemy_query: Query<(&EnemyNumber, &EnemyArchetype, &CurrentHp, &CombatAttack, &CombatDefense, &CombatSpeed, Ratings, &ExpReward, Option<&EnrageTimer>, StatusEffects), With<Enemy>>,
     mut stats_text_query: Query<&mut Text, (With<StatsText>, Without<CombatText>)>,
     mut combat_text_query: Query<&mut Text, (With<CombatText>, Without<StatsText>)>,
+    balance: Res<BalanceConfig>,
+    settings: Res<DisplaySettings>,
 ) {
+    let number = |value| settings.notation.format(value);
+
     // Update player stats display
-    if let Ok((exp, hp, attack, defense, speed)) = player_query.single() {
+    if let Ok((exp, level, lifetime_exp, hp, attack, defense, speed, ratings, effects, mana, shielded)) = player_query.single() {
         if let Ok(mut stats_text) = stats_text_query.single_mut() {
+            let next_level_exp = balance.leveling.exp_to_reach(level.0 + 1);
             let stats_info = format!(
-                "Player Stats:\nHP: {:.2}\nAttack: {:.2}\nDefense: {:.2}\nSpeed: {:.2}\nEXP: {:.2}",
-                hp.0.to_f64().unwrap_or(0.0),
-                attack.0.to_f64().unwrap_or(0.0),
-                defense.0.to_f64().unwrap_or(0.0),
-                speed.0.to_f64().unwrap_or(0.0),
-                exp.0.to_f64().unwrap_or(0.0),
+                "Player Stats:\nLevel: {} ({}/{} XP)\nHP: {}{}{}\nAttack: {}\nDefense: {}\nSpeed: {}\n{}\nEXP: {}",
+                level.0,
+                settings.notation.format_whole(lifetime_exp.0),
+                settings.notation.format_whole(next_level_exp),
+                number(hp.0),
+                status_text(effects),
+                mana_text(mana, shielded, settings.notation),
+                number(attack.0),
+                number(defense.0),
+                number(speed.0),
+                ratings_text(ratings, settings.notation),
+                number(exp.0),
             );
             **stats_text = stats_info;
         }
     }
 
     // Update enemy stats display
-    if let Ok((enemy_number, hp, attack, defense, speed, exp_reward)) = enemy_query.single() {
+    if let Ok((enemy_number, archetype, hp, attack, defense, speed, ratings, exp_reward, enrage, effects)) = enemy_query.single() {
         if let Ok(mut combat_text) = combat_text_query.single_mut() {
+            // Bosses carry an enrage timer
+            let boss_info = match enrage {
+                Some(enrage) if enrage.enraged => "\nBOSS - ENRAGED!".to_string(),
+                Some(enrage) => format!("\nBOSS - Enrage in {:.1}s", enrage.timer.remaining_secs()),
+                None => String::new(),
+            };
             let combat_info = format!(
-                "Enemy #{}\nEnemy HP: {:.2}\nEnemy Attack: {:.2}\nEnemy Defense: {:.2}\nEnemy Speed: {:.2}\nEXP Reward: {:.2}",
+                "Enemy #{}: {} ({}){}\nEnemy HP: {}{}\nEnemy Attack: {}\nEnemy Defense: {}\nEnemy Speed: {}\n{}\nEXP Reward: {}",
                 enemy_number.0,
-                hp.0.to_f64().unwrap_or(0.0),
-                attack.0.to_f64().unwrap_or(0.0),
-                defense.0.to_f64().unwrap_or(0.0),
-                speed.0.to_f64().unwrap_or(0.0),
-                exp_reward.0.to_f64().unwrap_or(0.0),
+                archetype.name(),
+                archetype.label(),
+                boss_info,
+                number(hp.0),
+                status_text(effects),
+                number(attack.0),
+                number(defense.0),
+                number(speed.0),
+                ratings_text(ratings, settings.notation),
+                number(exp_reward.0),
             );
             **combat_text = combat_info;
         }
@@ -1,7 +1,11 @@
 use bevy::prelude::*;
-use crate::{CombatState, AutomationConfig, DungeonButton, DungeonButtonText, AutoRetryButton, AutoRetryButtonText};
+use crate::{
+    CombatState, AutomationConfig, DungeonButton, DungeonButtonText, AutoRetryButton, AutoRetryButtonText,
+    UpgradeStrategy, UpgradeStrategyKind, UpgradeStrategyButton, UpgradeStrategyButtonText,
+};
 use crate::events::CombatStartEvent;
 
+#[allow(clippy::type_complexity)]
 pub fn dungeon_button_system(
     mut interaction_query: Query<
         (&Interaction, &mut BackgroundColor),
@@ -49,6 +53,7 @@ pub fn dungeon_button_system(
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub fn auto_retry_button_system(
     mut interaction_query: Query<
         (&Interaction, &mut BackgroundColor),
@@ -104,4 +109,45 @@ pub fn auto_retry_button_system(
             text.0 = "Auto Retry: OFF".to_string();
         }
     }
-}
\ No newline at end of file
+}
+
+#[allow(clippy::type_complexity)]
+pub fn upgrade_strategy_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<Button>, With<UpgradeStrategyButton>),
+    >,
+    mut button_text_query: Query<&mut Text, With<UpgradeStrategyButtonText>>,
+    mut strategy: ResMut<UpgradeStrategy>,
+) {
+    for (interaction, mut background_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                strategy.kind = strategy.kind.next();
+                *background_color = BackgroundColor(Color::srgb(0.1, 0.3, 0.5));
+            }
+            Interaction::Hovered => {
+                *background_color = BackgroundColor(Color::srgb(0.3, 0.5, 0.8));
+            }
+            Interaction::None => {
+                *background_color = BackgroundColor(Color::srgb(0.2, 0.4, 0.7));
+            }
+        }
+    }
+
+    if !strategy.is_changed() {
+        return;
+    }
+    if let Ok(mut text) = button_text_query.single_mut() {
+        text.0 = match strategy.kind {
+            UpgradeStrategyKind::Weighted => {
+                let [hp, attack, defense, speed, crit_chance, crit_multiplier, evasion, accuracy] = strategy.weights;
+                format!(
+                    "Upgrades: Weighted (HP {} / ATK {} / DEF {} / SPD {} / CRT {} / CDM {} / EVA {} / ACC {})",
+                    hp, attack, defense, speed, crit_chance, crit_multiplier, evasion, accuracy
+                )
+            }
+            kind => format!("Upgrades: {}", kind.label()),
+        };
+    }
+}
@@ -2,8 +2,24 @@ pub mod setup;
 pub mod combat_ui;
 pub mod tab_ui;
 pub mod dungeon_ui;
+pub mod offline_ui;
+pub mod rebirth_ui;
+pub mod upgrade_ui;
+pub mod achievements_ui;
+pub mod statistics_ui;
+pub mod combat_log_ui;
+pub mod settings_ui;
+pub mod skill_ui;
 
 pub use setup::setup_ui;
 pub use combat_ui::update_ui_system;
 pub use tab_ui::tab_button_system;
-pub use dungeon_ui::{dungeon_button_system, auto_retry_button_system};
\ No newline at end of file
+pub use dungeon_ui::{dungeon_button_system, auto_retry_button_system, upgrade_strategy_button_system};
+pub use offline_ui::{offline_summary_popup_system, offline_summary_close_system};
+pub use rebirth_ui::{rebirth_preview_system, rebirth_button_system, rebirth_shop_button_system};
+pub use upgrade_ui::{upgrade_rows_system, auto_upgrade_button_system};
+pub use achievements_ui::achievements_ui_system;
+pub use statistics_ui::statistics_ui_system;
+pub use combat_log_ui::{combat_log_ui_system, combat_log_filter_button_system, combat_log_scroll_system};
+pub use settings_ui::number_notation_button_system;
+pub use skill_ui::{skill_buttons_system, auto_cast_button_system};
\ No newline at end of file
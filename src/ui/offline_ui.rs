@@ -0,0 +1,108 @@
+use bevy::prelude::*;
+use crate::{OfflineProgressReport, OfflineSummaryPanel, OfflineSummaryCloseButton, DisplaySettings};
+
+fn format_elapsed(total_secs: u64) -> String {
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    let seconds = total_secs % 60;
+    if hours > 0 {
+        format!("{}h {:02}m {:02}s", hours, minutes, seconds)
+    } else {
+        format!("{}m {:02}s", minutes, seconds)
+    }
+}
+
+// Show a "While you were away" panel once offline progress has been applied
+pub fn offline_summary_popup_system(
+    mut commands: Commands,
+    report: Res<OfflineProgressReport>,
+    settings: Res<DisplaySettings>,
+) {
+    let summary = format!(
+        "Time away: {}\nEnemies defeated: {}\nEXP earned: {}\nUpgrades bought: {}\nDeaths: {}\nBoss retreats: {}\nRebirth points gained: {}\nHighest enemy: #{}",
+        format_elapsed(report.elapsed_secs),
+        report.enemies_killed,
+        settings.notation.format(report.exp_earned),
+        report.upgrades_bought,
+        report.deaths,
+        report.boss_retreats,
+        settings.notation.format(report.rebirth_points_gained),
+        report.highest_enemy,
+    );
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        GlobalZIndex(10),
+        OfflineSummaryPanel,
+    )).with_children(|parent| {
+        parent.spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(20.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BorderColor(Color::WHITE),
+            BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+        )).with_children(|parent| {
+            parent.spawn((
+                Text::new("While you were away"),
+                TextFont { font_size: 24.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+
+            parent.spawn((
+                Text::new(summary),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::WHITE),
+                Node {
+                    margin: UiRect::vertical(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            parent.spawn((
+                Button,
+                Node {
+                    width: Val::Px(120.0),
+                    height: Val::Px(40.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.2, 0.7, 0.2)),
+                OfflineSummaryCloseButton,
+            )).with_children(|parent| {
+                parent.spawn((
+                    Text::new("Continue"),
+                    TextFont { font_size: 16.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        });
+    });
+}
+
+pub fn offline_summary_close_system(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<OfflineSummaryCloseButton>)>,
+    panel_query: Query<Entity, With<OfflineSummaryPanel>>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            for panel in panel_query.iter() {
+                commands.entity(panel).despawn();
+            }
+            commands.remove_resource::<OfflineProgressReport>();
+        }
+    }
+}
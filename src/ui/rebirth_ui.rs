@@ -0,0 +1,160 @@
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+use crate::{
+    GameProgress, BalanceConfig, RebirthPreview, RebirthCurrency, RebirthCause,
+    RebirthPreviewText, RebirthButton, RebirthButtonText,
+    RebirthPerk, RebirthShop, RebirthShopButton, RebirthShopButtonText, DisplaySettings, NumberNotation,
+};
+use crate::events::{RebirthRequestEvent, RebirthShopPurchaseEvent};
+
+// Number of past rebirths listed under the preview
+const SHOWN_HISTORY: usize = 5;
+
+// Live preview of what rebirthing right now would give
+pub fn rebirth_preview_system(
+    game_progress: Res<GameProgress>,
+    balance: Res<BalanceConfig>,
+    currency: Res<RebirthCurrency>,
+    mut preview_text_query: Query<&mut Text, With<RebirthPreviewText>>,
+    settings: Res<DisplaySettings>,
+) {
+    let Ok(mut preview_text) = preview_text_query.single_mut() else { return };
+    let preview = RebirthPreview::new(&balance, &game_progress, &currency);
+    let notation = settings.notation;
+
+    let history = currency.history.iter().rev().take(SHOWN_HISTORY)
+        .map(|record| format!(
+            "  +{} at enemy #{} ({})",
+            notation.format_whole(record.points_gained),
+            record.enemy_reached,
+            match record.cause {
+                RebirthCause::Death => "death",
+                RebirthCause::Manual => "manual",
+            },
+        ))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    **preview_text = format!(
+        "Rebirth points: {} (lifetime: {}, rebirths: {})\nRebirth points on reset: +{}\nStat multiplier: x{} -> x{}\nUpgrade cost: x{}\n\n{}\n\nRecent rebirths:\n{}",
+        notation.format_whole(currency.points),
+        notation.format_whole(preview.lifetime_points),
+        currency.rebirth_count,
+        notation.format_whole(preview.points_gained),
+        notation.format(preview.current_bonus),
+        notation.format(preview.stats.bonus),
+        notation.format(preview.cost_multiplier),
+        if preview.available {
+            "Rebirth restarts from enemy #1 with the bonuses above.".to_string()
+        } else {
+            format!("Manual rebirth unlocks at enemy #{}.", balance.rebirth.manual_min_enemy)
+        },
+        if history.is_empty() { "  none yet".to_string() } else { history },
+    );
+}
+
+#[allow(clippy::type_complexity)]
+pub fn rebirth_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<Button>, With<RebirthButton>),
+    >,
+    mut button_text_query: Query<&mut Text, With<RebirthButtonText>>,
+    game_progress: Res<GameProgress>,
+    balance: Res<BalanceConfig>,
+    currency: Res<RebirthCurrency>,
+    mut rebirth_events: EventWriter<RebirthRequestEvent>,
+    settings: Res<DisplaySettings>,
+) {
+    let preview = RebirthPreview::new(&balance, &game_progress, &currency);
+    let available = preview.available;
+
+    for (interaction, mut background_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                if available {
+                    rebirth_events.write(RebirthRequestEvent);
+                }
+                *background_color = BackgroundColor(Color::srgb(0.4, 0.2, 0.5));
+            }
+            Interaction::Hovered => {
+                if available {
+                    *background_color = BackgroundColor(Color::srgb(0.65, 0.35, 0.8));
+                } else {
+                    *background_color = BackgroundColor(Color::srgb(0.5, 0.5, 0.5));
+                }
+            }
+            Interaction::None => {
+                if available {
+                    *background_color = BackgroundColor(Color::srgb(0.55, 0.25, 0.7));
+                } else {
+                    *background_color = BackgroundColor(Color::srgb(0.5, 0.5, 0.5));
+                }
+            }
+        }
+    }
+
+    if let Ok(mut text) = button_text_query.single_mut() {
+        text.0 = if available {
+            format!("Rebirth (+{})", settings.notation.format_whole(preview.points_gained))
+        } else {
+            format!("Locked (enemy #{})", balance.rebirth.manual_min_enemy)
+        };
+    }
+}
+
+// Current effect of a perk as shown on its shop button
+fn perk_effect(shop: &RebirthShop, perk: RebirthPerk, balance: &BalanceConfig, notation: NumberNotation) -> String {
+    let shop_balance = &balance.rebirth.shop;
+    match perk {
+        RebirthPerk::ExpMultiplier => format!("EXP x{}", notation.format(shop.exp_multiplier(shop_balance))),
+        RebirthPerk::StartingLevels => format!("start at Lv {}", shop.starting_level(shop_balance)),
+        RebirthPerk::FasterCooldown => {
+            format!("attack time x{}", notation.format(BigFloat::from(shop.attack_time_multiplier(shop_balance))))
+        }
+        _ => format!("cost x{}", notation.format(BigFloat::from(1.0) / shop.cost_divisor(perk, shop_balance))),
+    }
+}
+
+pub fn rebirth_shop_button_system(
+    mut button_query: Query<(Ref<Interaction>, &mut BackgroundColor, &RebirthShopButton), With<Button>>,
+    mut text_query: Query<(&mut Text, &RebirthShopButtonText)>,
+    shop: Res<RebirthShop>,
+    currency: Res<RebirthCurrency>,
+    balance: Res<BalanceConfig>,
+    mut purchase_events: EventWriter<RebirthShopPurchaseEvent>,
+    settings: Res<DisplaySettings>,
+) {
+    let affordable = |perk: RebirthPerk| {
+        shop.next_cost(perk, &balance.rebirth.shop).is_some_and(|cost| currency.points >= cost)
+    };
+
+    // Colors follow affordability, which changes without any interaction
+    for (interaction, mut background_color, button) in &mut button_query {
+        let can_buy = affordable(button.perk);
+        if *interaction == Interaction::Pressed && interaction.is_changed() && can_buy {
+            purchase_events.write(RebirthShopPurchaseEvent { perk: button.perk });
+        }
+        *background_color = BackgroundColor(match (*interaction, can_buy) {
+            (_, false) => Color::srgb(0.5, 0.5, 0.5),
+            (Interaction::Pressed, true) => Color::srgb(0.4, 0.2, 0.5),
+            (Interaction::Hovered, true) => Color::srgb(0.65, 0.35, 0.8),
+            (Interaction::None, true) => Color::srgb(0.55, 0.25, 0.7),
+        });
+    }
+
+    for (mut text, button_text) in &mut text_query {
+        let perk = button_text.perk;
+        let cost = match shop.next_cost(perk, &balance.rebirth.shop) {
+            Some(cost) => format!("{} pts", settings.notation.format_whole(cost)),
+            None => "MAX".to_string(),
+        };
+        text.0 = format!(
+            "{} Lv {} ({}) - {}",
+            perk.name(),
+            shop.level(perk),
+            perk_effect(&shop, perk, &balance, settings.notation),
+            cost,
+        );
+    }
+}
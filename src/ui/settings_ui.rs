@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+use crate::{DisplaySettings, NumberNotationButton, NumberNotationButtonText, NumberPreviewText};
+
+// Sample values shown under the notation button
+const PREVIEW_VALUES: [f64; 4] = [1234.5, 6.789e7, 1.5e15, 4.2e100];
+
+#[allow(clippy::type_complexity)]
+pub fn number_notation_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<Button>, With<NumberNotationButton>),
+    >,
+    mut button_text_query: Query<&mut Text, (With<NumberNotationButtonText>, Without<NumberPreviewText>)>,
+    mut preview_text_query: Query<(&mut Text, Ref<NumberPreviewText>), Without<NumberNotationButtonText>>,
+    mut settings: ResMut<DisplaySettings>,
+) {
+    for (interaction, mut background_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                settings.notation = settings.notation.next();
+                *background_color = BackgroundColor(Color::srgb(0.1, 0.3, 0.5));
+            }
+            Interaction::Hovered => {
+                *background_color = BackgroundColor(Color::srgb(0.3, 0.5, 0.8));
+            }
+            Interaction::None => {
+                *background_color = BackgroundColor(Color::srgb(0.2, 0.4, 0.7));
+            }
+        }
+    }
+
+    let Ok((mut preview_text, marker)) = preview_text_query.single_mut() else { return };
+    if !settings.is_changed() && !marker.is_added() {
+        return;
+    }
+    if let Ok(mut text) = button_text_query.single_mut() {
+        text.0 = format!("Numbers: {}", settings.notation.label());
+    }
+    let samples: Vec<String> = PREVIEW_VALUES.iter()
+        .map(|&value| settings.notation.format(BigFloat::from(value)))
+        .collect();
+    preview_text.0 = format!("Preview: {}", samples.join(", "));
+}
@@ -1,5 +1,14 @@
 use bevy::prelude::*;
-use crate::{GameTab, TabButton, TabContent, StatsText, CombatText, DungeonButton, DungeonButtonText, AutoRetryButton, AutoRetryButtonText};
+use crate::{
+    GameTab, TabButton, TabContent, StatsText, CombatText, DungeonButton, DungeonButtonText,
+    AutoRetryButton, AutoRetryButtonText, RebirthPreviewText, RebirthButton, RebirthButtonText,
+    RebirthPerk, RebirthShopButton, RebirthShopButtonText, UpgradeStrategyButton, UpgradeStrategyButtonText,
+    StatKind, BuyAmount, UpgradeRowText, ManualUpgradeButton, ManualUpgradeButtonText,
+    AutoUpgradeButton, AutoUpgradeButtonText, AchievementsText, StatisticsText,
+    SkillKind, SkillButton, SkillButtonText, AutoCastButton, AutoCastButtonText,
+    CombatLogKind, CombatLogPanel, CombatLogText, CombatLogFilterButton,
+    NumberNotationButton, NumberNotationButtonText, NumberPreviewText,
+};
 
 pub fn setup_ui(mut commands: Commands) {
     commands.spawn(Camera2d);
@@ -78,6 +87,7 @@ pub fn setup_ui(mut commands: Commands) {
                     border: UiRect::all(Val::Px(2.0)),
                     justify_content: JustifyContent::Center,
                     align_items: AlignItems::Center,
+                    margin: UiRect::bottom(Val::Px(10.0)),
                     ..default()
                 },
                 BorderColor(Color::WHITE),
@@ -90,6 +100,74 @@ pub fn setup_ui(mut commands: Commands) {
                     TextColor(Color::WHITE),
                 ));
             });
+
+            // Achievements tab button
+            parent.spawn((
+                Button,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(50.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+                BorderColor(Color::WHITE),
+                BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+                TabButton { tab: GameTab::Achievements },
+            )).with_children(|parent| {
+                parent.spawn((
+                    Text::new("Achievements"),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+            });
+
+            // Statistics tab button
+            parent.spawn((
+                Button,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(50.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+                BorderColor(Color::WHITE),
+                BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+                TabButton { tab: GameTab::Statistics },
+            )).with_children(|parent| {
+                parent.spawn((
+                    Text::new("Statistics"),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+            });
+
+            // Settings tab button
+            parent.spawn((
+                Button,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(50.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BorderColor(Color::WHITE),
+                BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+                TabButton { tab: GameTab::Settings },
+            )).with_children(|parent| {
+                parent.spawn((
+                    Text::new("Settings"),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+            });
         });
 
         // Main content area
@@ -148,9 +226,119 @@ pub fn setup_ui(mut commands: Commands) {
                         DungeonButtonText,
                     ));
                 });
+
+                parent.spawn((
+                    Text::new("Skills"),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+
+                // One cast button per skill, greyed out while cooling down or short on mana
+                parent.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    margin: UiRect::vertical(Val::Px(5.0)),
+                    ..default()
+                }).with_children(|parent| {
+                    for skill in SkillKind::ALL {
+                        parent.spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(160.0),
+                                height: Val::Px(32.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                margin: UiRect::right(Val::Px(6.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.5, 0.5, 0.5)),
+                            BorderColor(Color::WHITE),
+                            SkillButton { skill },
+                        )).with_children(|parent| {
+                            parent.spawn((
+                                Text::new(skill.label()),
+                                TextFont { font_size: 12.0, ..default() },
+                                TextColor(Color::WHITE),
+                                SkillButtonText { skill },
+                            ));
+                        });
+                    }
+                });
+
+                parent.spawn((
+                    Text::new("Upgrades"),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+
+                parent.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(32.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::vertical(Val::Px(5.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.2, 0.7, 0.2)),
+                    BorderColor(Color::WHITE),
+                    AutoUpgradeButton,
+                )).with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Auto Upgrade: ON"),
+                        TextFont { font_size: 14.0, ..default() },
+                        TextColor(Color::WHITE),
+                        AutoUpgradeButtonText,
+                    ));
+                });
+
+                // One row per stat: level, value and cost, then the buy buttons
+                for stat in StatKind::ALL {
+                    parent.spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::vertical(Val::Px(2.0)),
+                        ..default()
+                    }).with_children(|parent| {
+                        parent.spawn((
+                            Node {
+                                width: Val::Px(380.0),
+                                ..default()
+                            },
+                            Text::new(stat.label()),
+                            TextFont { font_size: 14.0, ..default() },
+                            TextColor(Color::WHITE),
+                            UpgradeRowText { stat },
+                        ));
+
+                        for amount in BuyAmount::ALL {
+                            parent.spawn((
+                                Button,
+                                Node {
+                                    width: Val::Px(70.0),
+                                    height: Val::Px(28.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    margin: UiRect::horizontal(Val::Px(3.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.5, 0.5, 0.5)),
+                                BorderColor(Color::WHITE),
+                                ManualUpgradeButton { stat, amount },
+                            )).with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(amount.label()),
+                                    TextFont { font_size: 12.0, ..default() },
+                                    TextColor(Color::WHITE),
+                                    ManualUpgradeButtonText { stat, amount },
+                                ));
+                            });
+                        }
+                    });
+                }
                 
                 parent.spawn((
-                    Text::new("Combat Log"),
+                    Text::new("Current Enemy"),
                     TextFont { font_size: 20.0, ..default() },
                     TextColor(Color::WHITE),
                 ));
@@ -161,6 +349,68 @@ pub fn setup_ui(mut commands: Commands) {
                     TextColor(Color::srgb(1.0, 1.0, 0.0)),
                     CombatText,
                 ));
+
+                parent.spawn((
+                    Text::new("Combat Log"),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::WHITE),
+                    Node {
+                        margin: UiRect::top(Val::Px(10.0)),
+                        ..default()
+                    },
+                ));
+
+                // One toggle per entry kind
+                parent.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    margin: UiRect::vertical(Val::Px(5.0)),
+                    ..default()
+                }).with_children(|parent| {
+                    for kind in CombatLogKind::ALL {
+                        parent.spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(80.0),
+                                height: Val::Px(24.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                margin: UiRect::right(Val::Px(4.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.2, 0.6, 0.2)),
+                            BorderColor(Color::WHITE),
+                            CombatLogFilterButton { kind },
+                        )).with_children(|parent| {
+                            parent.spawn((
+                                Text::new(kind.label()),
+                                TextFont { font_size: 12.0, ..default() },
+                                TextColor(Color::WHITE),
+                            ));
+                        });
+                    }
+                });
+
+                // Scrolls with the mouse wheel while hovered, newest entries on top
+                parent.spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(180.0),
+                        flex_direction: FlexDirection::Column,
+                        overflow: Overflow::scroll_y(),
+                        padding: UiRect::all(Val::Px(5.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.05, 0.05, 0.05)),
+                    Interaction::default(),
+                    CombatLogPanel,
+                )).with_children(|parent| {
+                    parent.spawn((
+                        Text::new(""),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                        CombatLogText,
+                    ));
+                });
             });
             
             // Rebirth tab content
@@ -181,10 +431,65 @@ pub fn setup_ui(mut commands: Commands) {
                 ));
                 
                 parent.spawn((
-                    Text::new("Rebirth features coming soon..."),
+                    Text::new("Loading..."),
                     TextFont { font_size: 16.0, ..default() },
                     TextColor(Color::WHITE),
+                    RebirthPreviewText,
+                ));
+
+                parent.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(40.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.5, 0.5, 0.5)),
+                    BorderColor(Color::WHITE),
+                    RebirthButton,
+                )).with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Rebirth"),
+                        TextFont { font_size: 16.0, ..default() },
+                        TextColor(Color::WHITE),
+                        RebirthButtonText,
+                    ));
+                });
+
+                parent.spawn((
+                    Text::new("Rebirth Shop"),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::WHITE),
                 ));
+
+                // One purchase button per perk
+                for perk in RebirthPerk::ALL {
+                    parent.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(420.0),
+                            height: Val::Px(32.0),
+                            justify_content: JustifyContent::FlexStart,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::horizontal(Val::Px(10.0)),
+                            margin: UiRect::vertical(Val::Px(3.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.5, 0.5, 0.5)),
+                        BorderColor(Color::WHITE),
+                        RebirthShopButton { perk },
+                    )).with_children(|parent| {
+                        parent.spawn((
+                            Text::new(perk.name()),
+                            TextFont { font_size: 14.0, ..default() },
+                            TextColor(Color::WHITE),
+                            RebirthShopButtonText { perk },
+                        ));
+                    });
+                }
             });
             
             // Automation tab content
@@ -225,6 +530,157 @@ pub fn setup_ui(mut commands: Commands) {
                         AutoRetryButtonText,
                     ));
                 });
+
+                // Cycles through the auto-upgrade strategies
+                parent.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(320.0),
+                        height: Val::Px(40.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.2, 0.4, 0.7)),
+                    BorderColor(Color::WHITE),
+                    UpgradeStrategyButton,
+                )).with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Upgrades: Cheapest First"),
+                        TextFont { font_size: 16.0, ..default() },
+                        TextColor(Color::WHITE),
+                        UpgradeStrategyButtonText,
+                    ));
+                });
+
+                parent.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(40.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.7, 0.2, 0.2)),
+                    BorderColor(Color::WHITE),
+                    AutoCastButton,
+                )).with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Auto Cast: OFF"),
+                        TextFont { font_size: 16.0, ..default() },
+                        TextColor(Color::WHITE),
+                        AutoCastButtonText,
+                    ));
+                });
+            });
+
+            // Achievements tab content
+            parent.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    display: Display::None,
+                    ..default()
+                },
+                TabContent { tab: GameTab::Achievements },
+            )).with_children(|parent| {
+                parent.spawn((
+                    Text::new("Achievements"),
+                    TextFont { font_size: 24.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+
+                parent.spawn((
+                    Text::new("Loading..."),
+                    TextFont { font_size: 16.0, ..default() },
+                    TextColor(Color::WHITE),
+                    Node {
+                        margin: UiRect::top(Val::Px(10.0)),
+                        ..default()
+                    },
+                    AchievementsText,
+                ));
+            });
+
+            // Statistics tab content
+            parent.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    display: Display::None,
+                    ..default()
+                },
+                TabContent { tab: GameTab::Statistics },
+            )).with_children(|parent| {
+                parent.spawn((
+                    Text::new("Statistics"),
+                    TextFont { font_size: 24.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+
+                parent.spawn((
+                    Text::new("Loading..."),
+                    TextFont { font_size: 16.0, ..default() },
+                    TextColor(Color::WHITE),
+                    Node {
+                        margin: UiRect::top(Val::Px(10.0)),
+                        ..default()
+                    },
+                    StatisticsText,
+                ));
+            });
+
+            // Settings tab content
+            parent.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    display: Display::None,
+                    ..default()
+                },
+                TabContent { tab: GameTab::Settings },
+            )).with_children(|parent| {
+                parent.spawn((
+                    Text::new("Settings"),
+                    TextFont { font_size: 24.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+
+                // Cycles through the number notations
+                parent.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(320.0),
+                        height: Val::Px(40.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.2, 0.4, 0.7)),
+                    BorderColor(Color::WHITE),
+                    NumberNotationButton,
+                )).with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Numbers: Suffix (1.23M)"),
+                        TextFont { font_size: 16.0, ..default() },
+                        TextColor(Color::WHITE),
+                        NumberNotationButtonText,
+                    ));
+                });
+
+                parent.spawn((
+                    Text::new(""),
+                    TextFont { font_size: 16.0, ..default() },
+                    TextColor(Color::WHITE),
+                    NumberPreviewText,
+                ));
             });
         });
     });
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use crate::{
+    Player, Mana, SkillCooldowns, Stunned, SkillButton, SkillButtonText, AutoCastButton, AutoCastButtonText,
+    AutomationConfig, BalanceConfig, CombatState,
+};
+use crate::events::SkillCastEvent;
+
+// Skill buttons in the Combat tab: cost and cooldown, cast on press
+pub fn skill_buttons_system(
+    player_query: Query<(&Mana, &SkillCooldowns, Has<Stunned>), With<Player>>,
+    mut button_text_query: Query<(&mut Text, &SkillButtonText)>,
+    mut button_query: Query<(Ref<Interaction>, &mut BackgroundColor, &SkillButton), With<Button>>,
+    mut cast_events: EventWriter<SkillCastEvent>,
+    combat_state: Res<CombatState>,
+    balance: Res<BalanceConfig>,
+) {
+    // No mana or cooldowns outside combat
+    let player = player_query.single().ok();
+
+    for (mut text, button) in &mut button_text_query {
+        let stats = balance.skills.get(button.skill);
+        text.0 = match player.map(|(_, cooldowns, _)| cooldowns.remaining(button.skill)) {
+            Some(remaining) if remaining > 0.0 => format!("{} ({:.1}s)", button.skill.label(), remaining),
+            _ => format!("{} ({} MP)", button.skill.label(), stats.cost),
+        };
+    }
+
+    for (interaction, mut background_color, button) in &mut button_query {
+        let can_cast = combat_state.in_dungeon && !combat_state.is_game_over && player.is_some_and(|(mana, cooldowns, stunned)| {
+            !stunned && cooldowns.is_ready(button.skill) && mana.current >= balance.skills.get(button.skill).cost
+        });
+        if *interaction == Interaction::Pressed && interaction.is_changed() && can_cast {
+            cast_events.write(SkillCastEvent { skill: button.skill });
+        }
+        *background_color = BackgroundColor(match (*interaction, can_cast) {
+            (_, false) => Color::srgb(0.5, 0.5, 0.5),
+            (Interaction::Pressed, true) => Color::srgb(0.1, 0.2, 0.5),
+            (Interaction::Hovered, true) => Color::srgb(0.3, 0.5, 0.9),
+            (Interaction::None, true) => Color::srgb(0.2, 0.4, 0.7),
+        });
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn auto_cast_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<Button>, With<AutoCastButton>),
+    >,
+    mut button_text_query: Query<&mut Text, With<AutoCastButtonText>>,
+    mut automation_config: ResMut<AutomationConfig>,
+) {
+    for (interaction, mut background_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                automation_config.auto_cast_enabled = !automation_config.auto_cast_enabled;
+                *background_color = BackgroundColor(Color::srgb(0.3, 0.3, 0.7));
+            }
+            Interaction::Hovered => {
+                *background_color = BackgroundColor(Color::srgb(0.6, 0.6, 0.6));
+            }
+            Interaction::None => {
+                if automation_config.auto_cast_enabled {
+                    *background_color = BackgroundColor(Color::srgb(0.2, 0.7, 0.2));
+                } else {
+                    *background_color = BackgroundColor(Color::srgb(0.7, 0.2, 0.2));
+                }
+            }
+        }
+    }
+
+    if let Ok(mut text) = button_text_query.single_mut() {
+        text.0 = if automation_config.auto_cast_enabled {
+            "Auto Cast: ON".to_string()
+        } else {
+            "Auto Cast: OFF".to_string()
+        };
+    }
+}
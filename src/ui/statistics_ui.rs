@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use crate::{Statistics, StatisticsText, DisplaySettings};
+
+fn format_duration(secs: f64) -> String {
+    let total = secs as u64;
+    format!("{}:{:02}:{:02}", total / 3600, total / 60 % 60, total % 60)
+}
+
+// Run and lifetime totals for the Statistics tab; refreshed every frame for the play timer
+pub fn statistics_ui_system(
+    statistics: Res<Statistics>,
+    mut text_query: Query<&mut Text, With<StatisticsText>>,
+    settings: Res<DisplaySettings>,
+) {
+    let Ok(mut text) = text_query.single_mut() else { return };
+    let (run, lifetime) = (&statistics.run, &statistics.lifetime);
+    let format_big = |value| settings.notation.format_whole(value);
+
+    let row = |label: &str, run: String, lifetime: String| format!("{}: {} | {}", label, run, lifetime);
+    text.0 = [
+        "(this run | lifetime)".to_string(),
+        row("Damage dealt", format_big(run.damage_dealt), format_big(lifetime.damage_dealt)),
+        row("Damage taken", format_big(run.damage_taken), format_big(lifetime.damage_taken)),
+        row("Enemies killed", run.kills.to_string(), lifetime.kills.to_string()),
+        row("Highest enemy", run.highest_enemy.to_string(), lifetime.highest_enemy.to_string()),
+        row("EXP earned", format_big(run.exp_earned), format_big(lifetime.exp_earned)),
+        row("Time played", format_duration(run.time_played_secs), format_duration(lifetime.time_played_secs)),
+        String::new(),
+        format!("Deaths: {}", statistics.deaths),
+        format!("Rebirths: {}", statistics.rebirths),
+    ]
+    .join("\n");
+}
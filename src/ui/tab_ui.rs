@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use crate::{UIState, TabButton, TabContent};
 
+#[allow(clippy::type_complexity)]
 pub fn tab_button_system(
     mut interaction_query: Query<
         (&Interaction, &TabButton, &mut BackgroundColor),
@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+use too_big_float::BigFloat;
+use crate::{
+    Player, Experience, StatKind, StatMarkers, BuyAmount, UpgradeableStat,
+    CurrentValue, UpgradeLevel, UpgradeCost, UpgradeMultiplier, CostMultiplier, UpgradeStrategy,
+    UpgradeRowText, ManualUpgradeButton, ManualUpgradeButtonText, AutoUpgradeButton, AutoUpgradeButtonText,
+    DisplaySettings, affordable_levels,
+};
+use crate::events::ManualUpgradeEvent;
+
+// Per-stat upgrade rows in the Combat tab and their buy buttons
+#[allow(clippy::type_complexity)]
+pub fn upgrade_rows_system(
+    player_query: Query<&Experience, With<Player>>,
+    stat_query: Query<(
+        &CurrentValue,
+        &UpgradeLevel,
+        &UpgradeCost,
+        &UpgradeMultiplier,
+        &CostMultiplier,
+        StatMarkers,
+    ), With<UpgradeableStat>>,
+    mut row_text_query: Query<(&mut Text, &UpgradeRowText)>,
+    mut button_text_query: Query<(&mut Text, &ManualUpgradeButtonText), Without<UpgradeRowText>>,
+    mut button_query: Query<(Ref<Interaction>, &mut BackgroundColor, &ManualUpgradeButton), With<Button>>,
+    mut upgrade_events: EventWriter<ManualUpgradeEvent>,
+    settings: Res<DisplaySettings>,
+) {
+    let Ok(experience) = player_query.single() else { return };
+
+    let mut stats = [None; StatKind::COUNT];
+    for (value, level, cost, multiplier, cost_multiplier, markers) in stat_query.iter() {
+        stats[markers.kind().index()] = Some((value, level, cost, multiplier, cost_multiplier));
+    }
+    // Levels each button would buy right now
+    let affordable = |stat: StatKind, limit: u32| {
+        stats[stat.index()].map_or(0, |(value, _, cost, multiplier, cost_multiplier)| {
+            let limit = limit.min(stat.levels_to_cap(value.0, multiplier.0));
+            affordable_levels(experience.0, cost, cost_multiplier, limit).0
+        })
+    };
+
+    for (mut text, row) in &mut row_text_query {
+        let Some((value, level, cost, multiplier, _)) = stats[row.stat.index()] else { continue };
+        if row.stat.levels_to_cap(value.0, multiplier.0) == 0 {
+            text.0 = format!("{} Lv {}: {} (max)", row.stat.label(), level.0, settings.notation.format(value.0));
+            continue;
+        }
+        let next_value = value.0 * BigFloat::from(multiplier.0);
+        text.0 = format!(
+            "{} Lv {}: {} -> {} (cost {})",
+            row.stat.label(),
+            level.0,
+            settings.notation.format(value.0),
+            settings.notation.format(next_value),
+            settings.notation.format(cost.0),
+        );
+    }
+
+    for (mut text, button) in &mut button_text_query {
+        text.0 = match button.amount {
+            BuyAmount::Max => format!("Max ({})", affordable(button.stat, button.amount.limit())),
+            amount => amount.label().to_string(),
+        };
+    }
+
+    for (interaction, mut background_color, button) in &mut button_query {
+        let can_buy = affordable(button.stat, 1) > 0;
+        if *interaction == Interaction::Pressed && interaction.is_changed() && can_buy {
+            upgrade_events.write(ManualUpgradeEvent { stat: button.stat, amount: button.amount });
+        }
+        *background_color = BackgroundColor(match (*interaction, can_buy) {
+            (_, false) => Color::srgb(0.5, 0.5, 0.5),
+            (Interaction::Pressed, true) => Color::srgb(0.1, 0.4, 0.1),
+            (Interaction::Hovered, true) => Color::srgb(0.3, 0.8, 0.3),
+            (Interaction::None, true) => Color::srgb(0.2, 0.6, 0.2),
+        });
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn auto_upgrade_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<Button>, With<AutoUpgradeButton>),
+    >,
+    mut button_text_query: Query<&mut Text, With<AutoUpgradeButtonText>>,
+    mut strategy: ResMut<UpgradeStrategy>,
+) {
+    for (interaction, mut background_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                strategy.enabled = !strategy.enabled;
+                *background_color = BackgroundColor(Color::srgb(0.3, 0.3, 0.7));
+            }
+            Interaction::Hovered => {
+                *background_color = BackgroundColor(Color::srgb(0.6, 0.6, 0.6));
+            }
+            Interaction::None => {
+                if strategy.enabled {
+                    *background_color = BackgroundColor(Color::srgb(0.2, 0.7, 0.2));
+                } else {
+                    *background_color = BackgroundColor(Color::srgb(0.7, 0.2, 0.2));
+                }
+            }
+        }
+    }
+
+    if let Ok(mut text) = button_text_query.single_mut() {
+        text.0 = if strategy.enabled {
+            "Auto Upgrade: ON".to_string()
+        } else {
+            "Auto Upgrade: OFF".to_string()
+        };
+    }
+}